@@ -0,0 +1,304 @@
+use crate::types::{ApplicationError, Wettkampf};
+use crate::Errors::DetailedError;
+use crate::FileFormat::{deserialize_wk_file, serialize_wk_file};
+use crate::Resources::application_data_dir;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+// Every document with unsaved changes is written to the Autosave folder periodically.
+// Saving or closing the document removes its autosave, so after a crash only the autosaves of the lost documents are left.
+// How often that happens is part of the settings, see Settings::autosave_interval_minutes.
+
+static NEXT_AUTOSAVE_ID: AtomicU32 = AtomicU32::new(0);
+
+/// An autosave on disk: the competition in the .wkdata format, so it is migrated like any other file when restored.
+#[derive(Debug, Serialize, Deserialize)]
+struct AutosaveFile {
+    /// The file the competition was opened from or last saved to, None if it was never saved.
+    source_path: Option<PathBuf>,
+    autosaved: String,
+    wk_file: Value,
+}
+
+/// An autosave that is newer than its source file, as offered to the user for restoring.
+#[derive(Debug, Clone, Serialize)]
+pub struct AutosaveInfo {
+    pub id: String,
+    pub wk_name: String,
+    pub source_path: Option<PathBuf>,
+    /// Formatted for display, e.g. 18.10.2026 14:05
+    pub autosaved: String,
+}
+
+/// Returns: The Autosave folder next to Logs and Resources, None if the Base Dirs could not be determined.
+pub fn autosave_dir() -> Option<PathBuf> {
+    return application_data_dir().map(|dir| dir.join("Autosave"));
+}
+
+/// Returns: A new name for the autosave of a document, unique across app starts.
+pub fn new_autosave_id() -> String {
+    let id = NEXT_AUTOSAVE_ID.fetch_add(1, Ordering::Relaxed);
+    return format!("{}-{}-{}", Local::now().format("%Y%m%d%H%M%S"), std::process::id(), id);
+}
+
+fn autosave_path(dir: &Path, id: &str) -> PathBuf {
+    return dir.join(format!("{id}.json"));
+}
+
+/// Writes the autosave of a document. The file is written next to the old one first and then moved over it,
+/// so a crash while writing never destroys the last autosave.
+/// Param 1: The autosave id of the document
+/// Param 2: The competition
+/// Param 3: The file the competition belongs to, if it was ever saved
+/// Returns: A description of what went wrong.
+pub fn write_autosave(id: &str, wettkampf: &Wettkampf, source_path: Option<&Path>) -> Result<(), String> {
+    return match autosave_dir() {
+        Some(dir) => write_autosave_in(&dir, id, wettkampf, source_path),
+        None => Err("Could not get the Base Dirs for the Autosave folder".to_string()),
+    };
+}
+
+/// write_autosave into the given folder.
+fn write_autosave_in(dir: &Path, id: &str, wettkampf: &Wettkampf, source_path: Option<&Path>) -> Result<(), String> {
+    match std::fs::create_dir_all(dir) {
+        Ok(()) => {}
+        Err(err) => return Err(format!("Could not create the Autosave folder: {:?}", err)),
+    }
+
+    let serialized_data = match serialize_wk_file(wettkampf, None) {
+        Ok(data) => data,
+        Err(err) => return Err(format!("Could not serialize the autosave: {err}")),
+    };
+    let wk_file: Value = match serde_json::from_str(&serialized_data) {
+        Ok(wk_file) => wk_file,
+        Err(err) => return Err(format!("Could not serialize the autosave: {:?}", err)),
+    };
+    let autosave = AutosaveFile {
+        source_path: source_path.map(|path| path.to_path_buf()),
+        autosaved: Local::now().to_rfc3339(),
+        wk_file,
+    };
+    let contents = match serde_json::to_string(&autosave) {
+        Ok(contents) => contents,
+        Err(err) => return Err(format!("Could not serialize the autosave: {:?}", err)),
+    };
+
+    let path = autosave_path(dir, id);
+    let temporary_path = path.with_extension("json.tmp");
+    match std::fs::write(&temporary_path, contents) {
+        Ok(()) => {}
+        Err(err) => return Err(format!("Could not write the autosave {}: {:?}", temporary_path.display(), err)),
+    }
+    return match std::fs::rename(&temporary_path, &path) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(format!("Could not move the autosave to {}: {:?}", path.display(), err)),
+    };
+}
+
+/// Removes the autosave of a document, if there is one.
+pub fn remove_autosave(id: &str) {
+    if let Some(dir) = autosave_dir() {
+        remove_autosave_in(&dir, id);
+    }
+}
+
+/// remove_autosave from the given folder.
+fn remove_autosave_in(dir: &Path, id: &str) {
+    match std::fs::remove_file(autosave_path(dir, id)) {
+        Ok(()) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => log::warn!("Could not remove the autosave {id}: {:?}", err),
+    }
+}
+
+/// Lists the autosaves left over from a previous run that are newer than their source file.
+/// Autosaves older than their source file were superseded by saving elsewhere and are removed.
+/// Returns: The restorable autosaves, oldest first.
+pub fn find_restorable_autosaves() -> Vec<AutosaveInfo> {
+    return match autosave_dir() {
+        Some(dir) => find_restorable_autosaves_in(&dir),
+        None => Vec::new(),
+    };
+}
+
+/// find_restorable_autosaves in the given folder.
+fn find_restorable_autosaves_in(dir: &Path) -> Vec<AutosaveInfo> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            if err.kind() != std::io::ErrorKind::NotFound {
+                log::error!("Could not read the Autosave folder: {:?}", err);
+            }
+            return Vec::new();
+        }
+    };
+
+    let mut restorable: Vec<(DateTime<Local>, AutosaveInfo)> = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|extension| extension.to_str()) != Some("json") {
+            continue;
+        }
+        let id = match path.file_stem() {
+            Some(stem) => stem.to_string_lossy().to_string(),
+            None => continue,
+        };
+        let autosave = match read_autosave_file(&path) {
+            Ok(autosave) => autosave,
+            Err(err) => {
+                log::warn!("Skipping the unreadable autosave {}: {err}", path.display());
+                continue;
+            }
+        };
+        let autosaved = match DateTime::parse_from_rfc3339(&autosave.autosaved) {
+            Ok(autosaved) => autosaved.with_timezone(&Local),
+            Err(err) => {
+                log::warn!("Skipping the autosave {} with invalid timestamp: {:?}", path.display(), err);
+                continue;
+            }
+        };
+
+        // Saved after the autosave was written (by another instance or after a crash while closing)
+        let source_modified = autosave
+            .source_path
+            .as_ref()
+            .and_then(|source| std::fs::metadata(source).ok())
+            .and_then(|metadata| metadata.modified().ok())
+            .map(DateTime::<Local>::from);
+        if source_modified.is_some_and(|modified| modified >= autosaved) {
+            remove_autosave_in(dir, &id);
+            continue;
+        }
+
+        let wk_name = autosave
+            .wk_file
+            .get("wettkampf")
+            .and_then(|wettkampf| wettkampf.get("wk_name"))
+            .and_then(|name| name.as_str())
+            .unwrap_or_default()
+            .to_string();
+        restorable.push((
+            autosaved,
+            AutosaveInfo {
+                id,
+                wk_name,
+                source_path: autosave.source_path,
+                autosaved: autosaved.format("%d.%m.%Y %H:%M").to_string(),
+            },
+        ));
+    }
+    restorable.sort_by_key(|(autosaved, _)| *autosaved);
+    return restorable.into_iter().map(|(_, info)| info).collect();
+}
+
+/// Reads an autosave for restoring it.
+/// Returns: The competition and the file it belongs to, or the error describing why it could not be loaded.
+pub fn read_autosave(id: &str) -> Result<(Wettkampf, Option<PathBuf>), DetailedError> {
+    return match autosave_dir() {
+        Some(dir) => read_autosave_in(&dir, id),
+        None => Err(DetailedError::new(ApplicationError::AutosaveReadError).caused_by_text("Could not get the Base Dirs for the Autosave folder")),
+    };
+}
+
+/// read_autosave from the given folder.
+fn read_autosave_in(dir: &Path, id: &str) -> Result<(Wettkampf, Option<PathBuf>), DetailedError> {
+    let path = autosave_path(dir, id);
+    let autosave = match read_autosave_file(&path) {
+        Ok(autosave) => autosave,
+        Err(err) => return Err(DetailedError::new(ApplicationError::AutosaveReadError).with_path(&path).caused_by_text(err)),
+    };
+    let wettkampf = deserialize_wk_file(&autosave.wk_file.to_string()).map_err(|err| err.with_path(&path))?;
+    return Ok((wettkampf, autosave.source_path));
+}
+
+fn read_autosave_file(path: &Path) -> Result<AutosaveFile, String> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) => return Err(format!("{:?}", err)),
+    };
+    return serde_json::from_str(&contents).map_err(|err| format!("{:?}", err));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::time::{Duration, SystemTime};
+
+    fn test_folder(name: &str) -> PathBuf {
+        let folder = std::env::temp_dir().join(format!("kre-autosave-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&folder);
+        std::fs::create_dir_all(&folder).unwrap();
+        return folder;
+    }
+
+    fn wettkampf(name: &str) -> Wettkampf {
+        return Wettkampf {
+            wk_name: name.to_string(),
+            wk_place: "Halle".to_string(),
+            wk_replacement_judges: vec!["Paul Wagner".to_string()],
+            ..Wettkampf::default()
+        };
+    }
+
+    #[test]
+    fn an_autosave_can_be_restored_and_removed() {
+        let dir = test_folder("restore");
+        let id = new_autosave_id();
+        write_autosave_in(&dir, &id, &wettkampf("Gaumeisterschaft"), None).unwrap();
+        assert!(!autosave_path(&dir, &id).with_extension("json.tmp").exists());
+
+        let restorable = find_restorable_autosaves_in(&dir);
+        assert_eq!(restorable.len(), 1);
+        assert_eq!(restorable[0].id, id);
+        assert_eq!(restorable[0].wk_name, "Gaumeisterschaft");
+        assert_eq!(restorable[0].source_path, None);
+
+        let (restored, source_path) = read_autosave_in(&dir, &id).unwrap();
+        assert_eq!(restored, wettkampf("Gaumeisterschaft"));
+        assert_eq!(source_path, None);
+
+        remove_autosave_in(&dir, &id);
+        assert!(find_restorable_autosaves_in(&dir).is_empty());
+        assert_eq!(read_autosave_in(&dir, &id).unwrap_err().code(), ApplicationError::AutosaveReadError);
+        // Removing it again is no error
+        remove_autosave_in(&dir, &id);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn autosaves_older_than_their_file_are_removed() {
+        let dir = test_folder("superseded");
+        let unsaved = dir.join("Unsaved.wkdata");
+        std::fs::write(&unsaved, "").unwrap();
+        let saved = dir.join("Saved.wkdata");
+        write_autosave_in(&dir, "1-unsaved", &wettkampf("Ungespeichert"), Some(&unsaved)).unwrap();
+        write_autosave_in(&dir, "2-saved", &wettkampf("Gespeichert"), Some(&saved)).unwrap();
+        // Saved after the autosave was written
+        std::fs::write(&saved, "").unwrap();
+        File::options().write(true).open(&saved).unwrap().set_modified(SystemTime::now() + Duration::from_secs(60)).unwrap();
+
+        let restorable = find_restorable_autosaves_in(&dir);
+        assert_eq!(restorable.len(), 1);
+        assert_eq!(restorable[0].id, "1-unsaved");
+        assert_eq!(restorable[0].source_path, Some(unsaved));
+        assert!(!autosave_path(&dir, "2-saved").exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn unreadable_autosaves_are_skipped() {
+        let dir = test_folder("unreadable");
+        std::fs::write(autosave_path(&dir, "broken"), "{").unwrap();
+        std::fs::write(dir.join("notes.txt"), "").unwrap();
+        write_autosave_in(&dir, "intact", &wettkampf("Gaumeisterschaft"), None).unwrap();
+
+        let restorable = find_restorable_autosaves_in(&dir);
+        assert_eq!(restorable.iter().map(|info| info.id.as_str()).collect::<Vec<_>>(), vec!["intact"]);
+        assert_eq!(read_autosave_in(&dir, "broken").unwrap_err().code(), ApplicationError::AutosaveReadError);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,313 @@
+use crate::types::{ApplicationError, Wettkampf};
+use crate::Errors::DetailedError;
+use crate::FileFormat::serialize_wk_file;
+use crate::Logging::recent_log_files;
+use crate::Roster::name_key;
+use crate::Settings::{current_settings, Settings};
+use crate::{APP_VERSION, LLVM_VER, TARGET_TRIPLE};
+use chrono::Local;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+// Bug reports are sent by mail, which needs a connection that competition halls often lack.
+// A diagnostics bundle holds the same information as a zip file, to be handed over on a USB stick
+// or attached to a mail later: the build, the settings, the recent logs and optionally the competition.
+
+/// The logs of this many sessions, the most recent ones, go into a bundle.
+pub const DIAGNOSTICS_LOG_SESSIONS: usize = 5;
+
+/// Takes the place of the values left out of the bundle.
+const REDACTED: &str = "(entfernt)";
+
+/// Writes a diagnostics bundle.
+/// Param 1: Where to write the zip file
+/// Param 2: The competition to include, None to leave it out
+/// Param 3: Whether to replace the names of the judges, clubs and the responsible person in the competition, see anonymize.
+/// The logs are left out then, as they name judges and competitions and contain the paths of the user,
+/// and so are the names, addresses and folders in the settings, see bundled_settings.
+pub fn write_diagnostics(filepath: &Path, wettkampf: Option<&Wettkampf>, anonymize_names: bool) -> Result<(), DetailedError> {
+    let error = |cause: &(dyn std::error::Error + 'static)| {
+        return DetailedError::new(ApplicationError::DiagnosticsWriteError).with_path(filepath).caused_by(cause);
+    };
+
+    let file = File::create(filepath).map_err(|err| error(&err))?;
+    let mut writer = ZipWriter::new(file);
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let mut entries: Vec<(String, Vec<u8>)> = vec![("info.txt".to_string(), build_info().into_bytes())];
+    match serde_json::to_vec_pretty(&bundled_settings(current_settings(), anonymize_names)) {
+        Ok(settings) => entries.push(("settings.json".to_string(), settings)),
+        Err(err) => log::warn!("The settings are left out of the diagnostics: {:?}", err),
+    }
+    if let Some(wettkampf) = wettkampf {
+        let wettkampf = if anonymize_names { anonymize(wettkampf) } else { wettkampf.clone() };
+        entries.push(("Wettkampf.wkdata".to_string(), serialize_wk_file(&wettkampf, None)?.into_bytes()));
+    }
+    // The logs may also name the judges of other competitions, so no placeholders could make them anonymous
+    let log_files = if anonymize_names { Vec::new() } else { recent_log_files(DIAGNOSTICS_LOG_SESSIONS) };
+    for log_file in log_files {
+        let name = log_file.file_name().unwrap_or_default().to_string_lossy().to_string();
+        match std::fs::read(&log_file) {
+            Ok(contents) => entries.push((format!("Logs/{name}"), contents)),
+            // A missing log should not cost the user the rest of the bundle
+            Err(err) => log::warn!("The log {} is left out of the diagnostics: {:?}", log_file.display(), err),
+        }
+    }
+
+    for (name, contents) in entries {
+        writer.start_file(name, options).map_err(|err| error(&err))?;
+        writer.write_all(&contents).map_err(|err| error(&err))?;
+    }
+    writer.finish().map_err(|err| error(&err))?;
+    return Ok(());
+}
+
+/// Removes the password from the settings, which are handed around on USB sticks with the bundle.
+/// Param 2: Whether to also remove everything that names the user: the responsible person, the mail account and addresses
+/// and the folders, which contain the user name on most systems.
+fn bundled_settings(mut settings: Settings, anonymize_names: bool) -> Settings {
+    let mut secrets = vec![&mut settings.smtp_password];
+    if anonymize_names {
+        secrets.extend([
+            &mut settings.smtp_username,
+            &mut settings.mail_from,
+            &mut settings.mail_to,
+            &mut settings.default_responsible_person,
+        ]);
+    }
+    for value in secrets {
+        if !value.trim().is_empty() {
+            *value = REDACTED.to_string();
+        }
+    }
+    if anonymize_names {
+        for dir in [&mut settings.mail_dir, &mut settings.default_save_dir, &mut settings.template_dir] {
+            if dir.is_some() {
+                *dir = Some(PathBuf::from(REDACTED));
+            }
+        }
+    }
+    return settings;
+}
+
+/// Returns: The version and build of the app and the system it runs on, as text.
+/// The sysinfo of vergen describes the machine the app was built on, not the one it runs on.
+fn build_info() -> String {
+    let lines = [
+        format!("DTB Kampfrichtereinsatzpläne v{APP_VERSION}"),
+        format!("Created: {}", Local::now().format("%Y-%m-%d %H:%M:%S")),
+        format!("Running on: {} ({})", std::env::consts::OS, std::env::consts::ARCH),
+        String::new(),
+        format!("[TARGET_TRIPLE]: {TARGET_TRIPLE}"),
+        format!("[LLVM]: LLVM {LLVM_VER}"),
+        format!("[RUSTC]: {}", option_env!("VERGEN_RUSTC_SEMVER").unwrap_or("unknown")),
+        format!("[BUILD_TIMESTAMP]: {}", option_env!("VERGEN_BUILD_TIMESTAMP").unwrap_or("unknown")),
+        format!("[CARGO_PROFILE]: {}", if cfg!(debug_assertions) { "debug" } else { "release" }),
+        format!("[CARGO_FEATURES]: {}", option_env!("VERGEN_CARGO_FEATURES").unwrap_or("unknown")),
+        String::new(),
+        format!("[BUILD_OS]: {}", option_env!("VERGEN_SYSINFO_NAME").unwrap_or("unknown")),
+        format!("[BUILD_OS_VERSION]: {}", option_env!("VERGEN_SYSINFO_OS_VERSION").unwrap_or("unknown")),
+        format!("[BUILD_CPU]: {}", option_env!("VERGEN_SYSINFO_CPU_BRAND").unwrap_or("unknown")),
+        format!("[BUILD_CPU_CORES]: {}", option_env!("VERGEN_SYSINFO_CPU_CORE_COUNT").unwrap_or("unknown")),
+        format!("[BUILD_MEMORY]: {}", option_env!("VERGEN_SYSINFO_TOTAL_MEMORY").unwrap_or("unknown")),
+    ];
+    return lines.join("\n") + "\n";
+}
+
+/// Replaces every name of a person and every club with a placeholder, e.g. "Kampfrichter 3" and "Verein 2".
+/// The same name always gets the same placeholder (see name_key), so duplicates and club conflicts
+/// still show up in the anonymized competition. The name, date and place of the competition are kept.
+pub fn anonymize(wettkampf: &Wettkampf) -> Wettkampf {
+    let mut judges = Placeholders::new("Kampfrichter");
+    let mut clubs = Placeholders::new("Verein");
+    let mut wettkampf = wettkampf.clone();
+
+    if !wettkampf.wk_responsible_person.trim().is_empty() {
+        wettkampf.wk_responsible_person = "Verantwortliche/r".to_string();
+    }
+    // Sorted, so the placeholders do not depend on the order of the HashMap
+    let mut tables: Vec<_> = wettkampf.wk_judgingtables.iter_mut().collect();
+    tables.sort_by_key(|(id, _)| *id);
+    for (_, table) in tables {
+        let mut table_judges: Vec<_> = table.judges.iter_mut().collect();
+        table_judges.sort_by_key(|(role, _)| role.to_string());
+        for (_, judge) in table_judges {
+            judge.name = judges.replace(&judge.name);
+        }
+        for club in table.table_clubs.iter_mut() {
+            *club = clubs.replace(club);
+        }
+    }
+    for name in wettkampf.wk_replacement_judges.iter_mut() {
+        *name = judges.replace(name);
+    }
+    for judge in wettkampf.wk_judge_roster.iter_mut() {
+        judge.name = judges.replace(&judge.name);
+        judge.club = clubs.replace(&judge.club);
+    }
+    return wettkampf;
+}
+
+/// Numbered placeholders for names, empty names stay empty.
+struct Placeholders {
+    prefix: &'static str,
+    assigned: HashMap<String, String>,
+}
+
+impl Placeholders {
+    fn new(prefix: &'static str) -> Placeholders {
+        return Placeholders { prefix, assigned: HashMap::new() };
+    }
+
+    fn replace(&mut self, name: &str) -> String {
+        if name.trim().is_empty() {
+            return name.to_string();
+        }
+        let placeholder = format!("{} {}", self.prefix, self.assigned.len() + 1);
+        return self.assigned.entry(name_key(name)).or_insert(placeholder).clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Kampfgericht, Kampfrichter};
+    use crate::Disciplines::{Discipline, JudgeRole};
+    use crate::Roster::{LicenseLevel, RosterJudge};
+    use std::io::Read;
+    use zip::ZipArchive;
+
+    fn judge(role: JudgeRole, name: &str) -> (JudgeRole, Kampfrichter) {
+        let judge = Kampfrichter {
+            role: role.clone(),
+            name: name.to_string(),
+            doubleFound: false,
+        };
+        return (role, judge);
+    }
+
+    fn wettkampf() -> Wettkampf {
+        let first = Kampfgericht {
+            uniqueID: "1".to_string(),
+            table_name: "KG 1".to_string(),
+            table_kind: Discipline::Sprung,
+            table_clubs: vec!["TV Nordheim".to_string()],
+            judges: HashMap::from([judge(JudgeRole::Ok, "Anna Schmidt"), judge(JudgeRole::Sk1, "Ben Weber")]),
+            ..Kampfgericht::default()
+        };
+        let second = Kampfgericht {
+            uniqueID: "2".to_string(),
+            table_name: "KG 2".to_string(),
+            table_kind: Discipline::Sprung,
+            table_clubs: vec!["tv nordheim ".to_string(), "TSV Südstadt".to_string()],
+            judges: HashMap::from([judge(JudgeRole::Ok, "anna schmidt"), judge(JudgeRole::Sk1, "")]),
+            ..Kampfgericht::default()
+        };
+        return Wettkampf {
+            wk_name: "Landesmeisterschaft".to_string(),
+            wk_place: "Stuttgart".to_string(),
+            wk_responsible_person: "Maria Keller".to_string(),
+            wk_replacement_judges: vec!["Paul Wagner".to_string(), "Ben Weber".to_string()],
+            wk_judge_roster: vec![RosterJudge {
+                name: "Clara Wolf".to_string(),
+                club: "SC Westend".to_string(),
+                license: Some(LicenseLevel::B),
+                disciplines: Vec::new(),
+            }],
+            wk_judgingtables: HashMap::from([("1".to_string(), first), ("2".to_string(), second)]),
+            ..Wettkampf::default()
+        };
+    }
+
+    /// Every text that must not be found in an anonymized bundle, in lowercase.
+    const PERSONAL: [&str; 8] = ["anna", "schmidt", "ben weber", "paul wagner", "clara wolf", "maria keller", "nordheim", "südstadt"];
+
+    fn settings() -> Settings {
+        return Settings {
+            smtp_username: "maria.keller".to_string(),
+            smtp_password: "geheim".to_string(),
+            mail_from: "maria.keller@tv-nordheim.de".to_string(),
+            mail_to: "support@stb.de".to_string(),
+            default_responsible_person: "Maria Keller".to_string(),
+            default_save_dir: Some(PathBuf::from("/home/maria/Wettkämpfe")),
+            ..Settings::default()
+        };
+    }
+
+    #[test]
+    fn anonymize_replaces_every_name_consistently() {
+        let anonymized = anonymize(&wettkampf());
+        let tables = &anonymized.wk_judgingtables;
+
+        assert_eq!(anonymized.wk_name, "Landesmeisterschaft");
+        assert_eq!(anonymized.wk_place, "Stuttgart");
+        assert_eq!(anonymized.wk_responsible_person, "Verantwortliche/r");
+        // The same person, written differently, still is one person
+        assert_eq!(tables["1"].judges[&JudgeRole::Ok].name, "Kampfrichter 1");
+        assert_eq!(tables["2"].judges[&JudgeRole::Ok].name, "Kampfrichter 1");
+        assert_eq!(tables["1"].judges[&JudgeRole::Sk1].name, "Kampfrichter 2");
+        assert_eq!(tables["2"].judges[&JudgeRole::Sk1].name, "");
+        assert_eq!(anonymized.wk_replacement_judges, vec!["Kampfrichter 3", "Kampfrichter 2"]);
+        assert_eq!(tables["1"].table_clubs, vec!["Verein 1"]);
+        assert_eq!(tables["2"].table_clubs, vec!["Verein 1", "Verein 2"]);
+        assert_eq!(anonymized.wk_judge_roster[0].name, "Kampfrichter 4");
+        assert_eq!(anonymized.wk_judge_roster[0].club, "Verein 3");
+        assert_eq!(anonymized.wk_judge_roster[0].license, Some(LicenseLevel::B));
+    }
+
+    #[test]
+    fn anonymize_keeps_the_conflicts() {
+        let mut anonymized = anonymize(&wettkampf());
+        crate::Validation::mark_conflicts(&mut anonymized.wk_judgingtables);
+
+        // Preliminaries only, so the judge in both tables is a conflict
+        assert!(anonymized.wk_judgingtables["1"].judges[&JudgeRole::Ok].doubleFound);
+    }
+
+    #[test]
+    fn anonymized_settings_name_nobody() {
+        let bundled = serde_json::to_string(&bundled_settings(settings(), true)).unwrap();
+
+        for text in ["maria", "Maria", "Keller", "geheim", "nordheim", "stb.de"] {
+            assert!(!bundled.contains(text), "{text} is in the settings");
+        }
+    }
+
+    #[test]
+    fn unchanged_settings_only_lose_the_password() {
+        let bundled = bundled_settings(settings(), false);
+
+        assert_eq!(bundled.smtp_password, REDACTED);
+        assert_eq!(Settings { smtp_password: "geheim".to_string(), ..bundled }, settings());
+    }
+
+    #[test]
+    fn an_anonymized_bundle_names_nobody() {
+        let path = std::env::temp_dir().join(format!("kre-diagnostics-{}.zip", std::process::id()));
+        write_diagnostics(&path, Some(&wettkampf()), true).unwrap();
+        let mut archive = ZipArchive::new(File::open(&path).unwrap()).unwrap();
+
+        let mut names = Vec::new();
+        for index in 0..archive.len() {
+            let mut entry = archive.by_index(index).unwrap();
+            let mut contents = String::new();
+            entry.read_to_string(&mut contents).unwrap();
+            for text in PERSONAL {
+                assert!(!contents.to_lowercase().contains(text), "{text} is in {}", entry.name());
+            }
+            // Whatever settings the machine running the tests has
+            let settings = current_settings();
+            for text in [&settings.mail_from, &settings.mail_to, &settings.smtp_username] {
+                assert!(text.is_empty() || !contents.contains(text.as_str()), "{text} is in {}", entry.name());
+            }
+            names.push(entry.name().to_string());
+        }
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(names, vec!["info.txt", "settings.json", "Wettkampf.wkdata"]);
+    }
+}
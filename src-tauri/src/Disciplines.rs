@@ -0,0 +1,312 @@
+use serde::{Deserialize, Serialize};
+
+/// The disciplines a Kampfgericht can judge.
+/// Serialized as the display name, so files keep the plain strings of earlier versions.
+/// Unknown names (e.g. from a newer version) are kept in Other and survive a round trip.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Discipline {
+    GeradeturnenOhneMusik,
+    GeradeturnenAufMusik,
+    Spiraleturnen,
+    Sprung,
+    ArtistischesProgramm,
+    TechnischesProgramm,
+    Other(String),
+}
+
+/// The roles of the judges inside a Kampfgericht.
+/// Serialized as the lowercase keys used by earlier versions ("ok", "sk1", "aik4", ...).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum JudgeRole {
+    Ok,
+    Sk1,
+    Sk2,
+    Ak1,
+    Ak2,
+    Ak3,
+    Ak4,
+    Aik1,
+    Aik2,
+    Aik3,
+    Aik4,
+    Other(String),
+}
+
+/// A single role slot of a discipline, as shown in the editor and the plans.
+#[derive(Debug, Clone, Serialize)]
+pub struct RoleSchema {
+    pub role: JudgeRole,
+    pub label: String,
+    pub required: bool,
+}
+
+/// Describes which roles a discipline has and to which sport it belongs.
+#[derive(Debug, Clone, Serialize)]
+pub struct DisciplineSchema {
+    pub discipline: Discipline,
+    pub sport: String,
+    pub roles: Vec<RoleSchema>,
+}
+
+/// All disciplines in the order they are offered in the editor.
+/// Adding a discipline only requires a new variant and an entry here.
+pub static DISCIPLINES: &[Discipline] = &[
+    Discipline::GeradeturnenOhneMusik,
+    Discipline::GeradeturnenAufMusik,
+    Discipline::Spiraleturnen,
+    Discipline::Sprung,
+    Discipline::ArtistischesProgramm,
+    Discipline::TechnischesProgramm,
+];
+
+impl Discipline {
+    pub fn as_str(&self) -> &str {
+        return match self {
+            Discipline::GeradeturnenOhneMusik => "Geradeturnen ohne Musik",
+            Discipline::GeradeturnenAufMusik => "Geradeturnen auf Musik",
+            Discipline::Spiraleturnen => "Spiraleturnen",
+            Discipline::Sprung => "Sprung",
+            Discipline::ArtistischesProgramm => "Artistisches Programm",
+            Discipline::TechnischesProgramm => "Technisches Programm",
+            Discipline::Other(name) => name.as_str(),
+        };
+    }
+
+    /// The sport the discipline belongs to, used for grouping in the editor.
+    pub fn sport(&self) -> &str {
+        return match self {
+            Discipline::GeradeturnenOhneMusik
+            | Discipline::GeradeturnenAufMusik
+            | Discipline::Spiraleturnen
+            | Discipline::Sprung => "Rhönradturnen",
+            Discipline::ArtistischesProgramm | Discipline::TechnischesProgramm => "Cyr Wheel",
+            Discipline::Other(_) => "Sonstige",
+        };
+    }
+
+    /// Whether the discipline is judged with artistic judges (AIK) in addition to the execution judges.
+    pub fn has_artistic_judges(&self) -> bool {
+        return matches!(self, Discipline::GeradeturnenAufMusik | Discipline::ArtistischesProgramm);
+    }
+
+    /// Returns: The role slots of this discipline, or None for unknown disciplines.
+    pub fn roles(&self) -> Option<Vec<RoleSchema>> {
+        if let Discipline::Other(_) = self {
+            return None;
+        }
+
+        let mut roles = vec![
+            RoleSchema::new(JudgeRole::Ok, true),
+            RoleSchema::new(JudgeRole::Sk1, true),
+            RoleSchema::new(JudgeRole::Sk2, false),
+            RoleSchema::new(JudgeRole::Ak1, true),
+            RoleSchema::new(JudgeRole::Ak2, true),
+            RoleSchema::new(JudgeRole::Ak3, false),
+            RoleSchema::new(JudgeRole::Ak4, false),
+        ];
+        if self.has_artistic_judges() {
+            roles.append(&mut vec![
+                RoleSchema::new(JudgeRole::Aik1, true),
+                RoleSchema::new(JudgeRole::Aik2, true),
+                RoleSchema::new(JudgeRole::Aik3, false),
+                RoleSchema::new(JudgeRole::Aik4, false),
+            ]);
+        }
+        return Some(roles);
+    }
+
+    pub fn schema(&self) -> Option<DisciplineSchema> {
+        return Some(DisciplineSchema {
+            discipline: self.clone(),
+            sport: self.sport().to_string(),
+            roles: self.roles()?,
+        });
+    }
+}
+
+impl JudgeRole {
+    pub fn as_str(&self) -> &str {
+        return match self {
+            JudgeRole::Ok => "ok",
+            JudgeRole::Sk1 => "sk1",
+            JudgeRole::Sk2 => "sk2",
+            JudgeRole::Ak1 => "ak1",
+            JudgeRole::Ak2 => "ak2",
+            JudgeRole::Ak3 => "ak3",
+            JudgeRole::Ak4 => "ak4",
+            JudgeRole::Aik1 => "aik1",
+            JudgeRole::Aik2 => "aik2",
+            JudgeRole::Aik3 => "aik3",
+            JudgeRole::Aik4 => "aik4",
+            JudgeRole::Other(role) => role.as_str(),
+        };
+    }
+
+    /// The label shown in front of the input field ("OK", "SK1", ...).
+    pub fn label(&self) -> String {
+        return self.as_str().to_uppercase();
+    }
+}
+
+impl RoleSchema {
+    fn new(role: JudgeRole, required: bool) -> Self {
+        return RoleSchema {
+            label: role.label(),
+            role,
+            required,
+        };
+    }
+}
+
+/// Returns: The schema of every known discipline, in editor order.
+pub fn discipline_schemas() -> Vec<DisciplineSchema> {
+    return DISCIPLINES.iter().filter_map(|discipline| discipline.schema()).collect();
+}
+
+impl Default for Discipline {
+    fn default() -> Self {
+        return Discipline::Other(String::new());
+    }
+}
+
+impl Default for JudgeRole {
+    fn default() -> Self {
+        return JudgeRole::Other(String::new());
+    }
+}
+
+impl From<String> for Discipline {
+    fn from(value: String) -> Self {
+        return match DISCIPLINES.iter().find(|discipline| discipline.as_str() == value) {
+            Some(discipline) => discipline.clone(),
+            None => Discipline::Other(value),
+        };
+    }
+}
+
+impl From<Discipline> for String {
+    fn from(value: Discipline) -> Self {
+        return match value {
+            Discipline::Other(name) => name,
+            known => known.as_str().to_string(),
+        };
+    }
+}
+
+impl From<String> for JudgeRole {
+    fn from(value: String) -> Self {
+        return match value.as_str() {
+            "ok" => JudgeRole::Ok,
+            "sk1" => JudgeRole::Sk1,
+            "sk2" => JudgeRole::Sk2,
+            "ak1" => JudgeRole::Ak1,
+            "ak2" => JudgeRole::Ak2,
+            "ak3" => JudgeRole::Ak3,
+            "ak4" => JudgeRole::Ak4,
+            "aik1" => JudgeRole::Aik1,
+            "aik2" => JudgeRole::Aik2,
+            "aik3" => JudgeRole::Aik3,
+            "aik4" => JudgeRole::Aik4,
+            _ => JudgeRole::Other(value),
+        };
+    }
+}
+
+impl From<JudgeRole> for String {
+    fn from(value: JudgeRole) -> Self {
+        return match value {
+            JudgeRole::Other(role) => role,
+            known => known.as_str().to_string(),
+        };
+    }
+}
+
+impl std::fmt::Display for Discipline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{}", self.as_str());
+    }
+}
+
+impl std::fmt::Display for JudgeRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{}", self.as_str());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const LEGACY_DISCIPLINES: [&str; 6] = [
+        "Geradeturnen ohne Musik",
+        "Geradeturnen auf Musik",
+        "Spiraleturnen",
+        "Sprung",
+        "Artistisches Programm",
+        "Technisches Programm",
+    ];
+    const LEGACY_ROLES: [&str; 11] = ["ok", "sk1", "sk2", "ak1", "ak2", "ak3", "ak4", "aik1", "aik2", "aik3", "aik4"];
+
+    #[test]
+    fn known_disciplines_keep_their_legacy_names() {
+        assert_eq!(DISCIPLINES.len(), LEGACY_DISCIPLINES.len());
+        for (discipline, name) in DISCIPLINES.iter().zip(LEGACY_DISCIPLINES) {
+            let json = format!("\"{name}\"");
+            assert_eq!(serde_json::to_string(discipline).unwrap(), json);
+            assert_eq!(&serde_json::from_str::<Discipline>(&json).unwrap(), discipline);
+        }
+    }
+
+    #[test]
+    fn known_roles_keep_their_legacy_keys() {
+        for key in LEGACY_ROLES {
+            let json = format!("\"{key}\"");
+            let role: JudgeRole = serde_json::from_str(&json).unwrap();
+            assert!(!matches!(role, JudgeRole::Other(_)), "{key} is unknown");
+            assert_eq!(serde_json::to_string(&role).unwrap(), json);
+        }
+        // As keys of the judges of a table
+        let judges: HashMap<JudgeRole, String> = serde_json::from_str(r#"{"ok":"Anna Schmidt","aik4":"Ben Weber"}"#).unwrap();
+        assert_eq!(judges[&JudgeRole::Aik4], "Ben Weber");
+        assert_eq!(serde_json::from_str::<HashMap<JudgeRole, String>>(&serde_json::to_string(&judges).unwrap()).unwrap(), judges);
+    }
+
+    #[test]
+    fn unknown_names_survive_a_round_trip() {
+        let discipline: Discipline = serde_json::from_str("\"Cyr Wheel Paar\"").unwrap();
+        assert_eq!(discipline, Discipline::Other("Cyr Wheel Paar".to_string()));
+        assert_eq!(serde_json::to_string(&discipline).unwrap(), "\"Cyr Wheel Paar\"");
+        assert!(discipline.roles().is_none());
+        assert!(discipline.schema().is_none());
+
+        let role: JudgeRole = serde_json::from_str("\"tk1\"").unwrap();
+        assert_eq!(role, JudgeRole::Other("tk1".to_string()));
+        assert_eq!(serde_json::to_string(&role).unwrap(), "\"tk1\"");
+        // Names are compared exactly, so nothing is changed on the way
+        assert_eq!(Discipline::from("sprung".to_string()), Discipline::Other("sprung".to_string()));
+        assert_eq!(JudgeRole::from("OK".to_string()), JudgeRole::Other("OK".to_string()));
+    }
+
+    #[test]
+    fn the_schemas_match_the_roles() {
+        let schemas = discipline_schemas();
+        assert_eq!(schemas.len(), DISCIPLINES.len());
+        for (schema, discipline) in schemas.iter().zip(DISCIPLINES) {
+            assert_eq!(&schema.discipline, discipline);
+            assert_eq!(schema.sport, discipline.sport());
+            let roles: Vec<(JudgeRole, bool)> = discipline.roles().unwrap().into_iter().map(|role| (role.role, role.required)).collect();
+            let schema_roles: Vec<(JudgeRole, bool)> = schema.roles.iter().map(|role| (role.role.clone(), role.required)).collect();
+            assert_eq!(schema_roles, roles);
+            assert!(schema.roles.iter().all(|role| role.label == role.role.as_str().to_uppercase()));
+            // Every discipline has a supervisor and two execution judges
+            for role in [JudgeRole::Ok, JudgeRole::Ak1, JudgeRole::Ak2] {
+                assert!(schema.roles.iter().any(|schema_role| schema_role.role == role && schema_role.required));
+            }
+            let has_artistic_judges = schema.roles.iter().any(|role| role.role == JudgeRole::Aik1);
+            assert_eq!(has_artistic_judges, discipline.has_artistic_judges());
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use crate::types::{ApplicationError, FrontendStorage, Wettkampf};
+use crate::Autosave::new_autosave_id;
+use crate::History::{diff, History};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+// Every editor window edits its own Wettkampf. The documents are kept in a registry keyed by the
+// label of their window, so the commands find the document of the window that called them.
+
+/// A Wettkampf opened in an editor window.
+#[derive(Debug, Default, Clone)]
+pub struct Document {
+    pub wettkampf: Wettkampf,
+    /// Where the Wettkampf was last saved to or opened from, None if it was never saved.
+    pub save_path: Option<PathBuf>,
+    /// The position of the history the file was saved at, see History::position.
+    /// None if the Wettkampf was never saved or differs from the file in a way the history does not know about.
+    pub saved_position: Option<u64>,
+    /// Name of the autosave of the document, see Autosave.rs.
+    pub autosave_id: String,
+    /// The changes that can be undone and redone, see History.rs.
+    pub history: History,
+}
+
+/// The global state managed by Tauri. All documents sit behind a single lock
+/// and are only ever replaced as a whole, so no command sees a half-applied update.
+#[derive(Debug, Default)]
+pub struct DocumentRegistry {
+    documents: Mutex<HashMap<String, Document>>,
+    next_id: AtomicU32,
+}
+
+impl Document {
+    /// A Wettkampf that was never saved counts as changed, so closing its window asks for saving it.
+    pub fn new(wettkampf: Wettkampf, save_path: Option<PathBuf>) -> Document {
+        let history = History::default();
+        return Document {
+            wettkampf,
+            saved_position: save_path.as_ref().map(|_| history.position()),
+            save_path,
+            autosave_id: new_autosave_id(),
+            history,
+        };
+    }
+
+    /// A document restored from its autosave. It keeps the autosave until it is saved and counts as changed.
+    pub fn restored(wettkampf: Wettkampf, save_path: Option<PathBuf>, autosave_id: String) -> Document {
+        return Document {
+            wettkampf,
+            save_path,
+            saved_position: None,
+            autosave_id,
+            history: History::default(),
+        };
+    }
+
+    /// Changes the Wettkampf and records the change in the history. Only a real change marks the document as changed.
+    pub fn change(&mut self, change: impl FnOnce(&mut Wettkampf)) {
+        let previous = self.wettkampf.clone();
+        change(&mut self.wettkampf);
+        self.history.record(diff(&previous, &self.wettkampf));
+    }
+
+    /// Returns: Whether the Wettkampf differs from the file, i.e. the history moved away from where it was saved.
+    /// Undoing all changes since saving makes the document unchanged again.
+    pub fn is_dirty(&self) -> bool {
+        return self.saved_position != Some(self.history.position());
+    }
+
+    /// The title of the editor window: name of the Wettkampf, file name and whether everything is saved.
+    pub fn title(&self) -> String {
+        let state = if self.is_dirty() { "nicht gespeichert" } else { "gespeichert" };
+        return match self.save_path.as_ref().and_then(|path| path.file_name()) {
+            Some(file_name) => format!("{} - {} ({state})", self.wettkampf.wk_name, file_name.to_string_lossy()),
+            None => format!("{} ({state})", self.wettkampf.wk_name),
+        };
+    }
+}
+
+impl DocumentRegistry {
+    /// Registers a document under a new window label.
+    /// Returns: The label the editor window of the document has to be created with.
+    pub fn open(&self, document: Document) -> String {
+        let label = format!("editor-{}", self.next_id.fetch_add(1, Ordering::Relaxed) + 1);
+        self.lock().insert(label.clone(), document);
+        return label;
+    }
+
+    /// Removes the document of a closed window. Unknown labels (windows without document) are ignored.
+    pub fn close(&self, label: &str) -> Option<Document> {
+        return self.lock().remove(label);
+    }
+
+    /// Returns: Whether a document is open in the window.
+    pub fn contains(&self, label: &str) -> bool {
+        return self.lock().contains_key(label);
+    }
+
+    /// Returns: A copy of the document of the window, so no lock is held while working with it.
+    pub fn get(&self, label: &str) -> Result<Document, ApplicationError> {
+        return match self.lock().get(label) {
+            Some(document) => Ok(document.clone()),
+            None => {
+                log::error!("No document is open in the window {label}");
+                Err(ApplicationError::DocumentNotFoundError)
+            }
+        };
+    }
+
+    /// Changes the document of the window while holding the lock, so two changes at the same time never overwrite each other.
+    /// The closure works on a copy, which only replaces the document once the closure is done.
+    /// The closure must not call back into the registry, the lock is not reentrant.
+    /// Returns: The result of the closure.
+    pub fn update<T>(&self, label: &str, change: impl FnOnce(&mut Document) -> T) -> Result<T, ApplicationError> {
+        let mut documents = self.lock();
+        let document = match documents.get_mut(label) {
+            Some(document) => document,
+            None => {
+                log::error!("No document is open in the window {label}");
+                return Err(ApplicationError::DocumentNotFoundError);
+            }
+        };
+        let mut changed = document.clone();
+        let result = change(&mut changed);
+        *document = changed;
+        return Ok(result);
+    }
+
+    /// Applies the data edited in the frontend to the document of the window, see Wettkampf::apply_frontend.
+    /// The document only counts as changed if the data differs from the one it had, see Document::change.
+    /// Returns: A copy of the updated Wettkampf.
+    pub fn apply_frontend(&self, label: &str, frontend_storage: FrontendStorage) -> Result<Wettkampf, ApplicationError> {
+        return self.update(label, |document| {
+            document.change(|wettkampf| wettkampf.apply_frontend(frontend_storage));
+            return document.wettkampf.clone();
+        });
+    }
+
+    /// Returns: Whether the window has a document with unsaved changes, false for windows without document.
+    pub fn is_dirty(&self, label: &str) -> bool {
+        return self.lock().get(label).is_some_and(|document| document.is_dirty());
+    }
+
+    /// Returns: The labels of all windows whose document has unsaved changes.
+    pub fn dirty_labels(&self) -> Vec<String> {
+        return self
+            .lock()
+            .iter()
+            .filter(|(_, document)| document.is_dirty())
+            .map(|(label, _)| label.clone())
+            .collect();
+    }
+
+    /// Returns: Copies of all documents with unsaved changes.
+    pub fn dirty_documents(&self) -> Vec<Document> {
+        return self.lock().values().filter(|document| document.is_dirty()).cloned().collect();
+    }
+
+    /// Returns: The label of the window the file is already open in, if any.
+    pub fn find_by_path(&self, path: &Path) -> Option<String> {
+        return self
+            .lock()
+            .iter()
+            .find(|(_, document)| document.save_path.as_deref() == Some(path))
+            .map(|(label, _)| label.clone());
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, Document>> {
+        // The documents are only replaced as a whole, so they are consistent even if a thread panicked while holding the lock
+        return match self.documents.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                log::warn!("Recovering the poisoned lock of the documents: {:?}", poisoned);
+                poisoned.into_inner()
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rename(document: &mut Document, name: &str) {
+        document.change(|wettkampf| wettkampf.wk_name = name.to_string());
+    }
+
+    #[test]
+    fn a_new_document_is_only_unchanged_if_it_was_opened_from_a_file() {
+        assert!(Document::new(Wettkampf::default(), None).is_dirty());
+        assert!(!Document::new(Wettkampf::default(), Some(PathBuf::from("Wettkampf.wkdata"))).is_dirty());
+        assert!(Document::restored(Wettkampf::default(), Some(PathBuf::from("Wettkampf.wkdata")), "1".to_string()).is_dirty());
+    }
+
+    #[test]
+    fn undoing_back_to_the_saved_state_is_unchanged() {
+        let mut document = Document::new(Wettkampf::default(), Some(PathBuf::from("Wettkampf.wkdata")));
+        rename(&mut document, "Landesmeisterschaft");
+        assert!(document.is_dirty());
+
+        document.history.undo(&mut document.wettkampf);
+        assert!(!document.is_dirty());
+        document.history.redo(&mut document.wettkampf);
+        assert!(document.is_dirty());
+
+        // Saved after the change, then undone
+        document.saved_position = Some(document.history.position());
+        assert!(!document.is_dirty());
+        document.history.undo(&mut document.wettkampf);
+        assert!(document.is_dirty());
+    }
+
+    #[test]
+    fn a_change_without_difference_keeps_the_document_unchanged() {
+        let mut document = Document::new(Wettkampf::default(), Some(PathBuf::from("Wettkampf.wkdata")));
+        rename(&mut document, "");
+
+        assert!(!document.is_dirty());
+    }
+}
@@ -0,0 +1,580 @@
+use crate::types::{ApplicationError, Kampfgericht, Wettkampf};
+use crate::Disciplines::JudgeRole;
+use crate::Resources::read_template;
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+static TEMPLATE_FILE_NAME: &str = "Vorlage_Einsatzplan_Leer.docx";
+static TABLE_FILE_NAME: &str = "Tabelle_Vorlage_Leer.docx";
+pub static DOCUMENT_PART: &str = "word/document.xml";
+
+static PAGE_BREAK: &str = r#"<w:p><w:r><w:br w:type="page"/></w:r></w:p>"#;
+
+/// Tables with more rows than this still contain the rows of the artistic judges.
+const REGULAR_TABLE_MAX_ROWS: usize = 12;
+
+/// The judge rows of the table template.
+/// Each entry is the role, the prefix of its placeholders and the number shown in the label.
+static TEMPLATE_ROLES: &[(JudgeRole, &str, u8)] = &[
+    (JudgeRole::Ok, "OK", 1),
+    (JudgeRole::Sk1, "SK", 1),
+    (JudgeRole::Sk2, "SK", 2),
+    (JudgeRole::Ak1, "AK", 1),
+    (JudgeRole::Ak2, "AK", 2),
+    (JudgeRole::Ak3, "AK", 3),
+    (JudgeRole::Ak4, "AK", 4),
+    (JudgeRole::Aik1, "AIK", 1),
+    (JudgeRole::Aik2, "AIK", 2),
+    (JudgeRole::Aik3, "AIK", 3),
+    (JudgeRole::Aik4, "AIK", 4),
+];
+
+/// A .docx file opened in memory.
+/// Replaced parts are written on save, all other parts are copied over unchanged.
+pub struct DocxPackage {
+    archive: ZipArchive<Cursor<Vec<u8>>>,
+    replaced_parts: HashMap<String, Vec<u8>>,
+}
+
+impl DocxPackage {
+    pub fn open(bytes: Vec<u8>) -> Result<Self, String> {
+        return match ZipArchive::new(Cursor::new(bytes)) {
+            Ok(archive) => Ok(DocxPackage {
+                archive,
+                replaced_parts: HashMap::new(),
+            }),
+            Err(err) => Err(format!("Could not open the document as zip archive: {err}")),
+        };
+    }
+
+    /// Param 1: The path of the part inside the package, e.g. "word/document.xml".
+    /// Returns: The content of the part, None if the package does not contain it.
+    pub fn read_part(&mut self, name: &str) -> Result<Option<Vec<u8>>, String> {
+        if let Some(content) = self.replaced_parts.get(name) {
+            return Ok(Some(content.clone()));
+        }
+        let mut file = match self.archive.by_name(name) {
+            Ok(file) => file,
+            Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+            Err(err) => return Err(format!("Could not open {name}: {err}")),
+        };
+        let mut content = Vec::new();
+        return match file.read_to_end(&mut content) {
+            Ok(_) => Ok(Some(content)),
+            Err(err) => Err(format!("Could not read {name}: {err}")),
+        };
+    }
+
+    /// Like read_part, but for XML parts that have to exist.
+    pub fn read_xml_part(&mut self, name: &str) -> Result<String, String> {
+        return match self.read_part(name)? {
+            Some(content) => String::from_utf8(content).map_err(|err| format!("{name} is not valid UTF-8: {err}")),
+            None => Err(format!("The document does not contain {name}")),
+        };
+    }
+
+    pub fn replace_part(&mut self, name: &str, content: Vec<u8>) {
+        self.replaced_parts.insert(name.to_string(), content);
+    }
+
+    /// Returns: The complete .docx file.
+    pub fn assemble(&mut self) -> Result<Vec<u8>, String> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        for index in 0..self.archive.len() {
+            let file = match self.archive.by_index_raw(index) {
+                Ok(file) => file,
+                Err(err) => return Err(format!("Could not read entry {index} of the template: {err}")),
+            };
+            if self.replaced_parts.contains_key(file.name()) {
+                continue;
+            }
+            match writer.raw_copy_file(file) {
+                Ok(()) => {}
+                Err(err) => return Err(format!("Could not copy entry {index} of the template: {err}")),
+            }
+        }
+
+        // Sorted, so the same input always produces the same file
+        let mut replaced_parts: Vec<(&String, &Vec<u8>)> = self.replaced_parts.iter().collect();
+        replaced_parts.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, content) in replaced_parts {
+            match writer.start_file(name.as_str(), options) {
+                Ok(()) => {}
+                Err(err) => return Err(format!("Could not add {name}: {err}")),
+            }
+            match writer.write_all(content) {
+                Ok(()) => {}
+                Err(err) => return Err(format!("Could not write {name}: {err}")),
+            }
+        }
+
+        return match writer.finish() {
+            Ok(cursor) => Ok(cursor.into_inner()),
+            Err(err) => Err(format!("Could not finish the document: {err}")),
+        };
+    }
+}
+
+/// Decides where page breaks go, so tables are never split across pages.
+/// A page fits three regular tables, two tables with artistic judges or one of each.
+/// The first page also contains the header of the plan and fits one table less.
+struct PageFiller {
+    music_tables: u8,
+    regular_tables: u8,
+    first_page: bool,
+}
+
+impl PageFiller {
+    fn new() -> Self {
+        return PageFiller {
+            music_tables: 0,
+            regular_tables: 0,
+            first_page: true,
+        };
+    }
+
+    /// Counts the table and tells whether it has to start on a new page.
+    fn needs_break_before(&mut self, is_music_table: bool) -> bool {
+        let page_full = self.music_tables >= 2
+            || (self.music_tables == 1 && self.regular_tables == 1)
+            || self.regular_tables == 3;
+        let first_page_full = self.first_page
+            && match is_music_table {
+                true => self.music_tables == 1,
+                false => self.regular_tables == 2,
+            };
+        if page_full || first_page_full {
+            self.first_page = false;
+            self.music_tables = is_music_table as u8;
+            self.regular_tables = !is_music_table as u8;
+            return true;
+        }
+        match is_music_table {
+            true => self.music_tables += 1,
+            false => self.regular_tables += 1,
+        }
+        return false;
+    }
+
+    /// Used when a page break is forced, e.g. before the finals.
+    fn start_new_page(&mut self) {
+        self.music_tables = 0;
+        self.regular_tables = 0;
+        self.first_page = false;
+    }
+}
+
+/// Finds all elements with the given tag name and returns their byte ranges.
+/// Elements of the same name nested into each other are not supported, the templates never do that.
+pub fn find_elements(xml: &str, tag: &str) -> Vec<Range<usize>> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+    let mut ranges = Vec::new();
+    let mut position = 0;
+    while let Some(found) = xml[position..].find(&open) {
+        let start = position + found;
+        let after_name = start + open.len();
+        // Skip elements which only start with the same name, e.g. <w:tbl> when looking for <w:t>
+        if !matches!(xml[after_name..].chars().next(), Some('>' | ' ' | '/')) {
+            position = after_name;
+            continue;
+        }
+        let tag_end = match xml[after_name..].find('>') {
+            Some(offset) => after_name + offset + 1,
+            None => break,
+        };
+        if xml[..tag_end].ends_with("/>") {
+            ranges.push(start..tag_end);
+            position = tag_end;
+            continue;
+        }
+        let end = match xml[tag_end..].find(&close) {
+            Some(offset) => tag_end + offset + close.len(),
+            None => break,
+        };
+        ranges.push(start..end);
+        position = end;
+    }
+    return ranges;
+}
+
+/// Returns: The text of all w:t elements in the XML, like InnerText in the OpenXML SDK.
+fn inner_text(xml: &str) -> String {
+    let mut text = String::new();
+    for range in find_elements(xml, "w:t") {
+        let element = &xml[range];
+        let content_start = match element.find('>') {
+            Some(offset) => offset + 1,
+            None => continue,
+        };
+        if element[..content_start].ends_with("/>") {
+            continue;
+        }
+        let content = &element[content_start..element.len() - "</w:t>".len()];
+        match quick_xml::escape::unescape(content) {
+            Ok(unescaped) => text.push_str(&unescaped),
+            Err(_) => text.push_str(content),
+        }
+    }
+    return text;
+}
+
+/// Removes an attribute from every element, e.g. the paragraph ids Word expects to be unique.
+fn strip_attribute(xml: &str, name: &str) -> String {
+    let pattern = format!(" {name}=\"");
+    let mut result = String::with_capacity(xml.len());
+    let mut position = 0;
+    while let Some(found) = xml[position..].find(&pattern) {
+        let start = position + found;
+        let value_start = start + pattern.len();
+        let end = match xml[value_start..].find('"') {
+            Some(offset) => value_start + offset + 1,
+            None => break,
+        };
+        result.push_str(&xml[position..start]);
+        position = end;
+    }
+    result.push_str(&xml[position..]);
+    return result;
+}
+
+/// Replaces the text of the first paragraph of a table cell.
+/// The formatting of the first run (or the paragraph mark, if there is no run) is kept.
+fn set_cell_text(cell: &str, text: &str) -> String {
+    let paragraph = match find_elements(cell, "w:p").into_iter().next() {
+        Some(range) => range,
+        None => return cell.to_string(),
+    };
+    let paragraph_xml = &cell[paragraph.clone()];
+    let runs = find_elements(paragraph_xml, "w:r");
+
+    let run_properties = match runs.first() {
+        Some(run) => find_elements(&paragraph_xml[run.clone()], "w:rPr")
+            .first()
+            .map(|properties| paragraph_xml[run.start + properties.start..run.start + properties.end].to_string()),
+        // Without a run, the only w:rPr is the one of the paragraph mark
+        None => find_elements(paragraph_xml, "w:rPr")
+            .first()
+            .map(|properties| paragraph_xml[properties.clone()].to_string()),
+    };
+    let new_run = match text.is_empty() {
+        true => String::new(),
+        false => format!(
+            r#"<w:r>{}<w:t xml:space="preserve">{}</w:t></w:r>"#,
+            run_properties.unwrap_or_default(),
+            quick_xml::escape::escape(text)
+        ),
+    };
+
+    let mut rebuilt = String::with_capacity(paragraph_xml.len() + new_run.len());
+    if runs.is_empty() {
+        match paragraph_xml.strip_suffix("/>") {
+            Some(open_tag) => {
+                rebuilt.push_str(open_tag);
+                rebuilt.push('>');
+                rebuilt.push_str(&new_run);
+                rebuilt.push_str("</w:p>");
+            }
+            None => {
+                let close_at = paragraph_xml.len() - "</w:p>".len();
+                rebuilt.push_str(&paragraph_xml[..close_at]);
+                rebuilt.push_str(&new_run);
+                rebuilt.push_str(&paragraph_xml[close_at..]);
+            }
+        }
+    } else {
+        let mut position = 0;
+        for (index, run) in runs.iter().enumerate() {
+            rebuilt.push_str(&paragraph_xml[position..run.start]);
+            if index == 0 {
+                rebuilt.push_str(&new_run);
+            }
+            position = run.end;
+        }
+        rebuilt.push_str(&paragraph_xml[position..]);
+    }
+
+    return format!("{}{}{}", &cell[..paragraph.start], rebuilt, &cell[paragraph.end..]);
+}
+
+/// Removes the element with the given tag name which contains the marker text.
+fn replace_element_containing(xml: &str, tag: &str, marker: &str, replacement: &str) -> Result<String, String> {
+    let marker_at = match xml.find(marker) {
+        Some(position) => position,
+        None => return Err(format!("The template does not contain {marker}")),
+    };
+    return match find_elements(xml, tag).into_iter().find(|range| range.contains(&marker_at)) {
+        Some(range) => Ok(format!("{}{}{}", &xml[..range.start], replacement, &xml[range.end..])),
+        None => Err(format!("{marker} is not inside of a {tag} element")),
+    };
+}
+
+/// Fills the placeholders of the competition in the main template.
+fn fill_competition_data(document_xml: &str, data: &Wettkampf) -> String {
+    let replacement_judges = match data.wk_replacement_judges.is_empty() {
+        true => "Keine".to_string(),
+        false => data.wk_replacement_judges.join(", "),
+    };
+    let placeholders = [
+        ("### Wettkampfname ###", data.wk_name.as_str()),
+        ("### Datum ###", data.wk_date.as_str()),
+        ("### Wettkampfort ###", data.wk_place.as_str()),
+        ("### Uhrzeit ###", data.wk_judgesmeeting_time.as_str()),
+        ("### Kampfrichterverantwortlicher ###", data.wk_responsible_person.as_str()),
+        ("### Ersatzkampfrichter ###", replacement_judges.as_str()),
+    ];
+    let mut xml = document_xml.to_string();
+    for (placeholder, value) in placeholders {
+        xml = xml.replace(placeholder, &quick_xml::escape::escape(value));
+    }
+    return xml;
+}
+
+/// Fills one copy of the table template with one or two Kampfgerichte.
+/// Param 1: The w:tbl element of the table template.
+/// Param 2: The Kampfgerichte of the left and (optionally) the right half, each with whether it is a final.
+/// Returns: The filled table and whether it still contains the rows of the artistic judges.
+fn fill_table(table_template: &str, slots: &[(&Kampfgericht, bool)]) -> (String, bool) {
+    let mut replacements: HashMap<String, String> = HashMap::new();
+    for slot in 1..=2 {
+        let entry = slots.get(slot - 1);
+        let (name, discipline) = match entry {
+            Some((table, true)) => (format!("(Finale) {}", table.table_name), table.table_kind.to_string()),
+            Some((table, false)) => (table.table_name.clone(), table.table_kind.to_string()),
+            None => (String::new(), String::new()),
+        };
+        replacements.insert(format!("### Name {slot} ###"), name);
+        replacements.insert(format!("### Disziplin {slot} ###"), discipline);
+
+        for (role, prefix, number) in TEMPLATE_ROLES {
+            // Empty halves stay blank, so do the artistic judges of disciplines without them
+            let has_role = match entry {
+                Some((table, _)) => *prefix != "AIK" || table.table_kind.has_artistic_judges(),
+                None => false,
+            };
+            let judge = match entry.and_then(|(table, _)| table.judges.get(role)) {
+                Some(judge) if has_role => judge.name.clone(),
+                _ => String::new(),
+            };
+            let label = match (has_role, *prefix) {
+                (false, _) => String::new(),
+                (true, "OK") => "OK:".to_string(),
+                (true, _) => format!("{prefix}{number}:"),
+            };
+            // There is only one OK, so its label has no position
+            let (name_placeholder, label_placeholder) = match *prefix {
+                "OK" => (format!("### OK{slot}.1 Name ###"), format!("OK{slot}:")),
+                _ => (format!("### {prefix}{slot}.{number} Name ###"), format!("{prefix}{slot}.{number}:")),
+            };
+            replacements.insert(name_placeholder, judge);
+            replacements.insert(label_placeholder, label);
+        }
+    }
+
+    let mut table = String::with_capacity(table_template.len());
+    let mut position = 0;
+    for cell in find_elements(table_template, "w:tc") {
+        table.push_str(&table_template[position..cell.start]);
+        let cell_xml = &table_template[cell.clone()];
+        match replacements.get(&inner_text(cell_xml)) {
+            Some(text) => table.push_str(&set_cell_text(cell_xml, text)),
+            None => table.push_str(cell_xml),
+        }
+        position = cell.end;
+    }
+    table.push_str(&table_template[position..]);
+
+    // Without artistic judges, the last four rows before the spacing row are not needed
+    let rows = find_elements(&table, "w:tr");
+    let has_artistic_judges = slots.iter().any(|(table, _)| table.table_kind.has_artistic_judges());
+    if !has_artistic_judges && rows.len() >= 5 {
+        let removed = rows[rows.len() - 5].start..rows[rows.len() - 2].end;
+        table.replace_range(removed, "");
+    }
+    let row_count = find_elements(&table, "w:tr").len();
+
+    return (table, row_count > REGULAR_TABLE_MAX_ROWS);
+}
+
+/// Pairs the Kampfgerichte into tables and lays them out on pages, regular ones first, then the finals.
+/// Returns: The XML of all tables and page breaks.
+fn build_tables(table_template: &str, data: &Wettkampf) -> String {
+    let mut regular: Vec<&Kampfgericht> = data.wk_judgingtables.values().filter(|table| !table.table_is_finale).collect();
+    let mut finals: Vec<&Kampfgericht> = data.wk_judgingtables.values().filter(|table| table.table_is_finale).collect();
+    // The uniqueID only keeps the order stable if two tables share a name
+    regular.sort_by(|a, b| a.table_name.cmp(&b.table_name).then(a.uniqueID.cmp(&b.uniqueID)));
+    finals.sort_by(|a, b| a.table_name.cmp(&b.table_name).then(a.uniqueID.cmp(&b.uniqueID)));
+
+    let mut xml = String::new();
+    let mut page = PageFiller::new();
+    for (tables, is_finale) in [(regular, false), (finals, true)] {
+        if tables.is_empty() {
+            continue;
+        }
+        if is_finale {
+            xml.push_str(PAGE_BREAK);
+            page.start_new_page();
+        }
+        for pair in tables.chunks(2) {
+            let slots: Vec<(&Kampfgericht, bool)> = pair.iter().map(|table| (*table, is_finale)).collect();
+            let (table, is_music_table) = fill_table(table_template, &slots);
+            if page.needs_break_before(is_music_table) {
+                xml.push_str(PAGE_BREAK);
+            }
+            xml.push_str(&table);
+        }
+    }
+    return xml;
+}
+
+/// Creates the plan from the templates.
+/// Param 1: The main template's document part.
+/// Param 2: The table template's document part.
+/// Param 3: The data of the competition.
+/// Returns: The filled document part.
+fn fill_document(document_xml: &str, table_document_xml: &str, data: &Wettkampf) -> Result<String, String> {
+    let table_template = match find_elements(table_document_xml, "w:tbl").into_iter().next() {
+        Some(range) => strip_attribute(
+            &strip_attribute(&table_document_xml[range], "w14:paraId"),
+            "w14:textId",
+        ),
+        None => return Err("The table template does not contain a table".to_string()),
+    };
+
+    let xml = fill_competition_data(document_xml, data);
+    // The age groups are not part of the competition data (yet)
+    let xml = replace_element_containing(&xml, "w:tr", "### Altersklassen ###", "")?;
+    let tables = build_tables(&table_template, data);
+    return replace_element_containing(&xml, "w:p", "### Kampfgerichte ###", &tables);
+}
+
+/// Creates the plan in memory.
+/// Param 1: The competition.
+/// Returns: The filled main template or the error to show to the user.
+pub fn generate_plan(data: &Wettkampf) -> Result<DocxPackage, ApplicationError> {
+    let mut package = match read_template(TEMPLATE_FILE_NAME).and_then(DocxPackage::open) {
+        Ok(package) => package,
+        Err(err) => {
+            log::error!("Could not load the template: {err}");
+            return Err(ApplicationError::TemplateReadError);
+        }
+    };
+    let table_document_xml = match read_template(TABLE_FILE_NAME)
+        .and_then(DocxPackage::open)
+        .and_then(|mut table_package| table_package.read_xml_part(DOCUMENT_PART))
+    {
+        Ok(xml) => xml,
+        Err(err) => {
+            log::error!("Could not load the table template: {err}");
+            return Err(ApplicationError::TemplateReadError);
+        }
+    };
+
+    let document_xml = match package
+        .read_xml_part(DOCUMENT_PART)
+        .and_then(|document_xml| fill_document(&document_xml, &table_document_xml, data))
+    {
+        Ok(xml) => xml,
+        Err(err) => {
+            log::error!("Failed to fill the template: {err}");
+            return Err(ApplicationError::DocxGenerationError);
+        }
+    };
+    package.replace_part(DOCUMENT_PART, document_xml.into_bytes());
+    return Ok(package);
+}
+
+fn write_package(package: &mut DocxPackage, path: &Path) -> ApplicationError {
+    let bytes = match package.assemble() {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            log::error!("Failed to assemble the document: {err}");
+            return ApplicationError::DocxGenerationError;
+        }
+    };
+    return match std::fs::write(path, bytes) {
+        Ok(()) => ApplicationError::NoError,
+        Err(err) => {
+            log::error!("Could not write {}: {:?}", path.display(), err);
+            ApplicationError::RustWriteFileError
+        }
+    };
+}
+
+/// To be called by the main application, saves the plans to the hard drive.
+/// Param 1: The competition.
+/// Param 2: A PathBuf containing the path where the plan should be saved to.
+/// Returns: An ApplicationError which gives more information (NoError == Success!).
+pub fn create_tables_docx(wettkampf: &Wettkampf, save_path: PathBuf) -> ApplicationError {
+    let mut package = match generate_plan(wettkampf) {
+        Ok(package) => package,
+        Err(err) => return err,
+    };
+    return write_package(&mut package, &save_path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Kampfrichter;
+    use crate::Disciplines::Discipline;
+    use crate::DocxReader::parse_xml;
+
+    fn wettkampf() -> Wettkampf {
+        let judges = [(JudgeRole::Ok, "Anna Schmidt"), (JudgeRole::Sk1, "Ben Weber"), (JudgeRole::Ak1, "Clara Wolf")];
+        let table = Kampfgericht {
+            uniqueID: "1".to_string(),
+            table_name: "KG Sprung".to_string(),
+            table_kind: Discipline::Sprung,
+            judges: judges
+                .iter()
+                .map(|(role, name)| {
+                    let judge = Kampfrichter {
+                        role: role.clone(),
+                        name: name.to_string(),
+                        doubleFound: false,
+                    };
+                    return (role.clone(), judge);
+                })
+                .collect(),
+            ..Kampfgericht::default()
+        };
+        return Wettkampf {
+            wk_name: "Landesmeisterschaft Rhönradturnen".to_string(),
+            wk_date: "2025-05-17".to_string(),
+            wk_place: "Stuttgart".to_string(),
+            wk_replacement_judges: vec!["Paul Wagner".to_string()],
+            wk_judgingtables: HashMap::from([("1".to_string(), table)]),
+            ..Wettkampf::default()
+        };
+    }
+
+    #[test]
+    fn writes_a_document_with_the_competition() {
+        let path = std::env::temp_dir().join(format!("kre-docx-{}.docx", std::process::id()));
+
+        assert_eq!(create_tables_docx(&wettkampf(), path.clone()), ApplicationError::NoError);
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(bytes.starts_with(b"PK\x03\x04"));
+        let document_xml = DocxPackage::open(bytes).unwrap().read_xml_part(DOCUMENT_PART).unwrap();
+        for text in ["Landesmeisterschaft Rhönradturnen", "Stuttgart", "KG Sprung", "Anna Schmidt", "Ben Weber", "Clara Wolf", "Paul Wagner"] {
+            assert!(inner_text(&document_xml).contains(text), "{text} is missing");
+        }
+        assert!(parse_xml(&document_xml).is_ok());
+    }
+
+    #[test]
+    fn reports_a_path_that_cannot_be_written() {
+        let path = std::env::temp_dir().join("kre-missing-folder").join("Plan.docx");
+
+        assert_eq!(create_tables_docx(&wettkampf(), path), ApplicationError::RustWriteFileError);
+    }
+}
@@ -0,0 +1,641 @@
+use crate::DocxImpl::{DocxPackage, DOCUMENT_PART};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+// Reads the parts of a generated plan which are needed to print it.
+// Only the WordprocessingML features used by the templates are supported.
+
+const TWIPS_PER_CM: f32 = 1440.0 / 2.54;
+const EMU_PER_CM: f32 = 360000.0;
+
+/// A minimal element tree of an XML part.
+#[derive(Debug, Clone, Default)]
+pub struct XmlElement {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<XmlNode>,
+}
+
+#[derive(Debug, Clone)]
+pub enum XmlNode {
+    Element(XmlElement),
+    Text(String),
+}
+
+impl XmlElement {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        return self
+            .attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str());
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &XmlElement> {
+        return self.children.iter().filter_map(|child| match child {
+            XmlNode::Element(element) => Some(element),
+            XmlNode::Text(_) => None,
+        });
+    }
+
+    pub fn child(&self, name: &str) -> Option<&XmlElement> {
+        return self.elements().find(|element| element.name == name);
+    }
+
+    /// Follows a path of child names, e.g. ["w:pPr", "w:jc"].
+    pub fn path(&self, names: &[&str]) -> Option<&XmlElement> {
+        let mut element = self;
+        for name in names {
+            element = element.child(name)?;
+        }
+        return Some(element);
+    }
+
+    /// Returns: All elements with the given name below this one, in document order.
+    pub fn descendants(&self, name: &str) -> Vec<&XmlElement> {
+        let mut found = Vec::new();
+        for element in self.elements() {
+            if element.name == name {
+                found.push(element);
+            }
+            found.append(&mut element.descendants(name));
+        }
+        return found;
+    }
+
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for child in &self.children {
+            match child {
+                XmlNode::Text(content) => text.push_str(content),
+                XmlNode::Element(element) => text.push_str(&element.text()),
+            }
+        }
+        return text;
+    }
+}
+
+/// Parses an XML part into an element tree.
+/// Returns: The root element or a description of what went wrong.
+pub fn parse_xml(xml: &str) -> Result<XmlElement, String> {
+    let mut reader = Reader::from_str(xml);
+    // The root is a placeholder, so the stack is never empty
+    let mut stack: Vec<XmlElement> = vec![XmlElement::default()];
+
+    loop {
+        let event = match reader.read_event() {
+            Ok(event) => event,
+            Err(err) => return Err(format!("Invalid XML at position {}: {err}", reader.buffer_position())),
+        };
+        let is_empty = matches!(event, Event::Empty(_));
+        match event {
+            Event::Start(start) | Event::Empty(start) => {
+                let mut element = XmlElement {
+                    name: String::from_utf8_lossy(start.name().as_ref()).to_string(),
+                    ..Default::default()
+                };
+                for attribute in start.attributes().flatten() {
+                    let value = match attribute.unescape_value() {
+                        Ok(value) => value.to_string(),
+                        Err(_) => String::from_utf8_lossy(&attribute.value).to_string(),
+                    };
+                    element
+                        .attributes
+                        .push((String::from_utf8_lossy(attribute.key.as_ref()).to_string(), value));
+                }
+                if is_empty {
+                    push_node(&mut stack, XmlNode::Element(element));
+                } else {
+                    stack.push(element);
+                }
+            }
+            Event::End(_) => {
+                if stack.len() < 2 {
+                    return Err("Unexpected closing tag".to_string());
+                }
+                let element = stack.pop().unwrap();
+                push_node(&mut stack, XmlNode::Element(element));
+            }
+            Event::Text(text) => match text.decode() {
+                Ok(content) => push_node(&mut stack, XmlNode::Text(content.to_string())),
+                Err(err) => return Err(format!("Invalid text: {err}")),
+            },
+            Event::CData(data) => {
+                push_node(&mut stack, XmlNode::Text(String::from_utf8_lossy(&data).to_string()));
+            }
+            Event::GeneralRef(reference) => {
+                let resolved = match reference.resolve_char_ref() {
+                    Ok(Some(character)) => Some(character.to_string()),
+                    _ => match reference.decode() {
+                        Ok(name) => quick_xml::escape::resolve_predefined_entity(&name).map(|entity| entity.to_string()),
+                        Err(_) => None,
+                    },
+                };
+                if let Some(text) = resolved {
+                    push_node(&mut stack, XmlNode::Text(text));
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if stack.len() != 1 {
+        return Err("Unclosed element at the end of the document".to_string());
+    }
+    return match stack.pop().unwrap().children.into_iter().find_map(|node| match node {
+        XmlNode::Element(element) => Some(element),
+        XmlNode::Text(_) => None,
+    }) {
+        Some(root) => Ok(root),
+        None => Err("The document has no root element".to_string()),
+    };
+}
+
+fn push_node(stack: &mut [XmlElement], node: XmlNode) {
+    // Text directly after another text, e.g. around an entity, is merged
+    let parent = stack.last_mut().unwrap();
+    if let (XmlNode::Text(text), Some(XmlNode::Text(previous))) = (&node, parent.children.last_mut()) {
+        previous.push_str(text);
+        return;
+    }
+    parent.children.push(node);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+    Justify,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VerticalAlignment {
+    Top,
+    Center,
+    Bottom,
+}
+
+/// A piece of text with the same formatting.
+/// Tabs and line breaks are kept as '\t' and '\n'.
+#[derive(Debug, Clone)]
+pub struct TextRun {
+    pub text: String,
+    pub font: String,
+    pub size_pt: f32,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Paragraph {
+    pub runs: Vec<TextRun>,
+    pub alignment: Alignment,
+    /// Font size of the paragraph mark, which sets the height of empty paragraphs.
+    pub size_pt: f32,
+    pub list_item: bool,
+    /// The paragraph contains a page break, everything after it starts on a new page.
+    pub page_break: bool,
+}
+
+/// Which sides of a table cell have a border.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Borders {
+    pub top: bool,
+    pub right: bool,
+    pub bottom: bool,
+    pub left: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct TableCell {
+    /// Covers all grid columns the cell spans.
+    pub width_cm: f32,
+    pub borders: Borders,
+    pub vertical_alignment: VerticalAlignment,
+    pub paragraphs: Vec<Paragraph>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TableRow {
+    pub min_height_cm: f32,
+    pub cells: Vec<TableCell>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub rows: Vec<TableRow>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Block {
+    Paragraph(Paragraph),
+    Table(Table),
+}
+
+/// A picture in the page header, positioned relative to the left page margin.
+#[derive(Debug, Clone)]
+pub struct HeaderImage {
+    pub png: Option<Vec<u8>>,
+    pub svg: Option<Vec<u8>>,
+    pub offset_x_cm: f32,
+    pub width_cm: f32,
+    pub height_cm: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct PageSetup {
+    pub width_cm: f32,
+    pub height_cm: f32,
+    pub margin_top_cm: f32,
+    pub margin_right_cm: f32,
+    pub margin_bottom_cm: f32,
+    pub margin_left_cm: f32,
+}
+
+/// Everything needed to print a plan.
+#[derive(Debug, Clone)]
+pub struct DocumentLayout {
+    pub page: PageSetup,
+    pub header_images: Vec<HeaderImage>,
+    pub blocks: Vec<Block>,
+}
+
+/// The defaults of the document, which apply where runs do not set their own formatting.
+struct Defaults {
+    font: String,
+    size_pt: f32,
+    table_styles: Vec<(String, TableBorders)>,
+}
+
+/// The borders a table gives its cells, unless a cell sets its own.
+#[derive(Debug, Clone, Copy, Default)]
+struct TableBorders {
+    outer: Borders,
+    inside_horizontal: bool,
+    inside_vertical: bool,
+}
+
+fn twips_to_cm(value: Option<&str>) -> Option<f32> {
+    return value.and_then(|value| value.parse::<f32>().ok()).map(|twips| twips / TWIPS_PER_CM);
+}
+
+fn emu_to_cm(value: Option<&str>) -> Option<f32> {
+    return value.and_then(|value| value.trim().parse::<f32>().ok()).map(|emu| emu / EMU_PER_CM);
+}
+
+/// On/off properties like w:b are on if they are present, unless their value says otherwise.
+fn is_on(element: Option<&XmlElement>) -> bool {
+    return match element {
+        Some(element) => !matches!(element.attribute("w:val"), Some("0" | "false" | "none")),
+        None => false,
+    };
+}
+
+/// Returns: Whether a border element draws a line.
+fn draws_line(border: &XmlElement) -> bool {
+    return !matches!(border.attribute("w:val"), Some("nil" | "none") | None);
+}
+
+fn read_defaults(styles: Option<XmlElement>) -> Defaults {
+    let mut defaults = Defaults {
+        font: "Arial".to_string(),
+        size_pt: 11.0,
+        table_styles: Vec::new(),
+    };
+    let styles = match styles {
+        Some(styles) => styles,
+        None => return defaults,
+    };
+
+    let mut properties: Vec<&XmlElement> = Vec::new();
+    if let Some(run_properties) = styles.path(&["w:docDefaults", "w:rPrDefault", "w:rPr"]) {
+        properties.push(run_properties);
+    }
+    for style in styles.elements().filter(|element| element.name == "w:style") {
+        if style.attribute("w:type") == Some("paragraph") && style.attribute("w:default") == Some("1") {
+            if let Some(run_properties) = style.child("w:rPr") {
+                properties.push(run_properties);
+            }
+        }
+        if style.attribute("w:type") == Some("table") {
+            if let (Some(id), Some(borders)) = (style.attribute("w:styleId"), style.path(&["w:tblPr", "w:tblBorders"])) {
+                defaults.table_styles.push((id.to_string(), TableBorders::default().overridden_by(borders)));
+            }
+        }
+    }
+    // Later properties override earlier ones, like the style hierarchy does
+    for run_properties in properties {
+        if let Some(font) = run_properties.child("w:rFonts").and_then(|fonts| fonts.attribute("w:ascii")) {
+            defaults.font = font.to_string();
+        }
+        if let Some(size) = run_properties.child("w:sz").and_then(|size| size.attribute("w:val")) {
+            if let Ok(half_points) = size.parse::<f32>() {
+                defaults.size_pt = half_points / 2.0;
+            }
+        }
+    }
+    return defaults;
+}
+
+impl TableBorders {
+    /// Returns: These borders with every side the element sets replaced.
+    fn overridden_by(self, borders: &XmlElement) -> TableBorders {
+        let side = |name: &str, current: bool| borders.child(name).map(draws_line).unwrap_or(current);
+        return TableBorders {
+            outer: Borders {
+                top: side("w:top", self.outer.top),
+                right: side("w:right", self.outer.right),
+                bottom: side("w:bottom", self.outer.bottom),
+                left: side("w:left", self.outer.left),
+            },
+            inside_horizontal: side("w:insideH", self.inside_horizontal),
+            inside_vertical: side("w:insideV", self.inside_vertical),
+        };
+    }
+}
+
+fn read_run(run: &XmlElement, defaults: &Defaults, page_break: &mut bool) -> Option<TextRun> {
+    let properties = run.child("w:rPr");
+    let property = |name: &str| properties.and_then(|properties| properties.child(name));
+
+    let mut text = String::new();
+    for element in run.elements() {
+        match element.name.as_str() {
+            "w:t" => text.push_str(&element.text()),
+            "w:tab" => text.push('\t'),
+            "w:br" if element.attribute("w:type") == Some("page") => *page_break = true,
+            "w:br" | "w:cr" => text.push('\n'),
+            _ => {}
+        }
+    }
+    if text.is_empty() {
+        return None;
+    }
+
+    return Some(TextRun {
+        text,
+        font: property("w:rFonts")
+            .and_then(|fonts| fonts.attribute("w:ascii"))
+            .unwrap_or(&defaults.font)
+            .to_string(),
+        size_pt: property("w:sz")
+            .and_then(|size| size.attribute("w:val"))
+            .and_then(|size| size.parse::<f32>().ok())
+            .map(|half_points| half_points / 2.0)
+            .unwrap_or(defaults.size_pt),
+        bold: is_on(property("w:b")),
+        italic: is_on(property("w:i")),
+        underline: is_on(property("w:u")),
+    });
+}
+
+fn read_paragraph(paragraph: &XmlElement, defaults: &Defaults) -> Paragraph {
+    let properties = paragraph.child("w:pPr");
+    let mut page_break = false;
+    let mut runs = Vec::new();
+    // Runs may also be wrapped, e.g. in hyperlinks or smart tags
+    for run in paragraph.descendants("w:r") {
+        if let Some(text_run) = read_run(run, defaults, &mut page_break) {
+            runs.push(text_run);
+        }
+    }
+
+    return Paragraph {
+        runs,
+        alignment: match properties.and_then(|properties| properties.child("w:jc")).and_then(|jc| jc.attribute("w:val")) {
+            Some("center") => Alignment::Center,
+            Some("right" | "end") => Alignment::Right,
+            Some("both" | "distribute") => Alignment::Justify,
+            _ => Alignment::Left,
+        },
+        size_pt: properties
+            .and_then(|properties| properties.path(&["w:rPr", "w:sz"]))
+            .and_then(|size| size.attribute("w:val"))
+            .and_then(|size| size.parse::<f32>().ok())
+            .map(|half_points| half_points / 2.0)
+            .unwrap_or(defaults.size_pt),
+        list_item: properties.and_then(|properties| properties.child("w:numPr")).is_some(),
+        page_break,
+    };
+}
+
+fn read_table(table: &XmlElement, defaults: &Defaults) -> Table {
+    let properties = table.child("w:tblPr");
+    let grid: Vec<f32> = match table.child("w:tblGrid") {
+        Some(grid) => grid
+            .elements()
+            .filter(|column| column.name == "w:gridCol")
+            .map(|column| twips_to_cm(column.attribute("w:w")).unwrap_or(0.0))
+            .collect(),
+        None => Vec::new(),
+    };
+
+    // Borders of the table itself win over the ones of its style
+    let mut table_borders = properties
+        .and_then(|properties| properties.child("w:tblStyle"))
+        .and_then(|style| style.attribute("w:val"))
+        .and_then(|id| defaults.table_styles.iter().find(|(style_id, _)| style_id == id))
+        .map(|(_, borders)| *borders)
+        .unwrap_or_default();
+    if let Some(borders) = properties.and_then(|properties| properties.child("w:tblBorders")) {
+        table_borders = table_borders.overridden_by(borders);
+    }
+
+    let row_elements: Vec<&XmlElement> = table.elements().filter(|element| element.name == "w:tr").collect();
+    let mut rows = Vec::new();
+    for (row_index, row) in row_elements.iter().enumerate() {
+        let first_row = row_index == 0;
+        let last_row = row_index == row_elements.len() - 1;
+        let mut column = 0;
+        let mut cells = Vec::new();
+        let cell_elements: Vec<&XmlElement> = row.elements().filter(|element| element.name == "w:tc").collect();
+        for cell in &cell_elements {
+            let cell_properties = cell.child("w:tcPr");
+            let span = cell_properties
+                .and_then(|properties| properties.child("w:gridSpan"))
+                .and_then(|span| span.attribute("w:val"))
+                .and_then(|span| span.parse::<usize>().ok())
+                .unwrap_or(1)
+                .max(1);
+            let first_column = column == 0;
+            let last_column = column + span >= grid.len();
+
+            // Inner lines use insideH/insideV, the outer ones the sides of the table
+            let inherited = Borders {
+                top: if first_row { table_borders.outer.top } else { table_borders.inside_horizontal },
+                right: if last_column { table_borders.outer.right } else { table_borders.inside_vertical },
+                bottom: if last_row { table_borders.outer.bottom } else { table_borders.inside_horizontal },
+                left: if first_column { table_borders.outer.left } else { table_borders.inside_vertical },
+            };
+            let cell_borders = cell_properties.and_then(|properties| properties.child("w:tcBorders"));
+            let cell_side = |name: &str, inherited: bool| -> bool {
+                cell_borders
+                    .and_then(|borders| borders.child(name))
+                    .map(draws_line)
+                    .unwrap_or(inherited)
+            };
+
+            let grid_width: f32 = grid.iter().skip(column).take(span).sum();
+            cells.push(TableCell {
+                width_cm: cell_properties
+                    .and_then(|properties| properties.child("w:tcW"))
+                    .filter(|width| width.attribute("w:type") == Some("dxa"))
+                    .and_then(|width| twips_to_cm(width.attribute("w:w")))
+                    .unwrap_or(grid_width),
+                borders: Borders {
+                    top: cell_side("w:top", inherited.top),
+                    right: cell_side("w:right", inherited.right),
+                    bottom: cell_side("w:bottom", inherited.bottom),
+                    left: cell_side("w:left", inherited.left),
+                },
+                vertical_alignment: match cell_properties
+                    .and_then(|properties| properties.child("w:vAlign"))
+                    .and_then(|alignment| alignment.attribute("w:val"))
+                {
+                    Some("center") => VerticalAlignment::Center,
+                    Some("bottom") => VerticalAlignment::Bottom,
+                    _ => VerticalAlignment::Top,
+                },
+                paragraphs: cell
+                    .elements()
+                    .filter(|element| element.name == "w:p")
+                    .map(|paragraph| read_paragraph(paragraph, defaults))
+                    .collect(),
+            });
+            column += span;
+        }
+        rows.push(TableRow {
+            min_height_cm: row
+                .path(&["w:trPr", "w:trHeight"])
+                .and_then(|height| twips_to_cm(height.attribute("w:val")))
+                .unwrap_or(0.0),
+            cells,
+        });
+    }
+    return Table { rows };
+}
+
+/// Resolves a relationship id of a part to the path of its target inside the package.
+/// Param 1: The package.
+/// Param 2: The part the relationship belongs to, e.g. "word/header1.xml".
+/// Param 3: The relationship id.
+pub fn resolve_relationship(package: &mut DocxPackage, part: &str, id: &str) -> Result<Option<String>, String> {
+    let (folder, file) = match part.rsplit_once('/') {
+        Some(split) => split,
+        None => ("", part),
+    };
+    let relationships = match package.read_part(&format!("{folder}/_rels/{file}.rels"))? {
+        Some(bytes) => parse_xml(&String::from_utf8_lossy(&bytes))?,
+        None => return Ok(None),
+    };
+    let target = relationships
+        .elements()
+        .find(|relationship| relationship.attribute("Id") == Some(id))
+        .and_then(|relationship| relationship.attribute("Target"));
+    return Ok(target.map(|target| match target.strip_prefix('/') {
+        Some(absolute) => absolute.to_string(),
+        None => format!("{folder}/{target}"),
+    }));
+}
+
+fn read_header_images(package: &mut DocxPackage, header_part: &str) -> Result<Vec<HeaderImage>, String> {
+    let header = parse_xml(&package.read_xml_part(header_part)?)?;
+    let mut images = Vec::new();
+    for drawing in header.descendants("w:drawing") {
+        let anchor = match drawing.child("wp:anchor").or_else(|| drawing.child("wp:inline")) {
+            Some(anchor) => anchor,
+            None => continue,
+        };
+        let extent = match anchor.child("wp:extent") {
+            Some(extent) => extent,
+            None => continue,
+        };
+        let mut image = HeaderImage {
+            png: None,
+            svg: None,
+            offset_x_cm: emu_to_cm(anchor.path(&["wp:positionH", "wp:posOffset"]).map(|offset| offset.text()).as_deref())
+                .unwrap_or(0.0),
+            width_cm: emu_to_cm(extent.attribute("cx")).unwrap_or(0.0),
+            height_cm: emu_to_cm(extent.attribute("cy")).unwrap_or(0.0),
+        };
+        // Word stores a PNG for older readers and the SVG as an extension
+        if let Some(id) = anchor.descendants("a:blip").first().and_then(|blip| blip.attribute("r:embed")) {
+            if let Some(target) = resolve_relationship(package, header_part, id)? {
+                image.png = package.read_part(&target)?;
+            }
+        }
+        if let Some(id) = anchor.descendants("asvg:svgBlip").first().and_then(|blip| blip.attribute("r:embed")) {
+            if let Some(target) = resolve_relationship(package, header_part, id)? {
+                image.svg = package.read_part(&target)?;
+            }
+        }
+        if image.png.is_some() || image.svg.is_some() {
+            images.push(image);
+        }
+    }
+    return Ok(images);
+}
+
+/// Reads the generated plan for printing.
+/// Param 1: The filled template.
+/// Returns: The layout of the plan or a description of what went wrong.
+pub fn read_layout(package: &mut DocxPackage) -> Result<DocumentLayout, String> {
+    let document = parse_xml(&package.read_xml_part(DOCUMENT_PART)?)?;
+    let styles = match package.read_part("word/styles.xml")? {
+        Some(bytes) => Some(parse_xml(&String::from_utf8_lossy(&bytes))?),
+        None => None,
+    };
+    let defaults = read_defaults(styles);
+
+    let body = match document.child("w:body") {
+        Some(body) => body,
+        None => return Err("The document has no body".to_string()),
+    };
+
+    let mut blocks = Vec::new();
+    for element in body.elements() {
+        match element.name.as_str() {
+            "w:p" => blocks.push(Block::Paragraph(read_paragraph(element, &defaults))),
+            "w:tbl" => blocks.push(Block::Table(read_table(element, &defaults))),
+            _ => {}
+        }
+    }
+
+    let section = body.child("w:sectPr");
+    let size = section.and_then(|section| section.child("w:pgSz"));
+    let margins = section.and_then(|section| section.child("w:pgMar"));
+    let margin = |side: &str| twips_to_cm(margins.and_then(|margins| margins.attribute(side))).unwrap_or(2.5);
+    let page = PageSetup {
+        width_cm: twips_to_cm(size.and_then(|size| size.attribute("w:w"))).unwrap_or(21.0),
+        height_cm: twips_to_cm(size.and_then(|size| size.attribute("w:h"))).unwrap_or(29.7),
+        margin_top_cm: margin("w:top"),
+        margin_right_cm: margin("w:right"),
+        margin_bottom_cm: margin("w:bottom"),
+        margin_left_cm: margin("w:left"),
+    };
+
+    // Only the default header is used, the templates have no others
+    let header_id = section
+        .map(|section| section.elements().filter(|element| element.name == "w:headerReference").collect::<Vec<_>>())
+        .unwrap_or_default()
+        .into_iter()
+        .find(|reference| reference.attribute("w:type").unwrap_or("default") == "default")
+        .and_then(|reference| reference.attribute("r:id"))
+        .map(|id| id.to_string());
+    let header_images = match header_id {
+        Some(id) => match resolve_relationship(package, DOCUMENT_PART, &id)? {
+            Some(header_part) => read_header_images(package, &header_part)?,
+            None => Vec::new(),
+        },
+        None => Vec::new(),
+    };
+
+    return Ok(DocumentLayout {
+        page,
+        header_images,
+        blocks,
+    });
+}
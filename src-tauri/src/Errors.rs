@@ -0,0 +1,168 @@
+use crate::types::ApplicationError;
+use serde::{Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+// ApplicationError only tells which kind of failure happened. DetailedError adds what the user needs to
+// understand it: a German message for the frontend, the file or field concerned and the chain of causes.
+// The numeric codes stay those of ApplicationError, which the frontend and the logs rely on and must never be reused.
+
+/// An error with everything needed to show it in the frontend, see ApplicationError for the codes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetailedError {
+    code: ApplicationError,
+    message: String,
+    path: Option<PathBuf>,
+    field: Option<String>,
+    /// The causes of the error, outermost first, e.g. the io::Error of a failed write.
+    causes: Vec<String>,
+}
+
+impl DetailedError {
+    /// Returns: An error with the default message of the code.
+    pub fn new(code: ApplicationError) -> DetailedError {
+        return DetailedError {
+            code,
+            message: code.message().to_string(),
+            path: None,
+            field: None,
+            causes: Vec::new(),
+        };
+    }
+
+    /// Replaces the default message of the code with a more specific one, shown to the user.
+    pub fn with_message(mut self, message: impl Into<String>) -> DetailedError {
+        self.message = message.into();
+        return self;
+    }
+
+    /// Names the file the error is about.
+    pub fn with_path(mut self, path: impl AsRef<Path>) -> DetailedError {
+        self.path = Some(path.as_ref().to_path_buf());
+        return self;
+    }
+
+    /// Names the setting or input field the error is about.
+    pub fn with_field(mut self, field: impl Into<String>) -> DetailedError {
+        self.field = Some(field.into());
+        return self;
+    }
+
+    /// Appends the error and all of its sources to the causes.
+    pub fn caused_by(mut self, error: &(dyn std::error::Error + 'static)) -> DetailedError {
+        let mut source = Some(error);
+        while let Some(error) = source {
+            self.causes.push(error.to_string());
+            source = error.source();
+        }
+        return self;
+    }
+
+    /// Appends a cause that is only available as text, e.g. the description of a failed migration.
+    pub fn caused_by_text(mut self, cause: impl Into<String>) -> DetailedError {
+        self.causes.push(cause.into());
+        return self;
+    }
+
+    pub fn code(&self) -> ApplicationError {
+        return self.code;
+    }
+
+    pub fn message(&self) -> &str {
+        return &self.message;
+    }
+
+    pub fn field(&self) -> Option<&str> {
+        return self.field.as_deref();
+    }
+}
+
+impl From<ApplicationError> for DetailedError {
+    fn from(code: ApplicationError) -> DetailedError {
+        return DetailedError::new(code);
+    }
+}
+
+/// The message, followed by the file, the field and the causes, as written to the log.
+impl Display for DetailedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} ({}): {}", self.code, self.code.ffi_code(), self.message)?;
+        if let Some(path) = &self.path {
+            write!(f, " [{}]", path.display())?;
+        }
+        if let Some(field) = &self.field {
+            write!(f, " [field: {field}]")?;
+        }
+        for cause in &self.causes {
+            write!(f, "\n  caused by: {cause}")?;
+        }
+        return Ok(());
+    }
+}
+
+impl std::error::Error for DetailedError {}
+
+/// As seen by the frontend (see Errors.ts): The name and numeric code of the ApplicationError and the details.
+impl Serialize for DetailedError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct SerializedError<'a> {
+            code: ApplicationError,
+            ffi_code: i32,
+            message: &'a str,
+            path: Option<&'a Path>,
+            field: Option<&'a str>,
+            causes: &'a [String],
+        }
+        return SerializedError {
+            code: self.code,
+            ffi_code: self.code.ffi_code(),
+            message: &self.message,
+            path: self.path.as_deref(),
+            field: self.field.as_deref(),
+            causes: &self.causes,
+        }
+        .serialize(serializer);
+    }
+}
+
+impl ApplicationError {
+    /// Returns: The numeric code, as sent to the frontend and written to the log.
+    pub fn ffi_code(self) -> i32 {
+        return self as i32;
+    }
+
+    /// Returns: A German description of the error for the user, used unless a more specific message is given.
+    pub fn message(self) -> &'static str {
+        return match self {
+            ApplicationError::UnknownError => "Ein unbekannter Fehler ist aufgetreten.",
+            ApplicationError::NoError => "Kein Fehler.",
+            ApplicationError::MutexPoisonedError => "Ein interner Zustand ist nach einem Absturz unbrauchbar geworden.",
+            ApplicationError::JSONSerializeError => "Die Daten konnten nicht umgewandelt werden.",
+            ApplicationError::TauriWindowCreationError => "Das Fenster konnte nicht erstellt werden.",
+            ApplicationError::TauriWindowShowError => "Das Fenster konnte nicht angezeigt werden.",
+            ApplicationError::TauriExistingWindowNotFoundError => "Das Fenster wurde nicht gefunden.",
+            ApplicationError::RustWriteFileError => "Die Datei konnte nicht geschrieben werden.",
+            ApplicationError::JSONDeserializeImporterError => "Die Datei ist beschädigt oder keine Wettkampfdatei.",
+            ApplicationError::PdfSavePathIsEmpty => "Es wurde kein Speicherort für die PDF-Datei angegeben.",
+            ApplicationError::SMTPConnectionError => "Keine Verbindung zum Mailserver.",
+            ApplicationError::MessageSendError => "Die Nachricht konnte nicht verschickt werden.",
+            ApplicationError::NewerFileFormatError => "Die Datei wurde mit einer neueren Version erstellt. Bitte die Anwendung aktualisieren.",
+            ApplicationError::FileMigrationError => "Die Datei einer älteren Version konnte nicht übernommen werden.",
+            ApplicationError::UnresolvedJudgeConflictsError => "Einige Kampfrichter sind mehrfach eingeteilt.",
+            ApplicationError::TemplateReadError => "Eine Vorlage konnte nicht gelesen werden.",
+            ApplicationError::DocxGenerationError => "Der Einsatzplan konnte nicht als DOCX erstellt werden.",
+            ApplicationError::PdfGenerationError => "Der Einsatzplan konnte nicht als PDF erstellt werden.",
+            ApplicationError::RosterImportError => "Die Kampfrichterliste konnte nicht importiert werden.",
+            ApplicationError::DocumentNotFoundError => "Zu diesem Fenster ist kein Wettkampf geöffnet.",
+            ApplicationError::AutosaveReadError => "Die automatische Sicherung konnte nicht gelesen werden.",
+            ApplicationError::InvalidSettingsError => "Die Einstellungen sind ungültig.",
+            ApplicationError::SettingsWriteError => "Die Einstellungen konnten nicht gespeichert werden.",
+            ApplicationError::LogFileCreationError => "Die Protokolldatei konnte nicht angelegt werden.",
+            ApplicationError::LogReadError => "Das Protokoll konnte nicht gelesen werden.",
+            ApplicationError::DiagnosticsWriteError => "Die Diagnosedaten konnten nicht gespeichert werden.",
+            ApplicationError::OutboxWriteError => "Die Nachricht konnte nicht für einen späteren Versand gespeichert werden.",
+            ApplicationError::ShowItemInFolderError => "Der Ordner konnte nicht angezeigt werden.",
+        };
+    }
+}
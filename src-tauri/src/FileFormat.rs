@@ -0,0 +1,465 @@
+use crate::types::{ApplicationError, Wettkampf};
+use crate::Errors::DetailedError;
+use crate::APP_VERSION;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// The version of the competition file format written by this build.
+/// Bump this and append a function to MIGRATIONS whenever the layout of Wettkampf changes.
+pub const CURRENT_FORMAT_VERSION: u32 = 4;
+
+type Migration = fn(Value) -> Result<Value, String>;
+
+/// Migrations between consecutive format versions.
+/// MIGRATIONS[0] migrates version 1 to 2, MIGRATIONS[1] migrates version 2 to 3 and so on.
+/// Each function receives and returns the raw JSON of the Wettkampf struct.
+static MIGRATIONS: &[Migration] = &[migrate_v1_to_v2, migrate_v2_to_v3, migrate_v3_to_v4];
+
+/// Self-describing envelope around the competition data, as written to .wkdata files.
+/// Files written before version 1.4 contain the bare Wettkampf struct without an envelope (format version 1).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WkFile<T> {
+    pub format_version: u32,
+    pub app_version: String,
+    pub created: String,
+    pub modified: String,
+    pub wettkampf: T,
+}
+
+/// Serializes the competition into the current file format.
+/// Param 1: The competition to serialize.
+/// Param 2: The creation timestamp of the file we overwrite, if any. A new one is generated otherwise.
+/// Returns: The serialized file contents or a JSONSerializeError.
+pub fn serialize_wk_file(wettkampf: &Wettkampf, created: Option<String>) -> Result<String, DetailedError> {
+    let now = chrono::Local::now().to_rfc3339();
+    let wk_file = WkFile {
+        format_version: CURRENT_FORMAT_VERSION,
+        app_version: APP_VERSION.to_string(),
+        created: created.unwrap_or(now.clone()),
+        modified: now,
+        wettkampf,
+    };
+    return match serde_json::to_string(&wk_file) {
+        Ok(data) => Ok(data),
+        Err(err) => Err(DetailedError::new(ApplicationError::JSONSerializeError).caused_by(&err)),
+    };
+}
+
+/// Reads the creation timestamp of an existing competition file, so it survives being overwritten.
+/// Returns: None, if there is no file or it predates the envelope.
+pub fn read_creation_timestamp(filepath: &Path) -> Option<String> {
+    let contents = std::fs::read_to_string(filepath).ok()?;
+    let value: Value = serde_json::from_str(&contents).ok()?;
+    return value.get("created")?.as_str().map(|created| created.to_string());
+}
+
+/// Deserializes a competition file of any known format version and migrates it to the current one.
+/// Param 1: The raw file contents.
+/// Returns: The Wettkampf or the error describing why the file could not be loaded.
+pub fn deserialize_wk_file(contents: &str) -> Result<Wettkampf, DetailedError> {
+    let value: Value = match serde_json::from_str(contents) {
+        Ok(value) => value,
+        Err(err) => return Err(DetailedError::new(ApplicationError::JSONDeserializeImporterError).caused_by(&err)),
+    };
+
+    // Files without an envelope are format version 1
+    let (format_version, mut wettkampf) = match value {
+        Value::Object(mut map) if map.contains_key("format_version") => {
+            let format_version = match map.get("format_version").and_then(|version| version.as_u64()) {
+                Some(version) => version as u32,
+                None => {
+                    return Err(DetailedError::new(ApplicationError::JSONDeserializeImporterError)
+                        .with_field("format_version")
+                        .caused_by_text(format!("The format_version is not a number: {:?}", map.get("format_version"))));
+                }
+            };
+            let wettkampf = match map.remove("wettkampf") {
+                Some(wettkampf) => wettkampf,
+                None => {
+                    return Err(DetailedError::new(ApplicationError::JSONDeserializeImporterError)
+                        .with_field("wettkampf")
+                        .caused_by_text(format!("The file (format version {format_version}) does not contain any wk data")));
+                }
+            };
+            if format_version > CURRENT_FORMAT_VERSION {
+                let app_version = map.get("app_version").and_then(|version| version.as_str()).unwrap_or("?");
+                return Err(DetailedError::new(ApplicationError::NewerFileFormatError)
+                    .with_message(format!(
+                        "Die Datei wurde mit Version {app_version} erstellt und kann mit dieser Version ({APP_VERSION}) nicht geöffnet werden. Bitte die Anwendung aktualisieren."
+                    ))
+                    .caused_by_text(format!(
+                        "Format version {format_version} is newer than the supported format version {CURRENT_FORMAT_VERSION}"
+                    )));
+            }
+            (format_version, wettkampf)
+        }
+        other => (1, other),
+    };
+
+    // Run all migrations from the file's version up to the current one
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip((format_version.max(1) - 1) as usize) {
+        wettkampf = match migration(wettkampf) {
+            Ok(migrated) => migrated,
+            Err(err) => {
+                return Err(DetailedError::new(ApplicationError::FileMigrationError)
+                    .caused_by_text(format!("Failed to migrate from format version {} to {}: {err}", index + 1, index + 2)));
+            }
+        };
+    }
+
+    return match serde_json::from_value(wettkampf) {
+        Ok(wettkampf) => Ok(wettkampf),
+        Err(err) => Err(DetailedError::new(ApplicationError::JSONDeserializeImporterError).caused_by(&err)),
+    };
+}
+
+/// Reads and migrates a competition file from disk.
+/// Returns: The Wettkampf or the error describing why the file could not be loaded, naming the file.
+pub fn read_wk_file(filepath: &Path) -> Result<Wettkampf, DetailedError> {
+    let contents = match std::fs::read_to_string(filepath) {
+        Ok(contents) => contents,
+        Err(err) => {
+            return Err(DetailedError::new(ApplicationError::JSONDeserializeImporterError)
+                .with_message("Die Datei konnte nicht gelesen werden.")
+                .with_path(filepath)
+                .caused_by(&err));
+        }
+    };
+    return deserialize_wk_file(&contents).map_err(|err| err.with_path(filepath));
+}
+
+// MARK: Saving
+
+/// How many previous versions of a competition file are kept next to it.
+pub const DEFAULT_BACKUP_COUNT: usize = 3;
+
+/// Writes a competition file so that a crash or a full disk never leaves a truncated file behind:
+/// The data goes into a temporary file in the same folder first, which then replaces the original.
+/// The replaced version is kept as backup, see backup_path.
+/// Param 1: Where to save the file
+/// Param 2: The competition
+/// Param 3: How many previous versions are kept, 0 for none
+/// Returns: The error describing why the file could not be written.
+pub fn write_wk_file(filepath: &Path, wettkampf: &Wettkampf, backup_count: usize) -> Result<(), DetailedError> {
+    // Serialize data, keeping the creation date if we overwrite an existing file
+    let serialized_data = serialize_wk_file(wettkampf, read_creation_timestamp(filepath))?;
+
+    let file_name = match filepath.file_name() {
+        Some(file_name) => file_name.to_string_lossy().to_string(),
+        None => {
+            return Err(DetailedError::new(ApplicationError::RustWriteFileError)
+                .with_message("Der Speicherort ist keine Datei.")
+                .with_path(filepath));
+        }
+    };
+    let temporary_path = filepath.with_file_name(format!(".{file_name}.tmp"));
+    match write_and_sync(&temporary_path, serialized_data.as_bytes()) {
+        Ok(()) => {}
+        Err(err) => {
+            let _ = std::fs::remove_file(&temporary_path);
+            return Err(DetailedError::new(ApplicationError::RustWriteFileError).with_path(&temporary_path).caused_by(&err));
+        }
+    }
+
+    // A failed backup is no reason to lose the new data, the save goes on without it
+    if backup_count > 0 && filepath.exists() {
+        match rotate_backups(filepath, backup_count) {
+            Ok(()) => {}
+            Err(err) => log::warn!("Could not back up {}: {:?}", filepath.display(), err),
+        }
+    }
+
+    match std::fs::rename(&temporary_path, filepath) {
+        Ok(()) => {}
+        Err(err) => {
+            let _ = std::fs::remove_file(&temporary_path);
+            return Err(DetailedError::new(ApplicationError::RustWriteFileError).with_path(filepath).caused_by(&err));
+        }
+    }
+    // Make the rename itself durable, Windows has no way (and no need) to sync a folder
+    #[cfg(not(target_os = "windows"))]
+    if let Some(folder) = filepath.parent().filter(|folder| !folder.as_os_str().is_empty()) {
+        match std::fs::File::open(folder).and_then(|folder| folder.sync_all()) {
+            Ok(()) => {}
+            Err(err) => log::warn!("Could not sync the folder {}: {:?}", folder.display(), err),
+        }
+    }
+    return Ok(());
+}
+
+/// Returns: The path of a backup of the competition file, 1 being the most recent one (e.g. Wettkampf.wkdata.1.bak).
+pub fn backup_path(filepath: &Path, index: usize) -> PathBuf {
+    let file_name = filepath.file_name().map(|file_name| file_name.to_string_lossy().to_string()).unwrap_or_default();
+    return filepath.with_file_name(format!("{file_name}.{index}.bak"));
+}
+
+/// Reads the most recent backup of a competition file that can still be loaded.
+/// Returns: The path of the backup and its competition, None if there is no readable backup.
+pub fn read_latest_valid_backup(filepath: &Path, backup_count: usize) -> Option<(PathBuf, Wettkampf)> {
+    for index in 1..=backup_count {
+        let path = backup_path(filepath, index);
+        if !path.exists() {
+            continue;
+        }
+        match read_wk_file(&path) {
+            Ok(wettkampf) => return Some((path, wettkampf)),
+            Err(err) => log::warn!("The backup cannot be loaded either: {err}"),
+        }
+    }
+    return None;
+}
+
+fn write_and_sync(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    file.write_all(contents)?;
+    return file.sync_all();
+}
+
+/// Shifts every backup one place back, dropping the oldest, and copies the current file into the first place.
+/// The current file is copied rather than moved, so it stays in place until the new version replaces it.
+fn rotate_backups(filepath: &Path, backup_count: usize) -> std::io::Result<()> {
+    match std::fs::remove_file(backup_path(filepath, backup_count)) {
+        Ok(()) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err),
+    }
+    for index in (1..backup_count).rev() {
+        let path = backup_path(filepath, index);
+        if path.exists() {
+            std::fs::rename(&path, backup_path(filepath, index + 1))?;
+        }
+    }
+    std::fs::copy(filepath, backup_path(filepath, 1))?;
+    return Ok(());
+}
+
+// MARK: Migrations
+
+/// Version 1 (app versions 1.0 to 1.3) is the bare Wettkampf struct.
+/// Some of the older files lack optional fields or the role inside a judge, fill those in.
+fn migrate_v1_to_v2(value: Value) -> Result<Value, String> {
+    let mut storage = match value {
+        Value::Object(map) => map,
+        other => return Err(format!("Expected the wk data to be an object, found: {other}")),
+    };
+
+    for key in ["wk_name", "wk_date", "wk_place", "wk_responsible_person", "wk_judgesmeeting_time"] {
+        if !storage.get(key).is_some_and(|value| value.is_string()) {
+            storage.insert(key.to_string(), Value::String(String::new()));
+        }
+    }
+    if !storage.get("wk_replacement_judges").is_some_and(|value| value.is_array()) {
+        storage.insert("wk_replacement_judges".to_string(), Value::Array(Vec::new()));
+    }
+    if !storage.get("wk_judgingtables").is_some_and(|value| value.is_object()) {
+        storage.insert("wk_judgingtables".to_string(), Value::Object(Map::new()));
+    }
+
+    if let Some(Value::Object(tables)) = storage.get_mut("wk_judgingtables") {
+        for (unique_id, table) in tables.iter_mut() {
+            let table = match table.as_object_mut() {
+                Some(table) => table,
+                None => return Err(format!("Judging table {unique_id} is not an object")),
+            };
+            if !table.contains_key("uniqueID") {
+                table.insert("uniqueID".to_string(), Value::String(unique_id.clone()));
+            }
+            if !table.get("table_is_finale").is_some_and(|value| value.is_boolean()) {
+                table.insert("table_is_finale".to_string(), Value::Bool(false));
+            }
+            if !table.get("judges").is_some_and(|value| value.is_object()) {
+                table.insert("judges".to_string(), Value::Object(Map::new()));
+            }
+            if let Some(Value::Object(judges)) = table.get_mut("judges") {
+                for (role, judge) in judges.iter_mut() {
+                    if let Some(judge) = judge.as_object_mut() {
+                        if !judge.contains_key("role") {
+                            judge.insert("role".to_string(), Value::String(role.clone()));
+                        }
+                        if !judge.contains_key("doubleFound") {
+                            judge.insert("doubleFound".to_string(), Value::Bool(false));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    return Ok(Value::Object(storage));
+}
+
+/// Version 3 adds the judge roster imported from GymNet.
+fn migrate_v2_to_v3(value: Value) -> Result<Value, String> {
+    let mut storage = match value {
+        Value::Object(map) => map,
+        other => return Err(format!("Expected the wk data to be an object, found: {other}")),
+    };
+    if !storage.get("wk_judge_roster").is_some_and(|value| value.is_array()) {
+        storage.insert("wk_judge_roster".to_string(), Value::Array(Vec::new()));
+    }
+    return Ok(Value::Object(storage));
+}
+
+/// Version 4 adds the clubs competing at a table, used by the automatic assignment of judges.
+fn migrate_v3_to_v4(value: Value) -> Result<Value, String> {
+    let mut storage = match value {
+        Value::Object(map) => map,
+        other => return Err(format!("Expected the wk data to be an object, found: {other}")),
+    };
+    if let Some(Value::Object(tables)) = storage.get_mut("wk_judgingtables") {
+        for (unique_id, table) in tables.iter_mut() {
+            let table = match table.as_object_mut() {
+                Some(table) => table,
+                None => return Err(format!("Judging table {unique_id} is not an object")),
+            };
+            if !table.get("table_clubs").is_some_and(|value| value.is_array()) {
+                table.insert("table_clubs".to_string(), Value::Array(Vec::new()));
+            }
+        }
+    }
+    return Ok(Value::Object(storage));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Disciplines::{Discipline, JudgeRole};
+    use crate::Roster::LicenseLevel;
+
+    fn fixture(name: &str) -> String {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(name);
+        return std::fs::read_to_string(path).unwrap();
+    }
+
+    /// A folder of its own for every test, as the tests run in parallel.
+    fn test_folder(name: &str) -> PathBuf {
+        let folder = std::env::temp_dir().join(format!("kre-fileformat-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&folder);
+        std::fs::create_dir_all(&folder).unwrap();
+        return folder;
+    }
+
+    fn wettkampf(name: &str) -> Wettkampf {
+        return Wettkampf {
+            wk_name: name.to_string(),
+            ..Wettkampf::default()
+        };
+    }
+
+    #[test]
+    fn migrates_version_1() {
+        let wettkampf = deserialize_wk_file(&fixture("Wettkampf_v1.wkdata")).unwrap();
+
+        assert_eq!(wettkampf.wk_name, "Landesmeisterschaft Rhönradturnen");
+        assert_eq!(wettkampf.wk_judgesmeeting_time, "08:30");
+        assert_eq!(wettkampf.wk_replacement_judges, vec!["Paul Wagner".to_string()]);
+        assert!(wettkampf.wk_judge_roster.is_empty());
+        let table = &wettkampf.wk_judgingtables["a1f3"];
+        assert_eq!(table.table_kind, Discipline::GeradeturnenAufMusik);
+        assert!(table.table_clubs.is_empty());
+        assert_eq!(table.judges[&JudgeRole::Aik1].name, "David Braun");
+        assert!(wettkampf.wk_judgingtables["b7c2"].table_is_finale);
+    }
+
+    #[test]
+    fn migrates_version_1_without_optional_fields() {
+        let wettkampf = deserialize_wk_file(&fixture("Wettkampf_v1_incomplete.wkdata")).unwrap();
+
+        assert_eq!(wettkampf.wk_responsible_person, "");
+        assert!(wettkampf.wk_replacement_judges.is_empty());
+        let table = &wettkampf.wk_judgingtables["c9d0"];
+        assert_eq!(table.uniqueID, "c9d0");
+        assert!(!table.table_is_finale);
+        assert_eq!(table.judges[&JudgeRole::Ok].role, JudgeRole::Ok);
+        assert!(!table.judges[&JudgeRole::Ok].doubleFound);
+        assert_eq!(table.judges[&JudgeRole::Sk1].role, JudgeRole::Sk1);
+    }
+
+    #[test]
+    fn migrates_versions_2_and_3() {
+        let wettkampf = deserialize_wk_file(&fixture("Wettkampf_v2.wkdata")).unwrap();
+        assert_eq!(wettkampf.wk_place, "München");
+        assert!(wettkampf.wk_judge_roster.is_empty());
+        assert!(wettkampf.wk_judgingtables["a1f3"].table_clubs.is_empty());
+
+        let wettkampf = deserialize_wk_file(&fixture("Wettkampf_v3.wkdata")).unwrap();
+        assert_eq!(wettkampf.wk_judge_roster.len(), 2);
+        assert_eq!(wettkampf.wk_judge_roster[0].license, Some(LicenseLevel::A));
+        assert_eq!(wettkampf.wk_judge_roster[0].disciplines, vec![Discipline::Sprung]);
+        assert_eq!(wettkampf.wk_judge_roster[1].license, None);
+        assert!(wettkampf.wk_judgingtables["a1f3"].table_clubs.is_empty());
+    }
+
+    #[test]
+    fn migrated_files_are_saved_in_the_current_version() {
+        let wettkampf = deserialize_wk_file(&fixture("Wettkampf_v1.wkdata")).unwrap();
+        let serialized = serialize_wk_file(&wettkampf, Some("2023-05-01T12:00:00+02:00".to_string())).unwrap();
+
+        let value: Value = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(value["format_version"], CURRENT_FORMAT_VERSION);
+        assert_eq!(value["created"], "2023-05-01T12:00:00+02:00");
+        assert_eq!(deserialize_wk_file(&serialized).unwrap(), wettkampf);
+    }
+
+    #[test]
+    fn refuses_newer_and_broken_files() {
+        let newer = fixture("Wettkampf_v3.wkdata").replace("\"format_version\":3", "\"format_version\":99");
+        assert_eq!(deserialize_wk_file(&newer).unwrap_err().code(), ApplicationError::NewerFileFormatError);
+
+        let broken = r#"{"wk_name":"Kreisturnfest","wk_judgingtables":{"c9d0":"KG 1"}}"#;
+        assert_eq!(deserialize_wk_file(broken).unwrap_err().code(), ApplicationError::FileMigrationError);
+
+        let truncated = &fixture("Wettkampf_v2.wkdata")[..100];
+        assert_eq!(deserialize_wk_file(truncated).unwrap_err().code(), ApplicationError::JSONDeserializeImporterError);
+    }
+
+    #[test]
+    fn keeps_the_given_number_of_backups() {
+        let folder = test_folder("backups");
+        let filepath = folder.join("Wettkampf.wkdata");
+
+        for version in 1..=5 {
+            write_wk_file(&filepath, &wettkampf(&format!("Version {version}")), 3).unwrap();
+        }
+
+        assert_eq!(read_wk_file(&filepath).unwrap().wk_name, "Version 5");
+        assert_eq!(read_wk_file(&backup_path(&filepath, 1)).unwrap().wk_name, "Version 4");
+        assert_eq!(read_wk_file(&backup_path(&filepath, 2)).unwrap().wk_name, "Version 3");
+        assert_eq!(read_wk_file(&backup_path(&filepath, 3)).unwrap().wk_name, "Version 2");
+        assert!(!backup_path(&filepath, 4).exists());
+        // No temporary file is left behind
+        assert_eq!(std::fs::read_dir(&folder).unwrap().count(), 4);
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+
+    #[test]
+    fn writes_no_backups_if_disabled() {
+        let folder = test_folder("no-backups");
+        let filepath = folder.join("Wettkampf.wkdata");
+
+        write_wk_file(&filepath, &wettkampf("Version 1"), 0).unwrap();
+        write_wk_file(&filepath, &wettkampf("Version 2"), 0).unwrap();
+
+        assert_eq!(read_wk_file(&filepath).unwrap().wk_name, "Version 2");
+        assert!(!backup_path(&filepath, 1).exists());
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+
+    #[test]
+    fn falls_back_to_the_latest_readable_backup() {
+        let folder = test_folder("restore");
+        let filepath = folder.join("Wettkampf.wkdata");
+        for version in 1..=3 {
+            write_wk_file(&filepath, &wettkampf(&format!("Version {version}")), 3).unwrap();
+        }
+        std::fs::write(backup_path(&filepath, 1), "{\"format_version\":4,").unwrap();
+
+        let (path, wettkampf) = read_latest_valid_backup(&filepath, 3).unwrap();
+
+        assert_eq!(path, backup_path(&filepath, 2));
+        assert_eq!(wettkampf.wk_name, "Version 1");
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+}
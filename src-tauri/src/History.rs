@@ -0,0 +1,555 @@
+use crate::types::{Kampfgericht, Kampfrichter, Wettkampf};
+use crate::Disciplines::{Discipline, JudgeRole};
+use crate::Roster::RosterJudge;
+use crate::Validation::mark_conflicts;
+
+// The frontend sends the whole Wettkampf after every change. The history compares it with the previous one
+// and records the difference as a list of edits, each with the value before and after, so it can be applied in both directions.
+// Typing into a field sends a Wettkampf per key stroke, consecutive edits of the same field are merged into one step.
+
+/// How many steps can be undone.
+const MAX_HISTORY_STEPS: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderField {
+    Name,
+    Date,
+    Place,
+    ResponsiblePerson,
+    JudgesMeetingTime,
+}
+
+/// A single change of a Wettkampf.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Edit {
+    SetHeader { field: HeaderField, old: String, new: String },
+    /// None if the role is empty.
+    SetJudge { table_id: String, role: JudgeRole, old: Option<String>, new: Option<String> },
+    AddTable { table: Kampfgericht },
+    /// Like AddTable, the table is a copy of another one.
+    CopyTable { table: Kampfgericht },
+    RemoveTable { table: Kampfgericht },
+    RenameTable { table_id: String, old: String, new: String },
+    SetDiscipline { table_id: String, old: Discipline, new: Discipline },
+    SetFinale { table_id: String, old: bool, new: bool },
+    SetTableClubs { table_id: String, old: Vec<String>, new: Vec<String> },
+    SetReplacementJudges { old: Vec<String>, new: Vec<String> },
+    SetRoster { old: Vec<RosterJudge>, new: Vec<RosterJudge> },
+}
+
+/// The undo and redo stacks of a document. A step is everything that changed with one update of the frontend.
+#[derive(Debug, Default, Clone)]
+pub struct History {
+    undo: Vec<Step>,
+    redo: Vec<Step>,
+    /// Whether the next edit may be merged into the last step. Undoing or redoing ends the step.
+    merge_open: bool,
+    /// The id of the last recorded step, see Step::id.
+    last_id: u64,
+    /// The id of the state before the oldest step that can still be undone.
+    oldest_id: u64,
+}
+
+/// The edits of one update of the frontend.
+#[derive(Debug, Clone)]
+struct Step {
+    /// Identifies the state of the Wettkampf after the step, see History::position. A merged step gets a new id.
+    id: u64,
+    edits: Vec<Edit>,
+}
+
+impl HeaderField {
+    fn value(self, wettkampf: &mut Wettkampf) -> &mut String {
+        return match self {
+            HeaderField::Name => &mut wettkampf.wk_name,
+            HeaderField::Date => &mut wettkampf.wk_date,
+            HeaderField::Place => &mut wettkampf.wk_place,
+            HeaderField::ResponsiblePerson => &mut wettkampf.wk_responsible_person,
+            HeaderField::JudgesMeetingTime => &mut wettkampf.wk_judgesmeeting_time,
+        };
+    }
+}
+
+impl Edit {
+    /// A short German description, used for the menu and the messages of the editor.
+    pub fn description(&self) -> &'static str {
+        return match self {
+            Edit::SetHeader { .. } => "Wettkampfdaten ändern",
+            Edit::SetJudge { .. } => "Kampfrichter eintragen",
+            Edit::AddTable { .. } => "Kampfgericht erstellen",
+            Edit::CopyTable { .. } => "Kampfgericht kopieren",
+            Edit::RemoveTable { .. } => "Kampfgericht löschen",
+            Edit::RenameTable { .. } => "Kampfgericht umbenennen",
+            Edit::SetDiscipline { .. } => "Disziplin ändern",
+            Edit::SetFinale { .. } => "Finale umschalten",
+            Edit::SetTableClubs { .. } => "Startende Vereine ändern",
+            Edit::SetReplacementJudges { .. } => "Ersatzkampfrichter ändern",
+            Edit::SetRoster { .. } => "Kampfrichterliste importieren",
+        };
+    }
+
+    /// Applies the edit to the Wettkampf.
+    /// Param 2: true to go back to the old values, false to set the new ones
+    fn apply(&self, wettkampf: &mut Wettkampf, backwards: bool) {
+        match self {
+            Edit::SetHeader { field, old, new } => {
+                *field.value(wettkampf) = pick(old, new, backwards).clone();
+            }
+            Edit::SetJudge { table_id, role, old, new } => {
+                if let Some(table) = wettkampf.wk_judgingtables.get_mut(table_id) {
+                    match pick(old, new, backwards) {
+                        Some(name) => {
+                            let judge = Kampfrichter {
+                                role: role.clone(),
+                                name: name.clone(),
+                                doubleFound: false,
+                            };
+                            table.judges.insert(role.clone(), judge);
+                        }
+                        None => {
+                            table.judges.remove(role);
+                        }
+                    }
+                }
+            }
+            Edit::AddTable { table } | Edit::CopyTable { table } | Edit::RemoveTable { table } => {
+                let adds = !matches!(self, Edit::RemoveTable { .. });
+                if adds != backwards {
+                    wettkampf.wk_judgingtables.insert(table.uniqueID.clone(), table.clone());
+                } else {
+                    wettkampf.wk_judgingtables.remove(&table.uniqueID);
+                }
+            }
+            Edit::RenameTable { table_id, old, new } => {
+                if let Some(table) = wettkampf.wk_judgingtables.get_mut(table_id) {
+                    table.table_name = pick(old, new, backwards).clone();
+                }
+            }
+            Edit::SetDiscipline { table_id, old, new } => {
+                if let Some(table) = wettkampf.wk_judgingtables.get_mut(table_id) {
+                    table.table_kind = pick(old, new, backwards).clone();
+                }
+            }
+            Edit::SetFinale { table_id, old, new } => {
+                if let Some(table) = wettkampf.wk_judgingtables.get_mut(table_id) {
+                    table.table_is_finale = *pick(old, new, backwards);
+                }
+            }
+            Edit::SetTableClubs { table_id, old, new } => {
+                if let Some(table) = wettkampf.wk_judgingtables.get_mut(table_id) {
+                    table.table_clubs = pick(old, new, backwards).clone();
+                }
+            }
+            Edit::SetReplacementJudges { old, new } => {
+                wettkampf.wk_replacement_judges = pick(old, new, backwards).clone();
+            }
+            Edit::SetRoster { old, new } => {
+                wettkampf.wk_judge_roster = pick(old, new, backwards).clone();
+            }
+        }
+    }
+
+    /// Merges a following edit of the same field into this one, so typing a name is undone at once.
+    /// Returns: false if the edits change different things and cannot be merged.
+    fn merge(&mut self, next: &Edit) -> bool {
+        match (self, next) {
+            (Edit::SetHeader { field, new, .. }, Edit::SetHeader { field: next_field, new: next_new, .. }) if field == next_field => {
+                *new = next_new.clone();
+            }
+            (
+                Edit::SetJudge { table_id, role, new, .. },
+                Edit::SetJudge { table_id: next_table_id, role: next_role, new: next_new, .. },
+            ) if table_id == next_table_id && role == next_role => {
+                *new = next_new.clone();
+            }
+            (Edit::SetReplacementJudges { new, .. }, Edit::SetReplacementJudges { new: next_new, .. }) => {
+                *new = next_new.clone();
+            }
+            _ => return false,
+        }
+        return true;
+    }
+
+    /// Returns: Whether the edit changes nothing (anymore), e.g. after typing a name and deleting it again.
+    fn is_noop(&self) -> bool {
+        return match self {
+            Edit::SetHeader { old, new, .. } => old == new,
+            Edit::SetJudge { old, new, .. } => old == new,
+            Edit::SetReplacementJudges { old, new } => old == new,
+            _ => false,
+        };
+    }
+}
+
+fn pick<'a, T>(old: &'a T, new: &'a T, backwards: bool) -> &'a T {
+    return if backwards { old } else { new };
+}
+
+/// Compares two states of a Wettkampf.
+/// Returns: The edits that turn the old state into the new one. The doubleFound flags are ignored, they are recalculated anyway.
+pub fn diff(old: &Wettkampf, new: &Wettkampf) -> Vec<Edit> {
+    let mut edits = Vec::new();
+
+    let headers = [
+        (HeaderField::Name, &old.wk_name, &new.wk_name),
+        (HeaderField::Date, &old.wk_date, &new.wk_date),
+        (HeaderField::Place, &old.wk_place, &new.wk_place),
+        (HeaderField::ResponsiblePerson, &old.wk_responsible_person, &new.wk_responsible_person),
+        (HeaderField::JudgesMeetingTime, &old.wk_judgesmeeting_time, &new.wk_judgesmeeting_time),
+    ];
+    for (field, old_value, new_value) in headers {
+        if old_value != new_value {
+            edits.push(Edit::SetHeader { field, old: old_value.clone(), new: new_value.clone() });
+        }
+    }
+
+    // Sorted, so the edits do not depend on the order of the HashMap
+    let mut removed: Vec<&Kampfgericht> = old.wk_judgingtables.iter().filter(|(id, _)| !new.wk_judgingtables.contains_key(*id)).map(|(_, table)| table).collect();
+    removed.sort_by(|a, b| a.uniqueID.cmp(&b.uniqueID));
+    for table in removed {
+        edits.push(Edit::RemoveTable { table: table.clone() });
+    }
+
+    let mut table_ids: Vec<&String> = new.wk_judgingtables.keys().collect();
+    table_ids.sort();
+    for table_id in table_ids {
+        let new_table = &new.wk_judgingtables[table_id];
+        let old_table = match old.wk_judgingtables.get(table_id) {
+            Some(table) => table,
+            None => {
+                let table = new_table.clone();
+                let is_copy = old.wk_judgingtables.values().any(|existing| is_same_content(existing, new_table));
+                edits.push(if is_copy { Edit::CopyTable { table } } else { Edit::AddTable { table } });
+                continue;
+            }
+        };
+        if old_table.table_name != new_table.table_name {
+            edits.push(Edit::RenameTable { table_id: table_id.clone(), old: old_table.table_name.clone(), new: new_table.table_name.clone() });
+        }
+        if old_table.table_kind != new_table.table_kind {
+            edits.push(Edit::SetDiscipline { table_id: table_id.clone(), old: old_table.table_kind.clone(), new: new_table.table_kind.clone() });
+        }
+        if old_table.table_is_finale != new_table.table_is_finale {
+            edits.push(Edit::SetFinale { table_id: table_id.clone(), old: old_table.table_is_finale, new: new_table.table_is_finale });
+        }
+        if old_table.table_clubs != new_table.table_clubs {
+            edits.push(Edit::SetTableClubs { table_id: table_id.clone(), old: old_table.table_clubs.clone(), new: new_table.table_clubs.clone() });
+        }
+
+        let mut roles: Vec<&JudgeRole> = old_table.judges.keys().chain(new_table.judges.keys()).collect();
+        roles.sort_by_key(|role| role.as_str());
+        roles.dedup();
+        for role in roles {
+            let old_name = old_table.judges.get(role).map(|judge| judge.name.clone());
+            let new_name = new_table.judges.get(role).map(|judge| judge.name.clone());
+            if old_name != new_name {
+                edits.push(Edit::SetJudge { table_id: table_id.clone(), role: role.clone(), old: old_name, new: new_name });
+            }
+        }
+    }
+
+    if old.wk_replacement_judges != new.wk_replacement_judges {
+        edits.push(Edit::SetReplacementJudges { old: old.wk_replacement_judges.clone(), new: new.wk_replacement_judges.clone() });
+    }
+    if old.wk_judge_roster != new.wk_judge_roster {
+        edits.push(Edit::SetRoster { old: old.wk_judge_roster.clone(), new: new.wk_judge_roster.clone() });
+    }
+    return edits;
+}
+
+/// Returns: The description of the edits of a step, if they are all of the same kind (e.g. an automatic assignment).
+fn step_description(step: &[Edit]) -> &'static str {
+    let description = step[0].description();
+    if step.iter().all(|edit| edit.description() == description) {
+        return description;
+    }
+    return "Mehrere Änderungen";
+}
+
+/// Returns: Whether two tables only differ in their id.
+fn is_same_content(a: &Kampfgericht, b: &Kampfgericht) -> bool {
+    let names = |table: &Kampfgericht| -> Vec<(String, String)> {
+        let mut names: Vec<(String, String)> = table.judges.iter().map(|(role, judge)| (role.as_str().to_string(), judge.name.clone())).collect();
+        names.sort();
+        return names;
+    };
+    return a.table_name == b.table_name
+        && a.table_kind == b.table_kind
+        && a.table_is_finale == b.table_is_finale
+        && a.table_clubs == b.table_clubs
+        && names(a) == names(b);
+}
+
+impl History {
+    /// Records the edits of one update as a step. Clears the redo stack, like any editor does.
+    pub fn record(&mut self, edits: Vec<Edit>) {
+        if edits.is_empty() {
+            return;
+        }
+        self.redo.clear();
+
+        self.last_id += 1;
+
+        // Typing: a single edit of the same field as the single edit of the last step
+        if self.merge_open && edits.len() == 1 {
+            if let Some(last) = self.undo.last_mut().filter(|step| step.edits.len() == 1) {
+                if last.edits[0].merge(&edits[0]) {
+                    // The merged step ends in a different state than before
+                    last.id = self.last_id;
+                    if last.edits[0].is_noop() {
+                        self.undo.pop();
+                        // Otherwise the next edit would be merged into the step before, which is a different action
+                        self.merge_open = false;
+                    }
+                    return;
+                }
+            }
+        }
+
+        self.undo.push(Step { id: self.last_id, edits });
+        if self.undo.len() > MAX_HISTORY_STEPS {
+            self.oldest_id = self.undo.remove(0).id;
+        }
+        self.merge_open = true;
+    }
+
+    /// Reverts the last step.
+    /// Returns: The description of the step, None if there is nothing to undo.
+    pub fn undo(&mut self, wettkampf: &mut Wettkampf) -> Option<&'static str> {
+        let step = self.undo.pop()?;
+        for edit in step.edits.iter().rev() {
+            edit.apply(wettkampf, true);
+        }
+        mark_conflicts(&mut wettkampf.wk_judgingtables);
+        let description = step_description(&step.edits);
+        self.redo.push(step);
+        self.merge_open = false;
+        return Some(description);
+    }
+
+    /// Applies the last undone step again.
+    /// Returns: The description of the step, None if there is nothing to redo.
+    pub fn redo(&mut self, wettkampf: &mut Wettkampf) -> Option<&'static str> {
+        let step = self.redo.pop()?;
+        for edit in step.edits.iter() {
+            edit.apply(wettkampf, false);
+        }
+        mark_conflicts(&mut wettkampf.wk_judgingtables);
+        let description = step_description(&step.edits);
+        self.undo.push(step);
+        self.merge_open = false;
+        return Some(description);
+    }
+
+    /// Returns: An id of the current state of the Wettkampf, 0 for the state before the first step.
+    /// Undoing and redoing back to a state returns the same id again, any other change a new one.
+    pub fn position(&self) -> u64 {
+        return self.undo.last().map_or(self.oldest_id, |step| step.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Roster::LicenseLevel;
+
+    fn table(id: &str, name: &str, judges: &[(JudgeRole, &str)]) -> Kampfgericht {
+        return Kampfgericht {
+            uniqueID: id.to_string(),
+            table_name: name.to_string(),
+            table_kind: Discipline::Sprung,
+            judges: judges
+                .iter()
+                .map(|(role, name)| {
+                    let judge = Kampfrichter {
+                        role: role.clone(),
+                        name: name.to_string(),
+                        doubleFound: false,
+                    };
+                    return (role.clone(), judge);
+                })
+                .collect(),
+            ..Kampfgericht::default()
+        };
+    }
+
+    fn wettkampf() -> Wettkampf {
+        let mut wettkampf = Wettkampf {
+            wk_name: "Landesmeisterschaft".to_string(),
+            wk_date: "2025-05-17".to_string(),
+            wk_replacement_judges: vec!["Paul Wagner".to_string()],
+            ..Wettkampf::default()
+        };
+        for table in [
+            table("1", "KG 1", &[(JudgeRole::Ok, "Anna Schmidt"), (JudgeRole::Sk1, "Ben Weber")]),
+            table("2", "KG 2", &[(JudgeRole::Ok, "Clara Wolf")]),
+        ] {
+            wettkampf.wk_judgingtables.insert(table.uniqueID.clone(), table);
+        }
+        return wettkampf;
+    }
+
+    /// Changes the Wettkampf like an update of the frontend and records it.
+    fn change(history: &mut History, wettkampf: &mut Wettkampf, change: impl FnOnce(&mut Wettkampf)) {
+        let previous = wettkampf.clone();
+        change(wettkampf);
+        history.record(diff(&previous, wettkampf));
+    }
+
+    #[test]
+    fn undo_and_redo_restore_every_kind_of_change() {
+        let old = wettkampf();
+        let mut new = old.clone();
+        new.wk_name = "Deutsche Meisterschaft".to_string();
+        new.wk_place = "Köln".to_string();
+        new.wk_judgingtables.remove("2");
+        new.wk_judgingtables.insert("3".to_string(), table("3", "KG 3", &[(JudgeRole::Ak1, "David Braun")]));
+        let first = new.wk_judgingtables.get_mut("1").unwrap();
+        first.table_name = "KG 1 Finale".to_string();
+        first.table_kind = Discipline::Spiraleturnen;
+        first.table_is_finale = true;
+        first.table_clubs = vec!["TV Nord".to_string()];
+        first.judges.remove(&JudgeRole::Sk1);
+        first.judges.get_mut(&JudgeRole::Ok).unwrap().name = "Eva Richter".to_string();
+        first.judges.insert(JudgeRole::Ak2, Kampfrichter { role: JudgeRole::Ak2, name: "Ben Weber".to_string(), doubleFound: false });
+        new.wk_replacement_judges.clear();
+        new.wk_judge_roster = vec![RosterJudge {
+            name: "Anna Schmidt".to_string(),
+            club: "TV Nord".to_string(),
+            license: Some(LicenseLevel::A),
+            disciplines: Vec::new(),
+        }];
+
+        let mut history = History::default();
+        history.record(diff(&old, &new));
+        let mut wettkampf = new.clone();
+
+        assert_eq!(history.undo(&mut wettkampf), Some("Mehrere Änderungen"));
+        assert_eq!(wettkampf, old);
+        assert!(diff(&wettkampf, &old).is_empty());
+        assert_eq!(history.redo(&mut wettkampf), Some("Mehrere Änderungen"));
+        assert_eq!(wettkampf, new);
+        assert_eq!(history.redo(&mut wettkampf), None);
+    }
+
+    #[test]
+    fn a_copied_table_is_recognized() {
+        let old = wettkampf();
+        let mut new = old.clone();
+        let mut copy = new.wk_judgingtables["2"].clone();
+        copy.uniqueID = "4".to_string();
+        new.wk_judgingtables.insert("4".to_string(), copy.clone());
+
+        assert_eq!(diff(&old, &new), vec![Edit::CopyTable { table: copy }]);
+    }
+
+    #[test]
+    fn typing_is_undone_at_once() {
+        let mut history = History::default();
+        let mut wettkampf = wettkampf();
+        for name in ["D", "De", "Deu", "Deutsche Meisterschaft"] {
+            change(&mut history, &mut wettkampf, |wettkampf| wettkampf.wk_name = name.to_string());
+        }
+        change(&mut history, &mut wettkampf, |wettkampf| wettkampf.wk_place = "Köln".to_string());
+
+        assert_eq!(history.undo(&mut wettkampf), Some("Wettkampfdaten ändern"));
+        assert_eq!(wettkampf.wk_name, "Deutsche Meisterschaft");
+        assert_eq!(history.undo(&mut wettkampf), Some("Wettkampfdaten ändern"));
+        assert_eq!(wettkampf.wk_name, "Landesmeisterschaft");
+        assert_eq!(history.undo(&mut wettkampf), None);
+    }
+
+    #[test]
+    fn typing_that_changes_nothing_ends_the_typing() {
+        let mut history = History::default();
+        let mut wettkampf = wettkampf();
+        let set_judge = |name: &'static str| {
+            return move |wettkampf: &mut Wettkampf| {
+                wettkampf.wk_judgingtables.get_mut("2").unwrap().judges.get_mut(&JudgeRole::Ok).unwrap().name = name.to_string();
+            };
+        };
+        change(&mut history, &mut wettkampf, set_judge("David Braun"));
+        // Typed and deleted again, so there is no step for it
+        change(&mut history, &mut wettkampf, |wettkampf| wettkampf.wk_place = "K".to_string());
+        change(&mut history, &mut wettkampf, |wettkampf| wettkampf.wk_place = String::new());
+        change(&mut history, &mut wettkampf, set_judge("Eva Richter"));
+
+        history.undo(&mut wettkampf);
+        assert_eq!(wettkampf.wk_judgingtables["2"].judges[&JudgeRole::Ok].name, "David Braun");
+        history.undo(&mut wettkampf);
+        assert_eq!(wettkampf.wk_judgingtables["2"].judges[&JudgeRole::Ok].name, "Clara Wolf");
+        assert_eq!(history.undo(&mut wettkampf), None);
+    }
+
+    #[test]
+    fn undo_ends_the_typing() {
+        let mut history = History::default();
+        let mut wettkampf = wettkampf();
+        change(&mut history, &mut wettkampf, |wettkampf| wettkampf.wk_name = "A".to_string());
+        change(&mut history, &mut wettkampf, |wettkampf| wettkampf.wk_place = "Köln".to_string());
+        history.undo(&mut wettkampf);
+        change(&mut history, &mut wettkampf, |wettkampf| wettkampf.wk_name = "AB".to_string());
+
+        history.undo(&mut wettkampf);
+        assert_eq!(wettkampf.wk_name, "A");
+        history.undo(&mut wettkampf);
+        assert_eq!(wettkampf.wk_name, "Landesmeisterschaft");
+    }
+
+    #[test]
+    fn undo_recalculates_the_conflicts() {
+        let mut history = History::default();
+        let mut wettkampf = wettkampf();
+        change(&mut history, &mut wettkampf, |wettkampf| {
+            let judge = wettkampf.wk_judgingtables.get_mut("2").unwrap().judges.get_mut(&JudgeRole::Ok).unwrap();
+            judge.name = "Anna Schmidt".to_string();
+        });
+        history.undo(&mut wettkampf);
+        history.redo(&mut wettkampf);
+
+        assert!(wettkampf.wk_judgingtables["1"].judges[&JudgeRole::Ok].doubleFound);
+        assert!(wettkampf.wk_judgingtables["2"].judges[&JudgeRole::Ok].doubleFound);
+    }
+
+    #[test]
+    fn the_position_follows_undo_and_redo() {
+        let mut history = History::default();
+        let mut wettkampf = wettkampf();
+        assert_eq!(history.position(), 0);
+
+        change(&mut history, &mut wettkampf, |wettkampf| wettkampf.wk_name = "A".to_string());
+        let typed = history.position();
+        change(&mut history, &mut wettkampf, |wettkampf| wettkampf.wk_name = "AB".to_string());
+        let merged = history.position();
+        assert_ne!(typed, merged);
+
+        history.undo(&mut wettkampf);
+        assert_eq!(history.position(), 0);
+        history.redo(&mut wettkampf);
+        assert_eq!(history.position(), merged);
+
+        // A different change after undoing is a new state
+        history.undo(&mut wettkampf);
+        change(&mut history, &mut wettkampf, |wettkampf| wettkampf.wk_name = "AB".to_string());
+        assert_ne!(history.position(), merged);
+        assert_ne!(history.position(), 0);
+    }
+
+    #[test]
+    fn only_the_latest_steps_are_kept() {
+        let mut history = History::default();
+        let mut wettkampf = wettkampf();
+        for index in 0..MAX_HISTORY_STEPS + 5 {
+            // Different fields, so the steps are not merged
+            change(&mut history, &mut wettkampf, |wettkampf| wettkampf.wk_judgingtables.get_mut("1").unwrap().table_name = format!("KG {index}"));
+            change(&mut history, &mut wettkampf, |wettkampf| wettkampf.wk_place = format!("Halle {index}"));
+        }
+        let mut undone = 0;
+        while history.undo(&mut wettkampf).is_some() {
+            undone += 1;
+        }
+
+        assert_eq!(undone, MAX_HISTORY_STEPS);
+        assert_ne!(history.position(), 0);
+    }
+}
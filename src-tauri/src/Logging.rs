@@ -0,0 +1,685 @@
+use crate::types::ApplicationError;
+use crate::Errors::DetailedError;
+use crate::Resources::application_data_dir;
+use chrono::{DateTime, Local, NaiveDateTime};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, SystemTime};
+
+// The backend of the log crate: Every launch (session) writes its records into its own file in the Logs folder.
+// A record is one line: timestamp, level, module and message. Further lines of a message are indented,
+// so a reader can tell where a record ends. Debug builds also echo every record to the console.
+
+/// When the log of a session grows beyond this, it is moved to its .1.log file and a new one is started.
+pub const MAX_LOG_FILE_SIZE: u64 = 5 * 1024 * 1024;
+/// Logs of sessions older than this are deleted on startup.
+pub const MAX_LOG_AGE_DAYS: u64 = 14;
+/// Logs of at most this many sessions are kept, the oldest ones are deleted on startup.
+pub const MAX_LOG_SESSIONS: usize = 20;
+/// The format of the timestamp at the start of every record.
+pub const LOG_TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f";
+
+const LOG_FILE_PREFIX: &str = "session_";
+/// Written by versions before 1.4, which redirected stdout and stderr into a pair of files per launch.
+const LEGACY_LOG_FILE_PREFIX: &str = "LOG__";
+/// The crates whose records count as our own: The shared library, the app and kre-cli.
+const OWN_CRATES: [&str; 3] = [env!("CARGO_CRATE_NAME"), "dtb_kampfrichtereinsatzplaene", "kre_cli"];
+
+static LOGGER: OnceLock<SessionLogger> = OnceLock::new();
+/// How often the log of this session was rotated, so a reader can tell its .1.log was replaced, see read_log.
+static LOG_ROTATIONS: AtomicU64 = AtomicU64::new(0);
+
+struct SessionLogger {
+    /// None if the log file could not be created, then the records only go to the console.
+    output: Mutex<Option<LogFile>>,
+    /// The records written before the log file is opened, see start_session_log. None once it is opened or failed.
+    early_records: Mutex<Option<Vec<String>>>,
+    /// Always on without a log file, so the records are not lost.
+    echo_to_console: AtomicBool,
+}
+
+struct LogFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+}
+
+/// Returns: The folder all logs are written to.
+pub fn log_dir() -> Option<PathBuf> {
+    return application_data_dir().map(|dir| dir.join("Logs"));
+}
+
+/// Returns: The log file of the running session, None if logging into a file is not possible.
+pub fn current_log_file() -> Option<PathBuf> {
+    return LOGGER.get().and_then(|logger| logger.lock().as_ref().map(|output| output.path.clone()));
+}
+
+/// Installs the logger of the app, echoing to the console in debug builds.
+/// The records are kept in memory until start_session_log opens the log file, as a second launch that only
+/// hands its files over to the running app must neither create a log nor delete old ones.
+pub fn init_logging() {
+    install(SessionLogger {
+        output: Mutex::new(None),
+        early_records: Mutex::new(Some(Vec::new())),
+        echo_to_console: AtomicBool::new(cfg!(debug_assertions)),
+    });
+}
+
+/// Opens the log file of this session and writes the records kept since init_logging into it.
+/// To be called once the app is known to be the only running instance.
+/// Logs of old sessions are deleted, see MAX_LOG_AGE_DAYS and MAX_LOG_SESSIONS.
+/// Returns: A LogFileCreationError if there is no log file. The records still go to the console then, also in release builds.
+pub fn start_session_log() -> Result<(), DetailedError> {
+    let logger = match LOGGER.get() {
+        Some(logger) => logger,
+        None => return Ok(()),
+    };
+    // Held until the file is in place, so no record gets lost in between
+    let mut output = logger.lock();
+    let early_records = logger.early_records.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).take().unwrap_or_default();
+    let mut log_file = match create_session_log() {
+        Ok(log_file) => log_file,
+        Err(err) => {
+            logger.echo_to_console.store(true, Ordering::Relaxed);
+            drop(output);
+            // They were not echoed in release builds
+            if !cfg!(debug_assertions) {
+                eprint!("{}", early_records.concat());
+            }
+            return Err(err);
+        }
+    };
+    for line in early_records {
+        if log_file.file.write_all(line.as_bytes()).is_ok() {
+            log_file.size += line.len() as u64;
+        }
+    }
+    let dir = log_file.path.parent().map(|dir| dir.to_path_buf());
+    *output = Some(log_file);
+    drop(output);
+    // Only now, so problems with deleting them end up in the new log
+    if let Some(dir) = dir {
+        remove_old_logs(&dir);
+    }
+    return Ok(());
+}
+
+/// Installs a logger that only writes to the console, for the command line interface.
+pub fn init_console_logging() {
+    install(SessionLogger {
+        output: Mutex::new(None),
+        early_records: Mutex::new(None),
+        echo_to_console: AtomicBool::new(true),
+    });
+}
+
+fn install(logger: SessionLogger) {
+    let logger = LOGGER.get_or_init(|| logger);
+    match log::set_logger(logger) {
+        Ok(()) => log::set_max_level(max_level()),
+        // Only possible if a logger was installed before, which then stays in use
+        Err(err) => log::warn!("Could not install the logger: {err}"),
+    }
+}
+
+fn max_level() -> LevelFilter {
+    return if cfg!(debug_assertions) { LevelFilter::Debug } else { LevelFilter::Info };
+}
+
+/// Records of our own modules are kept from max_level(), those of the libraries only from warnings on.
+fn is_logged(metadata: &Metadata) -> bool {
+    let own_record = metadata.target().split("::").next().is_some_and(|name| OWN_CRATES.contains(&name));
+    return if own_record { metadata.level() <= max_level() } else { metadata.level() <= Level::Warn };
+}
+
+impl Log for SessionLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        return is_logged(metadata);
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = format_record(record);
+        if self.echo_to_console.load(Ordering::Relaxed) {
+            eprint!("{line}");
+        }
+        let mut output = self.lock();
+        if output.is_none() {
+            if let Some(early_records) = self.early_records.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).as_mut() {
+                early_records.push(line);
+                return;
+            }
+        }
+        if let Some(log_file) = output.as_mut() {
+            // There is nowhere left to report a failed write to
+            if log_file.file.write_all(line.as_bytes()).is_ok() {
+                log_file.size += line.len() as u64;
+            }
+            if log_file.size > MAX_LOG_FILE_SIZE {
+                let path = log_file.path.clone();
+                // Closed first, as Windows cannot move open files
+                *output = None;
+                *output = rotate(&path);
+                // Only counted once the files are in place, so a reader never takes the old files for the new ones
+                LOG_ROTATIONS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(log_file) = self.lock().as_mut() {
+            let _ = log_file.file.flush();
+        }
+    }
+}
+
+impl SessionLogger {
+    fn lock(&self) -> MutexGuard<'_, Option<LogFile>> {
+        // A panic while writing a record leaves at most a broken line behind, so the file can still be used
+        return self.output.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    }
+}
+
+/// Returns: The record as one line, further lines of the message indented, e.g.
+/// "2026-10-18 10:29:26.123 ERROR [dtb_kampfrichtereinsatzplaene::FileFormat] Could not read the file"
+fn format_record(record: &Record) -> String {
+    let message = record.args().to_string().replace('\n', "\n    ");
+    return format!(
+        "{} {:<5} [{}] {message}\n",
+        Local::now().format(LOG_TIMESTAMP_FORMAT),
+        record.level(),
+        record.target()
+    );
+}
+
+fn create_session_log() -> Result<LogFile, DetailedError> {
+    let dir = match log_dir() {
+        Some(dir) => dir,
+        None => {
+            return Err(DetailedError::new(ApplicationError::LogFileCreationError).caused_by_text("Could not get the Base Dirs for the Logs folder"));
+        }
+    };
+    match std::fs::create_dir_all(&dir) {
+        Ok(()) => {}
+        Err(err) => return Err(DetailedError::new(ApplicationError::LogFileCreationError).with_path(&dir).caused_by(&err)),
+    }
+
+    let path = dir.join(format!("{LOG_FILE_PREFIX}{}.log", Local::now().format("%Y-%m-%d_%H-%M-%S")));
+    return match File::options().create(true).append(true).open(&path) {
+        Ok(file) => Ok(LogFile { size: file.metadata().map(|metadata| metadata.len()).unwrap_or(0), path, file }),
+        Err(err) => Err(DetailedError::new(ApplicationError::LogFileCreationError).with_path(&path).caused_by(&err)),
+    };
+}
+
+/// Moves a full log to its .1.log file, replacing the previous one, and starts the log anew.
+/// Returns: The new log, None if it could not be created.
+fn rotate(path: &Path) -> Option<LogFile> {
+    // If the log cannot be moved, it just keeps growing
+    let _ = std::fs::rename(path, rotated_path(path));
+    let file = File::options().create(true).append(true).open(path).ok()?;
+    return Some(LogFile { path: path.to_path_buf(), file, size: 0 });
+}
+
+/// Returns: Where the earlier records of a session are moved to when its log is full, e.g. session_2026-10-18_10-29-26.1.log.
+pub fn rotated_path(path: &Path) -> PathBuf {
+    return path.with_extension("1.log");
+}
+
+/// Returns: The session a file in the Logs folder belongs to, None for files that are no logs.
+/// A session has a log and possibly a rotated log, a legacy session a stdout and a stderr file.
+pub fn session_of_log_file(file_name: &str) -> Option<&str> {
+    if file_name.starts_with(LOG_FILE_PREFIX) && file_name.ends_with(".log") {
+        return file_name.split('.').next();
+    }
+    if file_name.starts_with(LEGACY_LOG_FILE_PREFIX) && file_name.ends_with(".txt") {
+        return file_name.rsplit_once("__").map(|(session, _)| session);
+    }
+    return None;
+}
+
+/// The files of every session in the folder, together with when the session last wrote to them.
+fn collect_sessions(dir: &Path) -> std::io::Result<HashMap<String, (SystemTime, Vec<PathBuf>)>> {
+    let mut sessions: HashMap<String, (SystemTime, Vec<PathBuf>)> = HashMap::new();
+    for entry in std::fs::read_dir(dir)?.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let session = match session_of_log_file(&file_name) {
+            Some(session) => session.to_string(),
+            None => continue,
+        };
+        let modified = entry.metadata().and_then(|metadata| metadata.modified()).unwrap_or(SystemTime::UNIX_EPOCH);
+        let (last_modified, files) = sessions.entry(session).or_insert((SystemTime::UNIX_EPOCH, Vec::new()));
+        *last_modified = (*last_modified).max(modified);
+        files.push(entry.path());
+    }
+    // The rotated log holds the earlier records, so it comes first
+    for (_, files) in sessions.values_mut() {
+        files.sort_by_key(|file| (!file.to_string_lossy().ends_with(".1.log"), file.clone()));
+    }
+    return Ok(sessions);
+}
+
+/// Deletes the logs of sessions older than MAX_LOG_AGE_DAYS and of all but the MAX_LOG_SESSIONS most recent sessions.
+fn remove_old_logs(dir: &Path) {
+    let sessions = match collect_sessions(dir) {
+        Ok(sessions) => sessions,
+        Err(err) => {
+            log::warn!("Could not list the old logs: {:?}", err);
+            return;
+        }
+    };
+
+    let mut sessions: Vec<(SystemTime, Vec<PathBuf>)> = sessions.into_values().collect();
+    sessions.sort_by(|(a, _), (b, _)| b.cmp(a));
+    let oldest_kept = SystemTime::now() - Duration::from_secs(MAX_LOG_AGE_DAYS * 24 * 60 * 60);
+    // The log of this session is the most recent one, so it is always kept
+    for (index, (last_modified, files)) in sessions.into_iter().enumerate() {
+        if index < MAX_LOG_SESSIONS && last_modified >= oldest_kept {
+            continue;
+        }
+        for file in files {
+            match std::fs::remove_file(&file) {
+                Ok(()) => {}
+                Err(err) => log::warn!("Could not delete the old log {}: {:?}", file.display(), err),
+            }
+        }
+    }
+}
+
+// MARK: Reading
+
+/// A session with logs, as listed in the log viewer.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogSession {
+    /// The name of the session, e.g. session_2026-10-18_10-29-26.
+    pub id: String,
+    /// The file the session writes to, or wrote to last.
+    pub path: PathBuf,
+    /// When the session last wrote to its log, e.g. 18.10.2026 10:29.
+    pub last_modified: String,
+    /// The size of all files of the session in bytes.
+    pub size: u64,
+    /// Whether this is the session of the running app, whose log still grows.
+    pub is_current: bool,
+}
+
+/// A record of a log, see format_record.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LogRecord {
+    /// None for lines that are no records, e.g. the raw output in the logs of versions before 1.4.
+    pub timestamp: Option<String>,
+    /// ERROR, WARN, INFO, DEBUG or TRACE, None for lines that are no records.
+    pub level: Option<String>,
+    /// The module the record comes from.
+    pub target: String,
+    pub message: String,
+}
+
+/// The records read from a log, see read_log.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogChunk {
+    pub records: Vec<LogRecord>,
+    /// Where to continue reading on the next call.
+    pub offset: u64,
+    /// How often the log was rotated when it was read, to be passed to the next call.
+    pub rotations: u64,
+    /// Whether the records start from the beginning again instead of continuing at the requested offset.
+    pub reset: bool,
+}
+
+/// Returns: All sessions with logs, the most recent first.
+pub fn list_log_sessions() -> Vec<LogSession> {
+    let sessions = match log_dir().map(|dir| collect_sessions(&dir)) {
+        Some(Ok(sessions)) => sessions,
+        Some(Err(err)) => {
+            log::error!("Could not list the logs: {:?}", err);
+            return Vec::new();
+        }
+        None => return Vec::new(),
+    };
+    let current_log_file = current_log_file();
+    let mut sessions: Vec<(SystemTime, LogSession)> = sessions
+        .into_iter()
+        .filter_map(|(id, (last_modified, files))| {
+            let path = files.last()?.clone();
+            let session = LogSession {
+                id,
+                is_current: current_log_file.as_ref() == Some(&path),
+                last_modified: DateTime::<Local>::from(last_modified).format("%d.%m.%Y %H:%M").to_string(),
+                size: files.iter().filter_map(|file| file.metadata().ok()).map(|metadata| metadata.len()).sum(),
+                path,
+            };
+            return Some((last_modified, session));
+        })
+        .collect();
+    sessions.sort_by(|(a, _), (b, _)| b.cmp(a));
+    return sessions.into_iter().map(|(_, session)| session).collect();
+}
+
+/// Returns: The files of the given number of most recent sessions, e.g. for a diagnostics bundle.
+pub fn recent_log_files(sessions: usize) -> Vec<PathBuf> {
+    let mut all_sessions: Vec<(SystemTime, Vec<PathBuf>)> = match log_dir().map(|dir| collect_sessions(&dir)) {
+        Some(Ok(all_sessions)) => all_sessions.into_values().collect(),
+        Some(Err(err)) => {
+            log::error!("Could not list the logs: {:?}", err);
+            return Vec::new();
+        }
+        None => return Vec::new(),
+    };
+    all_sessions.sort_by(|(a, _), (b, _)| b.cmp(a));
+    return all_sessions.into_iter().take(sessions).flat_map(|(_, files)| files).collect();
+}
+
+/// Reads the records of a session, all its files one after the other.
+/// Param 1: The id of the session, as returned by list_log_sessions
+/// Param 2: The offset returned by the previous call, 0 to read from the start
+/// Param 3: The rotations returned by the previous call, 0 to read from the start
+/// Returns: The complete records after the offset. Starts from the beginning if the log was rotated since.
+pub fn read_log(session: &str, offset: u64, rotations: u64) -> Result<LogChunk, DetailedError> {
+    return match log_dir() {
+        Some(dir) => read_log_in(&dir, session, offset, rotations),
+        None => Err(DetailedError::new(ApplicationError::LogReadError).caused_by_text("Could not get the Base Dirs for the Logs folder")),
+    };
+}
+
+/// read_log for the logs in the given folder.
+fn read_log_in(dir: &Path, session: &str, offset: u64, rotations: u64) -> Result<LogChunk, DetailedError> {
+    let files = match collect_sessions(dir) {
+        Ok(mut sessions) => sessions.remove(session).map(|(_, files)| files),
+        Err(err) => return Err(DetailedError::new(ApplicationError::LogReadError).with_path(dir).caused_by(&err)),
+    };
+    let files = match files {
+        Some(files) => files,
+        None => return Err(DetailedError::new(ApplicationError::LogReadError).caused_by_text(format!("There is no log of the session {session}"))),
+    };
+
+    // Only the log of this session is still written to, the others never rotate again
+    let is_current = current_log_file().is_some_and(|path| files.contains(&path));
+    let current_rotations = if is_current { LOG_ROTATIONS.load(Ordering::SeqCst) } else { 0 };
+    let mut contents = Vec::new();
+    for file in &files {
+        match std::fs::read(file) {
+            Ok(bytes) => contents.extend(bytes),
+            // The rotated log might be replaced while we read it
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(DetailedError::new(ApplicationError::LogReadError).with_path(file).caused_by(&err)),
+        }
+    }
+
+    // After a rotation, the .1.log holds other records than before, even if the log did not get shorter
+    let reset = rotations != current_rotations || offset > contents.len() as u64;
+    let start = if reset { 0 } else { offset as usize };
+    // A record being written right now is left for the next call
+    let end = match contents[start..].iter().rposition(|byte| *byte == b'\n') {
+        Some(position) => start + position + 1,
+        None => start,
+    };
+    return Ok(LogChunk {
+        records: parse_records(&String::from_utf8_lossy(&contents[start..end])),
+        offset: end as u64,
+        rotations: current_rotations,
+        reset,
+    });
+}
+
+/// Splits a log into its records, see format_record. Lines that are no records become records of their own,
+/// indented lines belong to the record before them.
+pub fn parse_records(contents: &str) -> Vec<LogRecord> {
+    let mut records: Vec<LogRecord> = Vec::new();
+    for line in contents.lines() {
+        if let Some(continuation) = line.strip_prefix("    ") {
+            if let Some(record) = records.last_mut() {
+                record.message.push('\n');
+                record.message.push_str(continuation);
+                continue;
+            }
+        }
+        records.push(parse_record(line));
+    }
+    return records;
+}
+
+fn parse_record(line: &str) -> LogRecord {
+    let unparsed = LogRecord {
+        timestamp: None,
+        level: None,
+        target: String::new(),
+        message: line.to_string(),
+    };
+    // "2026-10-18 10:29:26.123" is 23 characters long
+    let (timestamp, rest) = match (line.get(..23), line.get(23..)) {
+        (Some(timestamp), Some(rest)) if NaiveDateTime::parse_from_str(timestamp, LOG_TIMESTAMP_FORMAT).is_ok() => (timestamp, rest),
+        _ => return unparsed,
+    };
+    let (level, rest) = match rest.trim_start().split_once(' ') {
+        Some((level, rest)) if level.parse::<Level>().is_ok() => (level, rest.trim_start()),
+        _ => return unparsed,
+    };
+    let (target, message) = match rest.strip_prefix('[').and_then(|rest| rest.split_once("] ")) {
+        Some((target, message)) => (target, message),
+        None => return unparsed,
+    };
+    return LogRecord {
+        timestamp: Some(timestamp.to_string()),
+        level: Some(level.to_string()),
+        target: target.to_string(),
+        message: message.to_string(),
+    };
+}
+
+/// Writes records selected in the log viewer to a file, e.g. to attach them to a support request.
+pub fn export_log_records(path: &Path, contents: &str) -> Result<(), DetailedError> {
+    return match std::fs::write(path, contents) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(DetailedError::new(ApplicationError::RustWriteFileError).with_path(path).caused_by(&err)),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_folder(name: &str) -> PathBuf {
+        let folder = std::env::temp_dir().join(format!("kre-logging-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&folder);
+        std::fs::create_dir_all(&folder).unwrap();
+        return folder;
+    }
+
+    /// Writes a log file that was last written to the given number of hours ago.
+    fn write_log(path: &Path, contents: &str, hours_ago: u64) {
+        std::fs::write(path, contents).unwrap();
+        let modified = SystemTime::now() - Duration::from_secs(hours_ago * 60 * 60);
+        File::options().write(true).open(path).unwrap().set_modified(modified).unwrap();
+    }
+
+    fn file_names(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = std::fs::read_dir(dir).unwrap().flatten().map(|entry| entry.file_name().to_string_lossy().to_string()).collect();
+        names.sort();
+        return names;
+    }
+
+    fn record(message: &str) -> String {
+        return format!("2026-10-18 10:29:26.123 INFO  [dtb_kampfrichtereinsatzplaene::Logging] {message}\n");
+    }
+
+    #[test]
+    fn finds_the_session_of_a_log_file() {
+        assert_eq!(session_of_log_file("session_2026-10-18_10-29-26.log"), Some("session_2026-10-18_10-29-26"));
+        assert_eq!(session_of_log_file("session_2026-10-18_10-29-26.1.log"), Some("session_2026-10-18_10-29-26"));
+        assert_eq!(session_of_log_file("LOG__2024-05-01_08-00-00__stdout.txt"), Some("LOG__2024-05-01_08-00-00"));
+        assert_eq!(session_of_log_file("LOG__2024-05-01_08-00-00__stderr.txt"), Some("LOG__2024-05-01_08-00-00"));
+        assert_eq!(session_of_log_file("session_2026-10-18_10-29-26.txt"), None);
+        assert_eq!(session_of_log_file("Export.log"), None);
+        assert_eq!(session_of_log_file("settings.json"), None);
+    }
+
+    #[test]
+    fn rotating_moves_the_log_to_its_rotated_file() {
+        let dir = test_folder("rotate");
+        let path = dir.join("session_2026-10-18_10-29-26.log");
+        std::fs::write(&path, "first").unwrap();
+
+        let log_file = rotate(&path).unwrap();
+        assert_eq!(log_file.size, 0);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "");
+        assert_eq!(std::fs::read_to_string(dir.join("session_2026-10-18_10-29-26.1.log")).unwrap(), "first");
+
+        // Only one rotated file is kept
+        std::fs::write(&path, "second").unwrap();
+        rotate(&path).unwrap();
+        assert_eq!(std::fs::read_to_string(dir.join("session_2026-10-18_10-29-26.1.log")).unwrap(), "second");
+        assert_eq!(file_names(&dir), vec!["session_2026-10-18_10-29-26.1.log", "session_2026-10-18_10-29-26.log"]);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn a_full_log_is_rotated() {
+        let dir = test_folder("full");
+        let path = dir.join("session_2026-10-18_10-29-26.log");
+        let file = File::options().create(true).append(true).open(&path).unwrap();
+        let logger = SessionLogger {
+            output: Mutex::new(Some(LogFile { path: path.clone(), file, size: 0 })),
+            early_records: Mutex::new(None),
+            echo_to_console: AtomicBool::new(false),
+        };
+        let log = |message: &str| {
+            logger.log(&Record::builder().level(Level::Warn).target(env!("CARGO_CRATE_NAME")).args(format_args!("{message}")).build());
+        };
+
+        log("before");
+        let large = "x".repeat(MAX_LOG_FILE_SIZE as usize);
+        log(&large);
+        log("after");
+
+        let rotated = std::fs::read_to_string(rotated_path(&path)).unwrap();
+        assert!(rotated.contains("before"));
+        assert!(rotated.contains(&large));
+        let current = std::fs::read_to_string(&path).unwrap();
+        assert!(current.contains("after"));
+        assert!(!current.contains("before"));
+        assert_eq!(logger.lock().as_ref().unwrap().size, current.len() as u64);
+        drop(logger);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn keeps_the_most_recent_sessions() {
+        let dir = test_folder("count");
+        for session in 0..MAX_LOG_SESSIONS as u64 + 2 {
+            write_log(&dir.join(format!("session_{session:02}.log")), "", session);
+        }
+        // The rotated log of a kept session is no session of its own
+        write_log(&dir.join("session_03.1.log"), "", 4);
+        // Nor is the second file of a legacy session
+        write_log(&dir.join("LOG__legacy__stdout.txt"), "", MAX_LOG_SESSIONS as u64 + 5);
+        write_log(&dir.join("LOG__legacy__stderr.txt"), "", MAX_LOG_SESSIONS as u64 + 5);
+        std::fs::write(dir.join("notes.txt"), "").unwrap();
+
+        remove_old_logs(&dir);
+
+        let names = file_names(&dir);
+        assert!(names.contains(&"session_00.log".to_string()));
+        assert!(names.contains(&"session_03.1.log".to_string()));
+        assert!(names.contains(&format!("session_{:02}.log", MAX_LOG_SESSIONS - 1)));
+        assert!(!names.contains(&format!("session_{:02}.log", MAX_LOG_SESSIONS)));
+        assert!(!names.contains(&format!("session_{:02}.log", MAX_LOG_SESSIONS + 1)));
+        assert!(!names.iter().any(|name| name.starts_with(LEGACY_LOG_FILE_PREFIX)));
+        assert!(names.contains(&"notes.txt".to_string()));
+        assert_eq!(names.len(), MAX_LOG_SESSIONS + 2);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn deletes_sessions_older_than_the_maximum_age() {
+        let dir = test_folder("age");
+        let max_age_hours = MAX_LOG_AGE_DAYS * 24;
+        write_log(&dir.join("session_new.log"), "", 0);
+        write_log(&dir.join("session_recent.log"), "", max_age_hours - 1);
+        write_log(&dir.join("session_old.log"), "", max_age_hours + 1);
+        // A session counts as recent as long as one of its files is
+        write_log(&dir.join("session_rotated.1.log"), "", max_age_hours + 1);
+        write_log(&dir.join("session_rotated.log"), "", max_age_hours - 1);
+
+        remove_old_logs(&dir);
+
+        assert_eq!(file_names(&dir), vec!["session_new.log", "session_recent.log", "session_rotated.1.log", "session_rotated.log"]);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn reads_a_log_from_the_offset() {
+        let dir = test_folder("read");
+        let path = dir.join("session_2026-10-18_10-29-26.log");
+        std::fs::write(&path, format!("{}{}", record("first"), record("second"))).unwrap();
+
+        let chunk = read_log_in(&dir, "session_2026-10-18_10-29-26", 0, 0).unwrap();
+        assert_eq!(chunk.records.iter().map(|record| record.message.as_str()).collect::<Vec<_>>(), vec!["first", "second"]);
+        assert_eq!(chunk.records[0].level.as_deref(), Some("INFO"));
+        assert_eq!(chunk.records[0].target, "dtb_kampfrichtereinsatzplaene::Logging");
+        assert_eq!(chunk.offset, std::fs::metadata(&path).unwrap().len());
+        assert!(!chunk.reset);
+
+        // A record still being written is left for the next call
+        let partial = record("third");
+        let (start, end) = partial.split_at(30);
+        File::options().append(true).open(&path).unwrap().write_all(start.as_bytes()).unwrap();
+        let unfinished = read_log_in(&dir, "session_2026-10-18_10-29-26", chunk.offset, chunk.rotations).unwrap();
+        assert!(unfinished.records.is_empty());
+        assert_eq!(unfinished.offset, chunk.offset);
+
+        File::options().append(true).open(&path).unwrap().write_all(end.as_bytes()).unwrap();
+        let next = read_log_in(&dir, "session_2026-10-18_10-29-26", unfinished.offset, unfinished.rotations).unwrap();
+        assert_eq!(next.records.iter().map(|record| record.message.as_str()).collect::<Vec<_>>(), vec!["third"]);
+        assert!(!next.reset);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn reads_the_rotated_log_first() {
+        let dir = test_folder("read-rotated");
+        std::fs::write(dir.join("session_2026-10-18_10-29-26.log"), record("later")).unwrap();
+        std::fs::write(dir.join("session_2026-10-18_10-29-26.1.log"), format!("{}{}", record("earlier"), record("message\n    with two lines"))).unwrap();
+
+        let chunk = read_log_in(&dir, "session_2026-10-18_10-29-26", 0, 0).unwrap();
+        assert_eq!(
+            chunk.records.iter().map(|record| record.message.as_str()).collect::<Vec<_>>(),
+            vec!["earlier", "message\nwith two lines", "later"]
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn starts_anew_after_a_rotation() {
+        let dir = test_folder("read-reset");
+        std::fs::write(dir.join("session_2026-10-18_10-29-26.log"), record("first")).unwrap();
+        let chunk = read_log_in(&dir, "session_2026-10-18_10-29-26", 0, 0).unwrap();
+
+        // A log shorter than the offset was replaced
+        let shorter = read_log_in(&dir, "session_2026-10-18_10-29-26", chunk.offset + 100, chunk.rotations).unwrap();
+        assert!(shorter.reset);
+        assert_eq!(shorter.records, chunk.records);
+
+        // Other rotations than before mean other records, even at a valid offset
+        let rotated = read_log_in(&dir, "session_2026-10-18_10-29-26", chunk.offset, chunk.rotations + 1).unwrap();
+        assert!(rotated.reset);
+        assert_eq!(rotated.records, chunk.records);
+        assert_eq!(rotated.rotations, chunk.rotations);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn reading_an_unknown_session_fails() {
+        let dir = test_folder("read-unknown");
+        let err = read_log_in(&dir, "session_2026-10-18_10-29-26", 0, 0).unwrap_err();
+        assert_eq!(err.code(), ApplicationError::LogReadError);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,13 +1,30 @@
 use crate::types::ApplicationError;
-use crate::{
-    APP_VERSION, LLVM_VER, STDERR_FILE, STDOUT_FILE, TARGET_TRIPLE,
-};
+use crate::Resources::application_data_dir;
+use crate::Settings::{current_settings, is_mail_address, MailTransport, Settings, SmtpAuth, SmtpTls};
+use crate::Logging::current_log_file;
+use crate::{APP_VERSION, LLVM_VER, TARGET_TRIPLE};
+use chrono::Local;
 use mail_builder::MessageBuilder;
 use mail_send::SmtpClientBuilder;
-use std::fs::File;
-use std::io::Read;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
 
-#[derive(Clone)]
+// Mails go out through the transport of the settings. Environment variables override the settings,
+// e.g. to point a test run at a local SMTP stand-in without touching the settings of the user:
+// KRE_MAIL_TRANSPORT (smtp or file), KRE_SMTP_HOST, KRE_SMTP_PORT, KRE_SMTP_TLS (starttls, implicit or none),
+// KRE_SMTP_USERNAME and KRE_SMTP_PASSWORD (log in with these), KRE_MAIL_FROM, KRE_MAIL_TO and KRE_MAIL_DIR.
+
+/// Gives up connecting after this long. Without it, a hall without internet would keep a mail pending for an hour.
+const SMTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A file attached to a mail: its name and contents.
+pub type Attachment = (String, Vec<u8>);
+
+static NEXT_MAIL_FILE_ID: AtomicU32 = AtomicU32::new(0);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 // TODO: Remove
 #[allow(unused)]
 pub enum MessageKind {
@@ -19,7 +36,18 @@ pub enum MessageKind {
 }
 
 impl MessageKind {
-    fn generate_subject(&self) -> String {
+    /// Returns: What kind of message this is, as shown in the outbox.
+    pub fn label(&self) -> &'static str {
+        return match self {
+            MessageKind::Bug(_) => "Bug",
+            MessageKind::Feedback(_) => "Feedback",
+            MessageKind::Support(_) => "Support",
+            MessageKind::Panic(_) => "Absturz",
+            MessageKind::Unknown => "Unbekannt",
+        };
+    }
+
+    pub fn generate_subject(&self) -> String {
         return match self {
             MessageKind::Bug(bug_desc) => format!("[BUG]: {}", bug_desc).to_string(),
             MessageKind::Feedback(feedback_descr) => {
@@ -44,99 +72,166 @@ impl MessageKind {
     }
 }
 
-// Global func to send a mail with contents
-pub async fn send_mail(kind: MessageKind, body: String, send_logs: bool) -> ApplicationError {
-    // Create the Client
-    let mut client = match SmtpClientBuilder::new("smtp-mail.outlook.com", 587)
-        .implicit_tls(false)
-        .credentials((
-            option_env!("MAIL_ADDRESS").unwrap_or("STUB"),
-            option_env!("MAIL_PASSWORD").unwrap_or("STUB"),
-        ))
-        .connect()
-        .await
-    {
-        Ok(client) => client,
+/// Returns: The log of this session as an attachment, None if there is none or it cannot be read.
+pub fn current_log_attachment() -> Option<Attachment> {
+    let log_file = match current_log_file() {
+        Some(log_file) => log_file,
+        None => {
+            log::error!("There is no log file to send, the logs only go to the console");
+            return None;
+        }
+    };
+    return match std::fs::read(&log_file) {
+        Ok(contents) => {
+            let file_name = log_file.file_name().map(|file_name| file_name.to_string_lossy().to_string()).unwrap_or_default();
+            Some((file_name, contents))
+        }
         Err(err) => {
-            eprintln!("Failed to connect to the SMTP Server: {:?}", err);
-            return ApplicationError::SMTPConnectionError;
+            log::error!("The log file {} could not be read: {:?}", log_file.display(), err);
+            None
         }
     };
+}
+
+/// Returns: The settings with the mail settings replaced by those of the environment, see the top of this file.
+/// Invalid values in the environment are ignored.
+pub fn mail_settings() -> Settings {
+    let mut settings = current_settings();
+    let variable = |name: &str| std::env::var(name).ok().filter(|value| !value.trim().is_empty());
 
+    if let Some(transport) = variable("KRE_MAIL_TRANSPORT") {
+        match transport.to_lowercase().as_str() {
+            "smtp" => settings.mail_transport = MailTransport::Smtp,
+            "file" => settings.mail_transport = MailTransport::File,
+            _ => log::warn!("Ignoring KRE_MAIL_TRANSPORT={transport}, expected smtp or file"),
+        }
+    }
+    if let Some(host) = variable("KRE_SMTP_HOST") {
+        settings.smtp_host = host;
+    }
+    if let Some(port) = variable("KRE_SMTP_PORT") {
+        match port.parse::<u16>() {
+            Ok(port) if port != 0 => settings.smtp_port = port,
+            _ => log::warn!("Ignoring KRE_SMTP_PORT={port}, expected a port number"),
+        }
+    }
+    if let Some(tls) = variable("KRE_SMTP_TLS") {
+        match tls.to_lowercase().as_str() {
+            "starttls" => settings.smtp_tls = SmtpTls::StartTls,
+            "implicit" => settings.smtp_tls = SmtpTls::Implicit,
+            "none" => settings.smtp_tls = SmtpTls::None,
+            _ => log::warn!("Ignoring KRE_SMTP_TLS={tls}, expected starttls, implicit or none"),
+        }
+    }
+    if let Some(username) = variable("KRE_SMTP_USERNAME") {
+        settings.smtp_auth = SmtpAuth::Login;
+        settings.smtp_username = username;
+        settings.smtp_password = variable("KRE_SMTP_PASSWORD").unwrap_or_default();
+    }
+    for (name, target) in [("KRE_MAIL_FROM", &mut settings.mail_from), ("KRE_MAIL_TO", &mut settings.mail_to)] {
+        match variable(name) {
+            Some(address) if is_mail_address(&address) => *target = address,
+            Some(address) => log::warn!("Ignoring {name}={address}, expected a mail address"),
+            None => {}
+        }
+    }
+    if let Some(dir) = variable("KRE_MAIL_DIR") {
+        settings.mail_dir = Some(PathBuf::from(dir));
+    }
+    return settings;
+}
+
+/// Sends a mail to the developers once. Mails that must not get lost go through the outbox instead, see Outbox.rs.
+/// Param 1: The kind of the message, which makes up the subject and heading
+/// Param 2: The HTML body
+/// Param 3: The log to attach, see current_log_attachment
+pub async fn send_mail(kind: &MessageKind, body: &str, log: Option<&Attachment>) -> ApplicationError {
+    let settings = mail_settings();
     let sender_name = format!("DTB Kampfrichtereinsatzpläne v{APP_VERSION}");
     let mut message = MessageBuilder::new()
-        .from((
-            sender_name.as_str(),
-            "dtb-kampfrichtereinsatzplaene@outlook.com",
-        ))
-        .to(("Philipp Remy", "philipp.remy@dtb.de"))
+        .from((sender_name.as_str(), settings.mail_from.as_str()))
+        .to(settings.mail_to.as_str())
         .subject(kind.generate_subject())
         .html_body(format!("{}{body}", kind.generate_mail_heading()));
+    if let Some((file_name, contents)) = log {
+        message = message.attachment("text/plain", file_name.as_str(), contents.as_slice());
+    }
 
-    // Append logs, if we have any.
-    // SAFETY: The static muts are only written to once (at the very beginning) and then act
-    // like a regular static, which is not unsafe.
-    // We don't expect race conditions because of the big time difference they would be accessed
-    // from different actors.
-    if send_logs {
-        unsafe {
-            if STDOUT_FILE.is_some() && STDERR_FILE.is_some() {
-                // Read the files into a buffer
-                let stdout_file = File::open(STDOUT_FILE.clone().unwrap());
-                let stderr_file = File::open(STDERR_FILE.clone().unwrap());
-
-                // If opening succeeded
-                if stdout_file.is_ok() && stderr_file.is_ok() {
-                    let mut stdout_buffer = vec![];
-                    let mut stderr_buffer = vec![];
-                    let stdout_result = stdout_file.unwrap().read_to_end(&mut stdout_buffer);
-                    let stderr_result = stderr_file.unwrap().read_to_end(&mut stderr_buffer);
-
-                    // If reading was successful
-                    if stdout_result.is_ok() && stderr_result.is_ok() {
-                        // Add data as an appendix
-                        message = message.attachment("text/plain", "STDOUT.txt", stdout_buffer);
-                        message = message.attachment("text/plain", "STDERR.txt", stderr_buffer);
-                    } else {
-                        if stdout_result.is_err() {
-                            eprintln!(
-                                "STDOUT_FILE could not be read to end: {:?}",
-                                stdout_result.unwrap_err()
-                            );
-                        }
-                        if stderr_result.is_err() {
-                            eprintln!(
-                                "STDERR_FILE could not be read to end: {:?}",
-                                stderr_result.unwrap_err()
-                            );
-                        }
-                    }
-                } else {
-                    if stdout_file.is_err() {
-                        eprintln!(
-                            "STDOUT_FILE could not be opened: {:?}",
-                            stdout_file.unwrap_err()
-                        );
-                    }
-                    if stderr_file.is_err() {
-                        eprintln!(
-                            "STDERR_FILE could not be opened: {:?}",
-                            stderr_file.unwrap_err()
-                        );
-                    }
-                }
-            } else {
-                eprintln!("STDOUT_FILE and STDERR_FILE are 'None': Either we are running a development build or the piping process was not successful.");
-            }
+    if settings.mail_transport == MailTransport::File {
+        return write_mail_file(&settings, message);
+    }
+
+    // Create the Client
+    let mut client_builder = SmtpClientBuilder::new(settings.smtp_host.as_str(), settings.smtp_port)
+        .implicit_tls(settings.smtp_tls == SmtpTls::Implicit)
+        .timeout(SMTP_TIMEOUT);
+    match settings.smtp_auth {
+        // The environment may point at another server, which must never see the credentials of the app
+        SmtpAuth::BuiltIn if !settings.can_use_built_in_account() => {
+            log::warn!("The built-in mail account cannot be used with {}, connecting without authentication", settings.smtp_host);
         }
+        SmtpAuth::BuiltIn => match (option_env!("MAIL_ADDRESS"), option_env!("MAIL_PASSWORD")) {
+            (Some(address), Some(password)) => client_builder = client_builder.credentials((address, password)),
+            _ => log::warn!("This build has no mail account, connecting without authentication"),
+        },
+        SmtpAuth::Login => client_builder = client_builder.credentials((settings.smtp_username.as_str(), settings.smtp_password.as_str())),
+        SmtpAuth::None => {}
     }
-    match client.send(message).await {
+
+    // Without TLS, the client has a different stream type, so both are sent right where they are connected
+    let result = match settings.smtp_tls {
+        SmtpTls::None => match client_builder.connect_plain().await {
+            Ok(mut client) => client.send(message).await,
+            Err(err) => {
+                log::error!("Failed to connect to the SMTP Server {}:{}: {:?}", settings.smtp_host, settings.smtp_port, err);
+                return ApplicationError::SMTPConnectionError;
+            }
+        },
+        SmtpTls::StartTls | SmtpTls::Implicit => match client_builder.connect().await {
+            Ok(mut client) => client.send(message).await,
+            Err(err) => {
+                log::error!("Failed to connect to the SMTP Server {}:{}: {:?}", settings.smtp_host, settings.smtp_port, err);
+                return ApplicationError::SMTPConnectionError;
+            }
+        },
+    };
+    match result {
         Ok(()) => {}
         Err(err) => {
-            eprintln!("Failed to send Message: {:?}", err);
+            log::error!("Failed to send Message: {:?}", err);
             return ApplicationError::MessageSendError;
         }
     }
 
     return ApplicationError::NoError;
 }
+
+/// Writes a mail as .eml file instead of sending it, see MailTransport::File.
+fn write_mail_file(settings: &Settings, message: MessageBuilder) -> ApplicationError {
+    let dir = match settings.mail_dir.clone().or_else(|| application_data_dir().map(|dir| dir.join("Mails"))) {
+        Some(dir) => dir,
+        None => {
+            log::error!("Could not get the Base Dirs for the Mails folder");
+            return ApplicationError::MessageSendError;
+        }
+    };
+    let path = dir.join(format!(
+        "{}-{}-{}.eml",
+        Local::now().format("%Y%m%d%H%M%S"),
+        std::process::id(),
+        NEXT_MAIL_FILE_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+    let written = message
+        .write_to_vec()
+        .and_then(|contents| std::fs::create_dir_all(&dir).and_then(|()| std::fs::write(&path, contents)));
+    return match written {
+        Ok(()) => {
+            log::info!("Wrote the mail to {}", path.display());
+            ApplicationError::NoError
+        }
+        Err(err) => {
+            log::error!("Could not write the mail to {}: {:?}", path.display(), err);
+            ApplicationError::MessageSendError
+        }
+    };
+}
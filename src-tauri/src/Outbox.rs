@@ -0,0 +1,474 @@
+use crate::types::ApplicationError;
+use crate::Errors::DetailedError;
+use crate::MailImpl::{send_mail, Attachment, MessageKind};
+use crate::Resources::application_data_dir;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+use tokio::sync::{Mutex, MutexGuard};
+
+// Mails to the developers are written to the Outbox folder before they are sent, and removed once they are.
+// The ones that could not be sent, e.g. in a hall without internet, are retried with growing intervals
+// while the app runs and at later launches, until they are sent or the user cancels them. Only messages the server
+// refused too often are left for the user to send again, see OUTBOX_MAX_REJECTIONS.
+
+/// The first retry of a message waits this long, every further one twice as long as the one before.
+pub const OUTBOX_RETRY_BASE: Duration = Duration::from_secs(60);
+/// The longest wait between two retries of a message.
+pub const OUTBOX_RETRY_MAX: Duration = Duration::from_secs(6 * 60 * 60);
+/// How often the app checks for messages that are due to be retried.
+pub const OUTBOX_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+/// After the server refused a message this often, it is only sent again when the user asks for it, as e.g. a rejected
+/// login or recipient does not go away by waiting. Attempts without a connection do not count.
+pub const OUTBOX_MAX_REJECTIONS: u32 = 5;
+
+static NEXT_MESSAGE_ID: AtomicU32 = AtomicU32::new(0);
+/// Held while sending, so a message is never sent twice by the retries and a send of the frontend at the same time.
+static SENDING: Mutex<()> = Mutex::const_new(());
+
+/// A message in the outbox. The attached log is copied next to it, as the log of the session may be gone by the retry.
+#[derive(Debug, Serialize, Deserialize)]
+struct OutboxFile {
+    kind: MessageKind,
+    body: String,
+    /// The name of the attached log, its contents are in the .log file of the message.
+    log_file_name: Option<String>,
+    created: String,
+    attempts: u32,
+    next_attempt: String,
+    last_error: Option<ApplicationError>,
+    /// The attempts the server refused, see OUTBOX_MAX_REJECTIONS. Missing in the messages of earlier versions.
+    #[serde(default)]
+    rejections: u32,
+}
+
+/// A message that is not sent yet, as listed in the bug reporter.
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingMessage {
+    pub id: String,
+    /// e.g. Bug or Feedback
+    pub kind: String,
+    pub subject: String,
+    /// Formatted for display, e.g. 18.10.2026 14:05
+    pub created: String,
+    pub attempts: u32,
+    /// Formatted for display, e.g. 18.10.2026 14:06
+    pub next_attempt: String,
+    /// Why the last attempt failed, for the user.
+    pub last_error: Option<String>,
+    /// Whether it is no longer retried on its own, see OUTBOX_MAX_REJECTIONS.
+    pub given_up: bool,
+}
+
+/// What happened to a message handed to the outbox.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum MailDelivery {
+    Sent,
+    /// It could not be sent right away and is retried later.
+    Queued,
+}
+
+/// Returns: The Outbox folder next to Logs and Autosave, None if the Base Dirs could not be determined.
+pub fn outbox_dir() -> Option<PathBuf> {
+    return application_data_dir().map(|dir| dir.join("Outbox"));
+}
+
+fn message_path(dir: &Path, id: &str) -> PathBuf {
+    return dir.join(format!("{id}.json"));
+}
+
+fn log_path(dir: &Path, id: &str) -> PathBuf {
+    return dir.join(format!("{id}.log"));
+}
+
+/// Returns: How long to wait after the given number of failed attempts.
+fn retry_delay(attempts: u32) -> Duration {
+    let factor = 2u32.saturating_pow(attempts.saturating_sub(1));
+    return OUTBOX_RETRY_BASE.saturating_mul(factor).min(OUTBOX_RETRY_MAX);
+}
+
+fn format_timestamp(timestamp: &str) -> String {
+    return match DateTime::parse_from_rfc3339(timestamp) {
+        Ok(timestamp) => timestamp.with_timezone(&Local).format("%d.%m.%Y %H:%M").to_string(),
+        Err(_) => timestamp.to_string(),
+    };
+}
+
+/// Returns: Whether the message is no longer retried on its own, see OUTBOX_MAX_REJECTIONS.
+fn is_given_up(message: &OutboxFile) -> bool {
+    return message.rejections >= OUTBOX_MAX_REJECTIONS;
+}
+
+fn is_due(message: &OutboxFile) -> bool {
+    return match DateTime::parse_from_rfc3339(&message.next_attempt) {
+        Ok(next_attempt) => next_attempt <= Local::now(),
+        Err(_) => true,
+    };
+}
+
+/// Writes a message next to the old one first and then moves it over it, so a crash never leaves half a message.
+fn write_message(dir: &Path, id: &str, message: &OutboxFile) -> Result<(), String> {
+    let contents = match serde_json::to_string(message) {
+        Ok(contents) => contents,
+        Err(err) => return Err(format!("Could not serialize the message: {:?}", err)),
+    };
+    let path = message_path(dir, id);
+    let temporary_path = path.with_extension("json.tmp");
+    match std::fs::write(&temporary_path, contents).and_then(|()| std::fs::rename(&temporary_path, &path)) {
+        Ok(()) => return Ok(()),
+        Err(err) => return Err(format!("Could not write the message {}: {:?}", path.display(), err)),
+    }
+}
+
+fn read_message(dir: &Path, id: &str) -> Option<OutboxFile> {
+    let contents = std::fs::read_to_string(message_path(dir, id)).ok()?;
+    return match serde_json::from_str(&contents) {
+        Ok(message) => Some(message),
+        Err(err) => {
+            log::warn!("Skipping the unreadable message {id} in the outbox: {:?}", err);
+            None
+        }
+    };
+}
+
+/// Returns: The ids of the messages in the outbox, the oldest first.
+fn message_ids(dir: &Path) -> Vec<String> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        // There is no outbox until the first message
+        Err(_) => return Vec::new(),
+    };
+    let mut ids: Vec<String> = entries
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_string_lossy().strip_suffix(".json").map(|id| id.to_string()))
+        .collect();
+    // The ids start with the time they were created at
+    ids.sort();
+    return ids;
+}
+
+fn remove_message(dir: &Path, id: &str) -> std::io::Result<()> {
+    match std::fs::remove_file(log_path(dir, id)) {
+        Ok(()) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err),
+    }
+    return std::fs::remove_file(message_path(dir, id));
+}
+
+/// Puts a message into the outbox, without sending it.
+/// Param 1: The kind of the message
+/// Param 2: The HTML body
+/// Param 3: The log to attach, see current_log_attachment
+/// Returns: The id of the message
+pub fn queue_mail(kind: MessageKind, body: String, log: Option<Attachment>) -> Result<String, DetailedError> {
+    let dir = match outbox_dir() {
+        Some(dir) => dir,
+        None => return Err(DetailedError::new(ApplicationError::OutboxWriteError).caused_by_text("Could not get the Base Dirs for the Outbox folder")),
+    };
+    match std::fs::create_dir_all(&dir) {
+        Ok(()) => {}
+        Err(err) => return Err(DetailedError::new(ApplicationError::OutboxWriteError).with_path(&dir).caused_by(&err)),
+    }
+
+    let now = Local::now();
+    let id = format!("{}-{}-{}", now.format("%Y%m%d%H%M%S"), std::process::id(), NEXT_MESSAGE_ID.fetch_add(1, Ordering::Relaxed));
+    let log_file_name = match log {
+        Some((file_name, contents)) => match std::fs::write(log_path(&dir, &id), contents) {
+            Ok(()) => Some(file_name),
+            Err(err) => return Err(DetailedError::new(ApplicationError::OutboxWriteError).with_path(log_path(&dir, &id)).caused_by(&err)),
+        },
+        None => None,
+    };
+    let message = OutboxFile {
+        kind,
+        body,
+        log_file_name,
+        created: now.to_rfc3339(),
+        attempts: 0,
+        next_attempt: now.to_rfc3339(),
+        last_error: None,
+        rejections: 0,
+    };
+    return match write_message(&dir, &id, &message) {
+        Ok(()) => Ok(id),
+        Err(err) => Err(DetailedError::new(ApplicationError::OutboxWriteError).caused_by_text(err)),
+    };
+}
+
+/// Tries to send a message of the outbox once. Removes it when it was sent, otherwise schedules the next attempt.
+/// Must only be called while holding SENDING.
+async fn deliver(dir: &Path, id: &str, _sending: &MutexGuard<'_, ()>) -> ApplicationError {
+    // Sent or cancelled in the meantime
+    let mut message = match read_message(dir, id) {
+        Some(message) => message,
+        None => return ApplicationError::NoError,
+    };
+    let log = match &message.log_file_name {
+        Some(file_name) => match std::fs::read(log_path(dir, id)) {
+            Ok(contents) => Some((file_name.clone(), contents)),
+            Err(err) => {
+                log::warn!("Sending the message {id} without its log: {:?}", err);
+                None
+            }
+        },
+        None => None,
+    };
+
+    let result = send_mail(&message.kind, &message.body, log.as_ref()).await;
+    if result == ApplicationError::NoError {
+        match remove_message(dir, id) {
+            Ok(()) => {}
+            Err(err) => log::error!("The message {id} was sent but could not be removed from the outbox: {:?}", err),
+        }
+        return result;
+    }
+
+    record_failure(&mut message, result);
+    if is_given_up(&message) {
+        log::error!("The server refused the message {id} {} times, it is only sent again when the user asks for it", message.rejections);
+    } else {
+        log::warn!("Could not send the message {id} (attempt {}), retrying at {}", message.attempts, format_timestamp(&message.next_attempt));
+    }
+    // Cancelled while it was being sent
+    if !message_path(dir, id).is_file() {
+        return result;
+    }
+    match write_message(dir, id, &message) {
+        Ok(()) => {}
+        Err(err) => log::error!("{err}"),
+    }
+    return result;
+}
+
+/// Counts a failed attempt to send a message and schedules the next one.
+fn record_failure(message: &mut OutboxFile, error: ApplicationError) {
+    message.attempts += 1;
+    // Without a connection the server never saw the message, so it cannot have refused it
+    if error != ApplicationError::SMTPConnectionError {
+        message.rejections += 1;
+    }
+    message.last_error = Some(error);
+    message.next_attempt = (Local::now() + retry_delay(message.attempts)).to_rfc3339();
+}
+
+/// Hands a message to the outbox and tries to send it right away.
+/// If it cannot even be put into the outbox, it is sent once without it.
+/// Returns: Whether it was sent or is retried later, an error only if it is lost
+pub async fn send_mail_via_outbox(kind: MessageKind, body: String, log: Option<Attachment>) -> Result<MailDelivery, DetailedError> {
+    let id = match queue_mail(kind.clone(), body.clone(), log.clone()) {
+        Ok(id) => id,
+        Err(err) => {
+            log::error!("Sending without the outbox: {err}");
+            return match send_mail(&kind, &body, log.as_ref()).await {
+                ApplicationError::NoError => Ok(MailDelivery::Sent),
+                code => Err(DetailedError::new(code).caused_by_text(err.to_string())),
+            };
+        }
+    };
+    let dir = outbox_dir().unwrap_or_default();
+    let sending = SENDING.lock().await;
+    return match deliver(&dir, &id, &sending).await {
+        ApplicationError::NoError => Ok(MailDelivery::Sent),
+        _ => Ok(MailDelivery::Queued),
+    };
+}
+
+/// Puts a message into the outbox and tries to send it once, unless another message is being sent.
+/// Meant for the panic hook: the panicking thread might hold SENDING, so waiting for it could hang forever.
+pub async fn send_mail_unless_busy(kind: MessageKind, body: String, log: Option<Attachment>) {
+    let id = match queue_mail(kind.clone(), body.clone(), log.clone()) {
+        Ok(id) => id,
+        Err(err) => {
+            log::error!("Sending without the outbox: {err}");
+            send_mail(&kind, &body, log.as_ref()).await;
+            return;
+        }
+    };
+    match SENDING.try_lock() {
+        Ok(sending) => {
+            deliver(&outbox_dir().unwrap_or_default(), &id, &sending).await;
+        }
+        Err(_) => log::warn!("Another message is being sent, the message {id} stays in the outbox for the next launch"),
+    }
+}
+
+/// Tries to send the messages of the outbox, the oldest first.
+/// Once one of them was sent, the connection is back, so the others are sent as well even if they are not due yet.
+/// Messages the server refused too often are skipped, see OUTBOX_MAX_REJECTIONS.
+/// Param 1: Whether to send all messages now, including the refused ones, e.g. when the user asks for it
+/// Returns: Whether any message was sent
+pub async fn send_pending_messages(all: bool) -> bool {
+    let dir = match outbox_dir() {
+        Some(dir) => dir,
+        None => return false,
+    };
+    let sending = SENDING.lock().await;
+    let mut connected = all;
+    let mut sent_any = false;
+    for id in message_ids(&dir) {
+        let due = match read_message(&dir, &id) {
+            Some(message) if is_given_up(&message) => all,
+            Some(message) => connected || is_due(&message),
+            None => false,
+        };
+        if !due {
+            continue;
+        }
+        match deliver(&dir, &id, &sending).await {
+            ApplicationError::NoError => {
+                connected = true;
+                sent_any = true;
+            }
+            // Without a connection, the other messages would fail just the same
+            ApplicationError::SMTPConnectionError => break,
+            _ => {}
+        }
+    }
+    return sent_any;
+}
+
+/// Returns: The messages that are not sent yet, the oldest first.
+pub fn list_pending_messages() -> Vec<PendingMessage> {
+    let dir = match outbox_dir() {
+        Some(dir) => dir,
+        None => return Vec::new(),
+    };
+    return message_ids(&dir)
+        .into_iter()
+        .filter_map(|id| {
+            let message = read_message(&dir, &id)?;
+            return Some(PendingMessage {
+                kind: message.kind.label().to_string(),
+                subject: message.kind.generate_subject(),
+                created: format_timestamp(&message.created),
+                attempts: message.attempts,
+                next_attempt: format_timestamp(&message.next_attempt),
+                given_up: is_given_up(&message),
+                last_error: message.last_error.map(|error| error.message().to_string()),
+                id,
+            });
+        })
+        .collect();
+}
+
+/// Removes a message from the outbox, so it is never sent. Messages that were sent in the meantime are ignored.
+/// Waits for a message being sent, as its next attempt would otherwise be written back after the removal.
+pub async fn cancel_message(id: &str) -> Result<(), DetailedError> {
+    return match outbox_dir() {
+        Some(dir) => cancel_message_in(&dir, id).await,
+        None => Ok(()),
+    };
+}
+
+/// cancel_message for the outbox in the given folder.
+async fn cancel_message_in(dir: &Path, id: &str) -> Result<(), DetailedError> {
+    let _sending = SENDING.lock().await;
+    // Only ids of the listing, so the frontend cannot delete anything else
+    if !message_ids(dir).iter().any(|other| other == id) {
+        return Ok(());
+    }
+    return match remove_message(dir, id) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(DetailedError::new(ApplicationError::OutboxWriteError).with_path(message_path(dir, id)).caused_by(&err)),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(next_attempt: String) -> OutboxFile {
+        return OutboxFile {
+            kind: MessageKind::Bug("Absturz beim Speichern".to_string()),
+            body: String::new(),
+            log_file_name: None,
+            created: Local::now().to_rfc3339(),
+            attempts: 1,
+            next_attempt,
+            last_error: None,
+            rejections: 0,
+        };
+    }
+
+    #[test]
+    fn the_retry_delay_doubles_up_to_the_maximum() {
+        assert_eq!(retry_delay(0), OUTBOX_RETRY_BASE);
+        assert_eq!(retry_delay(1), OUTBOX_RETRY_BASE);
+        assert_eq!(retry_delay(2), OUTBOX_RETRY_BASE * 2);
+        assert_eq!(retry_delay(3), OUTBOX_RETRY_BASE * 4);
+        assert_eq!(retry_delay(9), OUTBOX_RETRY_BASE * 256);
+        assert_eq!(retry_delay(10), OUTBOX_RETRY_MAX);
+        assert_eq!(retry_delay(u32::MAX), OUTBOX_RETRY_MAX);
+    }
+
+    #[test]
+    fn the_retry_delay_never_shrinks() {
+        for attempts in 1..100 {
+            assert!(retry_delay(attempts) >= retry_delay(attempts - 1));
+        }
+    }
+
+    #[test]
+    fn a_message_is_due_once_its_time_has_come() {
+        let minute = chrono::Duration::minutes(1);
+        assert!(is_due(&message((Local::now() - minute).to_rfc3339())));
+        assert!(!is_due(&message((Local::now() + minute).to_rfc3339())));
+        // A broken timestamp must not keep the message in the outbox forever
+        assert!(is_due(&message("morgen".to_string())));
+    }
+
+    #[test]
+    fn only_refusals_of_the_server_count_towards_giving_up() {
+        let mut message = message(Local::now().to_rfc3339());
+        for _ in 0..10 {
+            record_failure(&mut message, ApplicationError::SMTPConnectionError);
+        }
+        assert_eq!(message.attempts, 11);
+        assert_eq!(message.rejections, 0);
+        assert!(!is_given_up(&message));
+        assert!(!is_due(&message));
+
+        for _ in 1..OUTBOX_MAX_REJECTIONS {
+            record_failure(&mut message, ApplicationError::MessageSendError);
+        }
+        assert!(!is_given_up(&message));
+        record_failure(&mut message, ApplicationError::MessageSendError);
+        assert!(is_given_up(&message));
+        assert_eq!(message.last_error, Some(ApplicationError::MessageSendError));
+    }
+
+    #[test]
+    fn messages_of_earlier_versions_were_never_refused() {
+        let contents = r#"{"kind":{"Bug":"Absturz"},"body":"","log_file_name":null,"created":"2026-10-18T14:05:00+02:00","attempts":12,"next_attempt":"2026-10-18T20:05:00+02:00","last_error":"MessageSendError"}"#;
+        let message: OutboxFile = serde_json::from_str(contents).unwrap();
+        assert_eq!(message.rejections, 0);
+        assert!(!is_given_up(&message));
+    }
+
+    #[tokio::test]
+    async fn cancelling_waits_for_the_message_being_sent() {
+        let dir = std::env::temp_dir().join(format!("kre-outbox-{}-cancel", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        write_message(&dir, "20261018140500-1-0", &message(Local::now().to_rfc3339())).unwrap();
+
+        let sending = SENDING.lock().await;
+        let cancel_dir = dir.clone();
+        let cancel = tokio::spawn(async move { cancel_message_in(&cancel_dir, "20261018140500-1-0").await });
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+        assert!(!cancel.is_finished());
+        assert!(message_path(&dir, "20261018140500-1-0").is_file());
+
+        drop(sending);
+        cancel.await.unwrap().unwrap();
+        assert!(message_ids(&dir).is_empty());
+        // Ids that are not in the outbox are ignored
+        cancel_message_in(&dir, "../settings").await.unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,943 @@
+use crate::types::{ApplicationError, Wettkampf};
+use crate::DocxImpl::generate_plan;
+use crate::DocxReader::{read_layout, Alignment, Block, DocumentLayout, HeaderImage, Paragraph, Table, TextRun, VerticalAlignment};
+use base64::Engine;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use pdf_writer::types::{CidFontType, FontFlags, SystemInfo, TextRenderingMode, UnicodeCmap};
+use pdf_writer::{Content, Filter, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::PathBuf;
+use svg2pdf::usvg::TreeParsing;
+
+const PT_PER_CM: f32 = 72.0 / 2.54;
+/// Single line spacing of Arial in Word, relative to the font size.
+const LINE_SPACING: f32 = 1.15;
+/// Distance of the baseline from the bottom of a line, relative to the font size.
+const DESCENT: f32 = 0.212;
+const HEADER_SPACING_CM: f32 = 0.5;
+const CELL_PADDING_CM: f32 = 0.19;
+const LIST_INDENT_CM: f32 = 1.27;
+const BULLET_INDENT_CM: f32 = 0.63;
+const TAB_STOP_CM: f32 = 1.25;
+const BORDER_WIDTH_PT: f32 = 0.5;
+/// WinAnsiEncoding of the bullet, which list items start with.
+const BULLET: u8 = 0x95;
+/// The fallback font has no oblique style, italic text is slanted like Helvetica-Oblique (12°) instead.
+const FALLBACK_SLANT: f32 = 0.2126;
+/// The fallback font has no bold style, bold text is also stroked with this width, relative to the font size.
+const FALLBACK_BOLD_STROKE: f32 = 0.03;
+const FALLBACK_FONT_NAME: &[u8] = b"F5";
+
+// DejaVu Sans, for the characters the standard fonts do not have, see FallbackFont
+#[cfg(not(target_os = "windows"))]
+static FALLBACK_FONT_BINARY: &[u8] = include_bytes!(r"../../res/DejaVuSans.ttf");
+#[cfg(target_os = "windows")]
+static FALLBACK_FONT_BINARY: &[u8] = include_bytes!(r"..\..\res\DejaVuSans.ttf");
+
+/// The standard fonts of every PDF viewer, so no font has to be embedded.
+/// Arial has the metrics of Helvetica. Indexed by bold + 2 * italic.
+static FONTS: [(&[u8], &[u8]); 4] = [
+    (b"F1", b"Helvetica"),
+    (b"F2", b"Helvetica-Bold"),
+    (b"F3", b"Helvetica-Oblique"),
+    (b"F4", b"Helvetica-BoldOblique"),
+];
+
+/// Glyph widths in 1/1000 of the font size, for the WinAnsiEncoding characters 32 to 255.
+/// The oblique fonts have the same widths.
+static HELVETICA_WIDTHS: [u16; 224] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584, 350,
+    556, 350, 222, 556, 333, 1000, 556, 556, 333, 1000, 667, 333, 1000, 350, 611, 350,
+    350, 222, 222, 333, 333, 350, 556, 1000, 333, 1000, 500, 333, 944, 350, 500, 667,
+    278, 333, 556, 556, 556, 556, 260, 556, 333, 737, 370, 556, 584, 333, 737, 333,
+    400, 584, 333, 333, 333, 556, 537, 278, 333, 333, 365, 556, 834, 834, 834, 611,
+    667, 667, 667, 667, 667, 667, 1000, 722, 667, 667, 667, 667, 278, 278, 278, 278,
+    722, 722, 778, 778, 778, 778, 778, 584, 778, 722, 722, 722, 722, 667, 667, 611,
+    556, 556, 556, 556, 556, 556, 889, 500, 556, 556, 556, 556, 278, 278, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 584, 611, 556, 556, 556, 556, 500, 556, 500,
+];
+static HELVETICA_BOLD_WIDTHS: [u16; 224] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611,
+    975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556,
+    333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611,
+    611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584, 350,
+    556, 350, 278, 556, 500, 1000, 556, 556, 333, 1000, 667, 333, 1000, 350, 611, 350,
+    350, 278, 278, 500, 500, 350, 556, 1000, 333, 1000, 556, 333, 944, 350, 500, 667,
+    278, 333, 556, 556, 556, 556, 280, 556, 333, 737, 370, 556, 584, 333, 737, 333,
+    400, 584, 333, 333, 333, 611, 556, 278, 333, 333, 365, 556, 834, 834, 834, 611,
+    722, 722, 722, 722, 722, 722, 1000, 722, 667, 667, 667, 667, 278, 278, 278, 278,
+    722, 722, 778, 778, 778, 778, 778, 584, 778, 722, 722, 722, 722, 667, 667, 611,
+    556, 556, 556, 556, 556, 556, 889, 556, 556, 556, 556, 556, 278, 278, 278, 278,
+    611, 611, 611, 611, 611, 611, 611, 584, 611, 611, 611, 611, 611, 556, 611, 556,
+];
+
+/// Encodes a character in WinAnsiEncoding, the encoding of the standard fonts.
+/// Returns: None for characters the standard fonts do not have, e.g. "Ł".
+fn encode(character: char) -> Option<u8> {
+    let byte = match character {
+        ' '..='~' | '\u{a0}'..='\u{ff}' => character as u8,
+        '€' => 0x80,
+        '‚' => 0x82,
+        'ƒ' => 0x83,
+        '„' => 0x84,
+        '…' => 0x85,
+        '†' => 0x86,
+        '‡' => 0x87,
+        'ˆ' => 0x88,
+        '‰' => 0x89,
+        'Š' => 0x8a,
+        '‹' => 0x8b,
+        'Œ' => 0x8c,
+        'Ž' => 0x8e,
+        '‘' => 0x91,
+        '’' => 0x92,
+        '“' => 0x93,
+        '”' => 0x94,
+        '•' => 0x95,
+        '–' => 0x96,
+        '—' => 0x97,
+        '˜' => 0x98,
+        '™' => 0x99,
+        'š' => 0x9a,
+        '›' => 0x9b,
+        'œ' => 0x9c,
+        'ž' => 0x9e,
+        'Ÿ' => 0x9f,
+        _ => return None,
+    };
+    return Some(byte);
+}
+
+/// A character, encoded for the font that draws it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Glyph {
+    /// A character of WinAnsiEncoding, drawn with a standard font.
+    WinAnsi(u8),
+    /// A glyph of the fallback font, with its width in 1/1000 of the font size.
+    Fallback { id: u16, width: u16 },
+}
+
+impl Glyph {
+    /// Returns: The bytes that select the glyph in its font. The fallback font takes two per glyph (Identity-H).
+    fn bytes(self) -> Vec<u8> {
+        return match self {
+            Glyph::WinAnsi(byte) => vec![byte],
+            Glyph::Fallback { id, .. } => id.to_be_bytes().to_vec(),
+        };
+    }
+}
+
+/// DejaVu Sans, for the characters the standard fonts do not have, e.g. the "Ł" of "Łukasz".
+/// Only the glyphs that are used are embedded, as a TrueType subset.
+struct FallbackFont {
+    face: Option<ttf_parser::Face<'static>>,
+    /// The glyphs used so far and the characters they stand for, so the text can be copied out of the PDF.
+    used: BTreeMap<u16, char>,
+}
+
+impl FallbackFont {
+    fn new() -> FallbackFont {
+        let face = match ttf_parser::Face::parse(FALLBACK_FONT_BINARY, 0) {
+            Ok(face) => Some(face),
+            Err(err) => {
+                log::error!("Could not read the fallback font: {err}");
+                None
+            }
+        };
+        return FallbackFont {
+            face,
+            used: BTreeMap::new(),
+        };
+    }
+
+    /// Returns: The glyph of the character, None if the fallback font does not have it either.
+    fn glyph(&mut self, character: char) -> Option<Glyph> {
+        let face = self.face.as_ref()?;
+        let id = face.glyph_index(character)?;
+        let advance = face.glyph_hor_advance(id).unwrap_or(0);
+        self.used.entry(id.0).or_insert(character);
+        return Some(Glyph::Fallback {
+            id: id.0,
+            width: self.scale(advance) as u16,
+        });
+    }
+
+    /// Converts font units into 1/1000 of the font size, the unit of the PDF font dictionaries.
+    fn scale(&self, units: impl Into<f32>) -> f32 {
+        let units_per_em = self.face.as_ref().map_or(1000, |face| face.units_per_em());
+        return units.into() * 1000.0 / units_per_em as f32;
+    }
+
+    /// Encodes a character for the standard fonts, or with the fallback font if they do not have it.
+    /// Characters that no font has become a question mark.
+    fn encode(&mut self, character: char) -> Glyph {
+        if let Some(byte) = encode(character) {
+            return Glyph::WinAnsi(byte);
+        }
+        return match self.glyph(character) {
+            Some(glyph) => glyph,
+            None => {
+                log::warn!("No font has the character {character:?} (U+{:04X}), it is replaced by a question mark", character as u32);
+                Glyph::WinAnsi(b'?')
+            }
+        };
+    }
+
+    /// Writes the font as Type0 font with the used glyphs into the PDF.
+    /// Param 2: The id of the Type0 font, the font file, its descriptor and so on take the ids after it.
+    /// Returns: The next unused object id.
+    fn write(&self, pdf: &mut Pdf, font_id: Ref) -> Ref {
+        let face = match &self.face {
+            Some(face) => face,
+            None => return font_id,
+        };
+        let cid_font_id = font_id.next();
+        let descriptor_id = cid_font_id.next();
+        let cmap_id = descriptor_id.next();
+        let file_id = cmap_id.next();
+
+        let glyphs: Vec<u16> = self.used.keys().copied().collect();
+        let base_font = format!("{}+DejaVuSans", subset_tag(&glyphs));
+        let system_info = SystemInfo {
+            registry: Str(b"Adobe"),
+            ordering: Str(b"Identity"),
+            supplement: 0,
+        };
+
+        pdf.type0_font(font_id)
+            .base_font(Name(base_font.as_bytes()))
+            .encoding_predefined(Name(b"Identity-H"))
+            .descendant_font(cid_font_id)
+            .to_unicode(cmap_id);
+
+        let mut cid_font = pdf.cid_font(cid_font_id);
+        cid_font
+            .subtype(CidFontType::Type2)
+            .base_font(Name(base_font.as_bytes()))
+            .system_info(system_info)
+            .font_descriptor(descriptor_id)
+            .cid_to_gid_map_predefined(Name(b"Identity"));
+        let mut widths = cid_font.widths();
+        for glyph in &glyphs {
+            let advance = face.glyph_hor_advance(ttf_parser::GlyphId(*glyph)).unwrap_or(0);
+            widths.consecutive(*glyph, [self.scale(advance)]);
+        }
+        widths.finish();
+        cid_font.finish();
+
+        let bbox = face.global_bounding_box();
+        // DejaVu Sans does not state it, the height of the "H" is the same
+        let cap_height = face
+            .capital_height()
+            .or_else(|| face.glyph_index('H').and_then(|id| face.glyph_bounding_box(id)).map(|bbox| bbox.y_max))
+            .unwrap_or(face.ascender());
+        pdf.font_descriptor(descriptor_id)
+            .name(Name(base_font.as_bytes()))
+            .flags(FontFlags::NON_SYMBOLIC)
+            .bbox(Rect::new(self.scale(bbox.x_min), self.scale(bbox.y_min), self.scale(bbox.x_max), self.scale(bbox.y_max)))
+            .italic_angle(0.0)
+            .ascent(self.scale(face.ascender()))
+            .descent(self.scale(face.descender()))
+            .cap_height(self.scale(cap_height))
+            .stem_v(80.0)
+            .font_file2(file_id);
+
+        let mut cmap = UnicodeCmap::new(Name(b"Custom"), system_info);
+        for (glyph, character) in &self.used {
+            cmap.pair(*glyph, *character);
+        }
+        pdf.cmap(cmap_id, &cmap.finish());
+
+        // The subsetter keeps the glyph ids, so the text stays valid. The whole font works as well, just larger
+        let subset = match subsetter::subset(FALLBACK_FONT_BINARY, 0, subsetter::Profile::pdf(&glyphs)) {
+            Ok(subset) => subset,
+            Err(err) => {
+                log::warn!("Embedding the whole fallback font, it could not be subset: {err}");
+                FALLBACK_FONT_BINARY.to_vec()
+            }
+        };
+        pdf.stream(file_id, &deflate(&subset)).filter(Filter::FlateDecode);
+        return file_id.next();
+    }
+}
+
+/// Returns: The tag that marks a font as subset, e.g. the "KXMQRA" of "KXMQRA+DejaVuSans".
+/// Derived from the glyphs, so the same plan always gives the same file.
+fn subset_tag(glyphs: &[u16]) -> String {
+    let hash = glyphs.iter().fold(2166136261u32, |hash, glyph| (hash ^ *glyph as u32).wrapping_mul(16777619));
+    return (0..6).map(|index| (b'A' + ((hash >> (index * 5)) % 26) as u8) as char).collect();
+}
+
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+    // Writing into a Vec cannot fail
+    encoder.write_all(data).unwrap();
+    return encoder.finish().unwrap();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Font {
+    index: usize,
+    /// Narrow fonts are drawn horizontally compressed.
+    horizontal_scale: f32,
+    /// Whether the text is drawn with the fallback font, in the style of the index.
+    fallback: bool,
+}
+
+impl Font {
+    fn of(run: &TextRun) -> Font {
+        let family = run.font.to_lowercase();
+        let bold = run.bold || family.contains("black");
+        return Font {
+            index: bold as usize + 2 * run.italic as usize,
+            horizontal_scale: if family.contains("narrow") { 0.82 } else { 1.0 },
+            fallback: false,
+        };
+    }
+
+    fn regular() -> Font {
+        return Font {
+            index: 0,
+            horizontal_scale: 1.0,
+            fallback: false,
+        };
+    }
+
+    /// Returns: The font in the same style, drawn with the fallback font.
+    fn with_fallback(self) -> Font {
+        return Font { fallback: true, ..self };
+    }
+
+    fn is_bold(&self) -> bool {
+        return self.index % 2 == 1;
+    }
+
+    fn is_italic(&self) -> bool {
+        return self.index >= 2;
+    }
+
+    fn name(&self) -> Name<'static> {
+        return if self.fallback { Name(FALLBACK_FONT_NAME) } else { Name(FONTS[self.index].0) };
+    }
+
+    fn width(&self, glyph: Glyph, size_pt: f32) -> f32 {
+        let units = match glyph {
+            Glyph::WinAnsi(byte) if byte < 32 => 0,
+            Glyph::WinAnsi(byte) if self.is_bold() => HELVETICA_BOLD_WIDTHS[(byte - 32) as usize],
+            Glyph::WinAnsi(byte) => HELVETICA_WIDTHS[(byte - 32) as usize],
+            Glyph::Fallback { width, .. } => width,
+        };
+        return units as f32 / 1000.0 * size_pt * self.horizontal_scale;
+    }
+}
+
+/// A piece of text with the same formatting.
+#[derive(Debug, Clone)]
+struct Fragment {
+    font: Font,
+    size_pt: f32,
+    underline: bool,
+    text: Vec<u8>,
+    /// Position relative to the start of the line.
+    x: f32,
+    width: f32,
+}
+
+/// A word including the spaces after it. Lines are only broken between words.
+#[derive(Debug, Default)]
+struct Word {
+    fragments: Vec<Fragment>,
+    width: f32,
+    trailing_space: f32,
+}
+
+impl Word {
+    fn push(&mut self, run: &TextRun, font: Font, glyph: Glyph) {
+        let width = font.width(glyph, run.size_pt);
+        match self.fragments.last_mut() {
+            Some(last) if last.font == font && last.size_pt == run.size_pt && last.underline == run.underline => {
+                last.text.extend(glyph.bytes());
+                last.width += width;
+            }
+            _ => self.fragments.push(Fragment {
+                font,
+                size_pt: run.size_pt,
+                underline: run.underline,
+                text: glyph.bytes(),
+                x: self.width,
+                width,
+            }),
+        }
+        self.width += width;
+        self.trailing_space = if glyph == Glyph::WinAnsi(b' ') { self.trailing_space + width } else { 0.0 };
+    }
+}
+
+enum Token {
+    Word(Word),
+    Tab,
+    LineBreak,
+}
+
+fn split_words(runs: &[TextRun], fallback: &mut FallbackFont) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut word = Word::default();
+    for run in runs {
+        let font = Font::of(run);
+        for character in run.text.chars() {
+            if character == '\t' || character == '\n' {
+                if !word.fragments.is_empty() {
+                    tokens.push(Token::Word(std::mem::take(&mut word)));
+                }
+                tokens.push(if character == '\t' { Token::Tab } else { Token::LineBreak });
+                continue;
+            }
+            let glyph = fallback.encode(character);
+            if glyph != Glyph::WinAnsi(b' ') && word.trailing_space > 0.0 {
+                tokens.push(Token::Word(std::mem::take(&mut word)));
+            }
+            let font = if matches!(glyph, Glyph::Fallback { .. }) { font.with_fallback() } else { font };
+            word.push(run, font, glyph);
+        }
+    }
+    if !word.fragments.is_empty() {
+        tokens.push(Token::Word(word));
+    }
+    return tokens;
+}
+
+#[derive(Debug)]
+struct Line {
+    fragments: Vec<Fragment>,
+    /// Width of the text without the trailing spaces.
+    width: f32,
+    size_pt: f32,
+}
+
+impl Line {
+    fn new(size_pt: f32) -> Line {
+        return Line {
+            fragments: Vec::new(),
+            width: 0.0,
+            size_pt,
+        };
+    }
+
+    fn height(&self) -> f32 {
+        return self.size_pt * LINE_SPACING;
+    }
+}
+
+/// Breaks a paragraph into lines that fit the given width.
+/// Words that are longer than a line are not broken and overflow.
+fn break_lines(paragraph: &Paragraph, max_width: f32, fallback: &mut FallbackFont) -> Vec<Line> {
+    let tab_stop = TAB_STOP_CM * PT_PER_CM;
+    let mut lines = Vec::new();
+    let mut line = Line::new(paragraph.size_pt);
+    let mut x = 0.0;
+
+    for token in split_words(&paragraph.runs, fallback) {
+        match token {
+            Token::LineBreak => {
+                lines.push(std::mem::replace(&mut line, Line::new(paragraph.size_pt)));
+                x = 0.0;
+            }
+            Token::Tab => {
+                x = ((x / tab_stop).floor() + 1.0) * tab_stop;
+                line.width = x;
+            }
+            Token::Word(word) => {
+                if x + word.width - word.trailing_space > max_width && !line.fragments.is_empty() {
+                    lines.push(std::mem::replace(&mut line, Line::new(paragraph.size_pt)));
+                    x = 0.0;
+                }
+                for mut fragment in word.fragments {
+                    line.size_pt = line.size_pt.max(fragment.size_pt);
+                    fragment.x += x;
+                    line.fragments.push(fragment);
+                }
+                x += word.width;
+                line.width = x - word.trailing_space;
+            }
+        }
+    }
+    lines.push(line);
+    return lines;
+}
+
+fn lines_height(lines: &[Line]) -> f32 {
+    return lines.iter().map(Line::height).sum();
+}
+
+/// A header picture, converted to a form XObject of 1x1 pt.
+struct PlacedImage {
+    name: String,
+    id: Ref,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
+/// Wraps a PNG into an SVG, so all pictures take the same way into the PDF.
+fn png_as_svg(png: &[u8]) -> Vec<u8> {
+    return format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" xmlns:xlink=\"http://www.w3.org/1999/xlink\" width=\"100\" height=\"100\" preserveAspectRatio=\"none\">\
+         <image width=\"100\" height=\"100\" preserveAspectRatio=\"none\" xlink:href=\"data:image/png;base64,{}\"/></svg>",
+        base64::engine::general_purpose::STANDARD.encode(png)
+    )
+    .into_bytes();
+}
+
+/// Writes the header pictures into the PDF.
+/// Param 4: The first unused object id.
+/// Returns: The pictures and the next unused object id.
+fn embed_header_images(pdf: &mut Pdf, layout: &DocumentLayout, page_height: f32, mut next_id: Ref) -> (Vec<PlacedImage>, Ref) {
+    let mut placed = Vec::new();
+    for image in &layout.header_images {
+        // Prefer the SVG, it stays sharp when printed
+        let svg = match (&image.svg, &image.png) {
+            (Some(svg), _) => svg.clone(),
+            (None, Some(png)) => png_as_svg(png),
+            (None, None) => continue,
+        };
+        let tree = match svg2pdf::usvg::Tree::from_data(&svg, &svg2pdf::usvg::Options::default()) {
+            Ok(tree) => tree,
+            Err(err) => {
+                log::warn!("Skipping a header picture that could not be read: {:?}", err);
+                continue;
+            }
+        };
+        let id = next_id;
+        next_id = svg2pdf::convert_tree_into(&tree, svg2pdf::Options::default(), pdf, id);
+        placed.push(PlacedImage {
+            name: format!("Im{}", placed.len() + 1),
+            id,
+            x: (layout.page.margin_left_cm + image.offset_x_cm) * PT_PER_CM,
+            y: page_height - (layout.page.margin_top_cm + image.height_cm) * PT_PER_CM,
+            width: image.width_cm * PT_PER_CM,
+            height: image.height_cm * PT_PER_CM,
+        });
+    }
+    return (placed, next_id);
+}
+
+fn header_height(images: &[HeaderImage]) -> f32 {
+    return images.iter().map(|image| image.height_cm).fold(0.0, f32::max);
+}
+
+/// Places the blocks of the plan on pages.
+/// Positions are measured in points from the top of the page.
+struct PageWriter<'a> {
+    header: &'a [PlacedImage],
+    page_height: f32,
+    body_top: f32,
+    body_bottom: f32,
+    left: f32,
+    width: f32,
+    pages: Vec<Content>,
+    cursor: f32,
+    break_pending: bool,
+    fallback: FallbackFont,
+}
+
+impl<'a> PageWriter<'a> {
+    fn new(layout: &DocumentLayout, header: &'a [PlacedImage]) -> PageWriter<'a> {
+        let page = &layout.page;
+        let mut body_top = page.margin_top_cm;
+        if !layout.header_images.is_empty() {
+            body_top += header_height(&layout.header_images) + HEADER_SPACING_CM;
+        }
+        return PageWriter {
+            header,
+            page_height: page.height_cm * PT_PER_CM,
+            body_top: body_top * PT_PER_CM,
+            body_bottom: (page.height_cm - page.margin_bottom_cm) * PT_PER_CM,
+            left: page.margin_left_cm * PT_PER_CM,
+            width: (page.width_cm - page.margin_left_cm - page.margin_right_cm) * PT_PER_CM,
+            pages: Vec::new(),
+            cursor: 0.0,
+            break_pending: false,
+            fallback: FallbackFont::new(),
+        };
+    }
+
+    fn new_page(&mut self) {
+        let mut content = Content::new();
+        for image in self.header {
+            content
+                .save_state()
+                .transform([image.width, 0.0, 0.0, image.height, image.x, image.y])
+                .x_object(Name(image.name.as_bytes()))
+                .restore_state();
+        }
+        self.pages.push(content);
+        self.cursor = self.body_top;
+        self.break_pending = false;
+    }
+
+    /// Reserves space on the current page, or on a new one if it is full.
+    /// Returns: The top of the reserved space.
+    fn reserve(&mut self, height: f32) -> f32 {
+        let page_is_full = self.cursor + height > self.body_bottom && self.cursor > self.body_top;
+        if self.pages.is_empty() || self.break_pending || page_is_full {
+            self.new_page();
+        }
+        let top = self.cursor;
+        self.cursor += height;
+        return top;
+    }
+
+    fn content(&mut self) -> &mut Content {
+        return self.pages.last_mut().unwrap();
+    }
+
+    fn draw_line(&mut self, line: &Line, alignment: Alignment, x: f32, top: f32, width: f32) {
+        let offset = match alignment {
+            Alignment::Left | Alignment::Justify => 0.0,
+            Alignment::Center => (width - line.width) / 2.0,
+            Alignment::Right => width - line.width,
+        };
+        let baseline = self.page_height - top - line.height() + line.size_pt * DESCENT;
+        let content = self.content();
+        for fragment in &line.fragments {
+            let fragment_x = x + offset + fragment.x;
+            let font = fragment.font;
+            if font.fallback {
+                content.save_state();
+                if font.is_bold() {
+                    content
+                        .set_text_rendering_mode(TextRenderingMode::FillStroke)
+                        .set_line_width(fragment.size_pt * FALLBACK_BOLD_STROKE);
+                }
+            }
+            content
+                .begin_text()
+                .set_font(font.name(), fragment.size_pt)
+                .set_horizontal_scaling(font.horizontal_scale * 100.0);
+            if font.fallback && font.is_italic() {
+                content.set_text_matrix([1.0, 0.0, FALLBACK_SLANT, 1.0, fragment_x, baseline]);
+            } else {
+                content.next_line(fragment_x, baseline);
+            }
+            content.show(Str(&fragment.text)).end_text();
+            if font.fallback {
+                content.restore_state();
+            }
+            if fragment.underline {
+                let y = baseline - fragment.size_pt * 0.1;
+                content
+                    .set_line_width(fragment.size_pt * 0.05)
+                    .move_to(fragment_x, y)
+                    .line_to(fragment_x + fragment.width, y)
+                    .stroke();
+            }
+        }
+    }
+
+    fn draw_border(&mut self, from: (f32, f32), to: (f32, f32)) {
+        let page_height = self.page_height;
+        self.content()
+            .set_line_width(BORDER_WIDTH_PT)
+            .move_to(from.0, page_height - from.1)
+            .line_to(to.0, page_height - to.1)
+            .stroke();
+    }
+
+    fn write_paragraph(&mut self, paragraph: &Paragraph) {
+        let (indent, bullet) = if paragraph.list_item {
+            (LIST_INDENT_CM * PT_PER_CM, Some(BULLET_INDENT_CM * PT_PER_CM))
+        } else {
+            (0.0, None)
+        };
+        let x = self.left + indent;
+        let width = self.width - indent;
+        let lines = if paragraph.page_break && paragraph.runs.is_empty() {
+            Vec::new()
+        } else {
+            break_lines(paragraph, width, &mut self.fallback)
+        };
+
+        for (index, line) in lines.iter().enumerate() {
+            let top = self.reserve(line.height());
+            if let (0, Some(bullet_x)) = (index, bullet) {
+                let font = Font::regular();
+                let bullet_line = Line {
+                    fragments: vec![Fragment {
+                        font,
+                        size_pt: paragraph.size_pt,
+                        underline: false,
+                        text: vec![BULLET],
+                        x: 0.0,
+                        width: font.width(Glyph::WinAnsi(BULLET), paragraph.size_pt),
+                    }],
+                    width: 0.0,
+                    size_pt: line.size_pt,
+                };
+                self.draw_line(&bullet_line, Alignment::Left, self.left + bullet_x, top, width);
+            }
+            self.draw_line(line, paragraph.alignment, x, top, width);
+        }
+
+        if paragraph.page_break {
+            self.break_pending = true;
+        }
+    }
+
+    /// Rows are kept together, a table may continue on the next page between rows.
+    fn write_table(&mut self, table: &Table) {
+        let padding = CELL_PADDING_CM * PT_PER_CM;
+        // Word moves tables to the left by the cell padding, so the text lines up with the margin
+        let table_left = self.left - padding;
+
+        for row in &table.rows {
+            let mut cells = Vec::new();
+            let mut x = table_left;
+            for cell in &row.cells {
+                let width = cell.width_cm * PT_PER_CM;
+                let paragraphs: Vec<(Alignment, Vec<Line>)> = cell
+                    .paragraphs
+                    .iter()
+                    .map(|paragraph| (paragraph.alignment, break_lines(paragraph, width - 2.0 * padding, &mut self.fallback)))
+                    .collect();
+                let content_height: f32 = paragraphs.iter().map(|(_, lines)| lines_height(lines)).sum();
+                cells.push((cell, x, width, paragraphs, content_height));
+                x += width;
+            }
+
+            let height = cells
+                .iter()
+                .map(|(_, _, _, _, content_height)| *content_height)
+                .fold(row.min_height_cm * PT_PER_CM, f32::max);
+            let top = self.reserve(height);
+
+            for (cell, x, width, paragraphs, content_height) in cells {
+                let mut line_top = top
+                    + match cell.vertical_alignment {
+                        VerticalAlignment::Top => 0.0,
+                        VerticalAlignment::Center => (height - content_height) / 2.0,
+                        VerticalAlignment::Bottom => height - content_height,
+                    };
+                for (alignment, lines) in &paragraphs {
+                    for line in lines {
+                        self.draw_line(line, *alignment, x + padding, line_top, width - 2.0 * padding);
+                        line_top += line.height();
+                    }
+                }
+
+                let (right, bottom) = (x + width, top + height);
+                if cell.borders.top {
+                    self.draw_border((x, top), (right, top));
+                }
+                if cell.borders.right {
+                    self.draw_border((right, top), (right, bottom));
+                }
+                if cell.borders.bottom {
+                    self.draw_border((x, bottom), (right, bottom));
+                }
+                if cell.borders.left {
+                    self.draw_border((x, top), (x, bottom));
+                }
+            }
+        }
+    }
+}
+
+/// Lays out the plan on pages and writes it as PDF.
+/// The output only depends on the layout, so the same plan always gives the same file.
+/// Param 1: The layout of the generated plan.
+/// Returns: The bytes of the PDF file.
+pub fn render_pdf(layout: &DocumentLayout) -> Vec<u8> {
+    let page_width = layout.page.width_cm * PT_PER_CM;
+    let page_height = layout.page.height_cm * PT_PER_CM;
+
+    let mut pdf = Pdf::new();
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let info_id = Ref::new(3);
+    let font_ids: Vec<Ref> = (0..FONTS.len() as i32).map(|index| Ref::new(4 + index)).collect();
+    let next_id = Ref::new(4 + FONTS.len() as i32);
+
+    let (header, mut next_id) = embed_header_images(&mut pdf, layout, page_height, next_id);
+
+    let mut writer = PageWriter::new(layout, &header);
+    for block in &layout.blocks {
+        match block {
+            Block::Paragraph(paragraph) => writer.write_paragraph(paragraph),
+            Block::Table(table) => writer.write_table(table),
+        }
+    }
+    if writer.pages.is_empty() {
+        writer.new_page();
+    }
+
+    // Only embedded if some text needs it
+    let fallback_font_id = if writer.fallback.used.is_empty() {
+        None
+    } else {
+        let id = next_id;
+        next_id = writer.fallback.write(&mut pdf, id);
+        Some(id)
+    };
+
+    let mut page_ids = Vec::new();
+    for content in writer.pages {
+        let page_id = next_id;
+        let content_id = page_id.next();
+        next_id = content_id.next();
+        page_ids.push(page_id);
+
+        let mut page = pdf.page(page_id);
+        page.media_box(Rect::new(0.0, 0.0, page_width, page_height));
+        page.parent(page_tree_id);
+        page.contents(content_id);
+        let mut resources = page.resources();
+        let mut fonts = resources.fonts();
+        for ((name, _), id) in FONTS.iter().zip(&font_ids) {
+            fonts.pair(Name(name), *id);
+        }
+        if let Some(id) = fallback_font_id {
+            fonts.pair(Name(FALLBACK_FONT_NAME), id);
+        }
+        fonts.finish();
+        let mut x_objects = resources.x_objects();
+        for image in &header {
+            x_objects.pair(Name(image.name.as_bytes()), image.id);
+        }
+        x_objects.finish();
+        resources.finish();
+        page.finish();
+
+        pdf.stream(content_id, &content.finish());
+    }
+
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id).count(page_ids.len() as i32).kids(page_ids);
+    for ((_, base_font), id) in FONTS.iter().zip(&font_ids) {
+        pdf.type1_font(*id)
+            .base_font(Name(base_font))
+            .encoding_predefined(Name(b"WinAnsiEncoding"));
+    }
+    pdf.document_info(info_id)
+        .title(TextStr("Kampfrichtereinsatzplan"))
+        .producer(TextStr("DTB Kampfrichtereinsatzpläne"));
+
+    return pdf.finish();
+}
+
+/// To be called by the main application, saves the plans as PDF to the hard drive.
+/// Param 1: The competition.
+/// Param 2: A PathBuf containing the path where the PDF should be saved to.
+/// Returns: An ApplicationError which gives more information (NoError == Success!).
+pub fn create_tables_pdf(wettkampf: &Wettkampf, save_path: PathBuf) -> ApplicationError {
+    if save_path.as_os_str().is_empty() {
+        return ApplicationError::PdfSavePathIsEmpty;
+    }
+
+    let mut package = match generate_plan(wettkampf) {
+        Ok(package) => package,
+        Err(err) => return err,
+    };
+    let layout = match read_layout(&mut package) {
+        Ok(layout) => layout,
+        Err(err) => {
+            log::error!("Failed to lay out the plan: {err}");
+            return ApplicationError::PdfGenerationError;
+        }
+    };
+
+    return match std::fs::write(&save_path, render_pdf(&layout)) {
+        Ok(()) => ApplicationError::NoError,
+        Err(err) => {
+            log::error!("Could not write {}: {:?}", save_path.display(), err);
+            ApplicationError::RustWriteFileError
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Kampfgericht, Kampfrichter};
+    use crate::Disciplines::{Discipline, JudgeRole};
+    use std::collections::HashMap;
+
+    fn wettkampf(judge_name: &str) -> Wettkampf {
+        let judge = Kampfrichter {
+            role: JudgeRole::Ok,
+            name: judge_name.to_string(),
+            doubleFound: false,
+        };
+        let table = Kampfgericht {
+            uniqueID: "1".to_string(),
+            table_name: "KG Sprung".to_string(),
+            table_kind: Discipline::Sprung,
+            judges: HashMap::from([(JudgeRole::Ok, judge)]),
+            ..Kampfgericht::default()
+        };
+        return Wettkampf {
+            wk_name: "Landesmeisterschaft Rhönradturnen".to_string(),
+            wk_place: "Stuttgart".to_string(),
+            wk_judgingtables: HashMap::from([("1".to_string(), table)]),
+            ..Wettkampf::default()
+        };
+    }
+
+    fn render(wettkampf: &Wettkampf) -> Vec<u8> {
+        let mut package = generate_plan(wettkampf).unwrap();
+        return render_pdf(&read_layout(&mut package).unwrap());
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        return haystack.windows(needle.len()).any(|window| window == needle);
+    }
+
+    #[test]
+    fn writes_a_pdf() {
+        let path = std::env::temp_dir().join(format!("kre-pdf-{}.pdf", std::process::id()));
+
+        assert_eq!(create_tables_pdf(&wettkampf("Anna Schmidt"), path.clone()), ApplicationError::NoError);
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(bytes.starts_with(b"%PDF-"));
+        assert!(bytes.trim_ascii_end().ends_with(b"%%EOF"));
+        assert!(contains(&bytes, b"/Type /Page"));
+        // Names in WinAnsi need no embedded font
+        assert!(!contains(&bytes, b"/FontFile2"));
+    }
+
+    #[test]
+    fn embeds_the_fallback_font_for_other_characters() {
+        let bytes = render(&wettkampf("Łukasz Nowak"));
+
+        assert!(contains(&bytes, b"/FontFile2"));
+        assert!(contains(&bytes, b"+DejaVuSans"));
+        assert!(contains(&bytes, b"/F5"));
+        // The same plan gives the same file, see subset_tag
+        assert_eq!(bytes, render(&wettkampf("Łukasz Nowak")));
+    }
+
+    #[test]
+    fn encodes_winansi() {
+        assert_eq!(encode('A'), Some(b'A'));
+        assert_eq!(encode('ü'), Some(0xFC));
+        assert_eq!(encode('€'), Some(0x80));
+        assert_eq!(encode('Š'), Some(0x8A));
+        assert_eq!(encode('Ł'), None);
+    }
+
+    #[test]
+    fn refuses_an_empty_path() {
+        assert_eq!(create_tables_pdf(&wettkampf("Anna Schmidt"), PathBuf::new()), ApplicationError::PdfSavePathIsEmpty);
+    }
+}
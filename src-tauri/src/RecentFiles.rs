@@ -0,0 +1,182 @@
+use crate::types::Wettkampf;
+use crate::Resources::application_data_dir;
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+// The most recently opened and saved Wettkampf files, newest first.
+// The list lives in the application data folder, so it is kept across launches.
+
+/// How many files the list remembers.
+pub const MAX_RECENT_FILES: usize = 10;
+
+/// A recently used Wettkampf file, as shown in the Open Recent menu and the main window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentFile {
+    pub path: PathBuf,
+    /// Name and date of the Wettkampf when the file was last opened or saved.
+    pub wk_name: String,
+    pub wk_date: String,
+    /// RFC 3339 timestamp of when the file was last opened or saved.
+    pub last_opened: String,
+}
+
+fn recent_files_path() -> Option<PathBuf> {
+    return application_data_dir().map(|dir| dir.join("recent_files.json"));
+}
+
+/// Reads the list of recent files. Files that were moved or deleted since are dropped from the list.
+/// Returns: The recent files, newest first. An unreadable list counts as empty.
+pub fn load_recent_files() -> Vec<RecentFile> {
+    let path = match recent_files_path() {
+        Some(path) => path,
+        None => return Vec::new(),
+    };
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            if err.kind() != std::io::ErrorKind::NotFound {
+                log::error!("Could not read the recent files: {:?}", err);
+            }
+            return Vec::new();
+        }
+    };
+    let recent_files: Vec<RecentFile> = match serde_json::from_str(&contents) {
+        Ok(recent_files) => recent_files,
+        Err(err) => {
+            log::warn!("Could not deserialize the recent files, starting with an empty list: {:?}", err);
+            return Vec::new();
+        }
+    };
+
+    let count = recent_files.len();
+    let existing: Vec<RecentFile> = recent_files.into_iter().filter(|recent_file| recent_file.path.is_file()).collect();
+    if existing.len() != count {
+        store_recent_files(&existing);
+    }
+    return existing;
+}
+
+/// Puts a file at the top of the list, or moves it there if it is already in it.
+/// Param 1: The path of the file
+/// Param 2: The Wettkampf in the file, for its name and date
+pub fn add_recent_file(path: &Path, wettkampf: &Wettkampf) {
+    let mut recent_files = load_recent_files();
+    insert_recent_file(&mut recent_files, path, wettkampf);
+    store_recent_files(&recent_files);
+}
+
+/// Puts a file at the top of the list, dropping its previous entry and those beyond MAX_RECENT_FILES.
+fn insert_recent_file(recent_files: &mut Vec<RecentFile>, path: &Path, wettkampf: &Wettkampf) {
+    recent_files.retain(|recent_file| recent_file.path != path);
+    recent_files.insert(
+        0,
+        RecentFile {
+            path: path.to_path_buf(),
+            wk_name: wettkampf.wk_name.clone(),
+            wk_date: wettkampf.wk_date.clone(),
+            last_opened: Local::now().to_rfc3339(),
+        },
+    );
+    recent_files.truncate(MAX_RECENT_FILES);
+}
+
+/// Removes a file from the list, e.g. because it could not be opened anymore.
+pub fn remove_recent_file(path: &Path) {
+    let mut recent_files = load_recent_files();
+    let count = recent_files.len();
+    recent_files.retain(|recent_file| recent_file.path != path);
+    if recent_files.len() != count {
+        store_recent_files(&recent_files);
+    }
+}
+
+fn store_recent_files(recent_files: &[RecentFile]) {
+    let path = match recent_files_path() {
+        Some(path) => path,
+        None => {
+            log::error!("Could not get the Base Dirs for the recent files");
+            return;
+        }
+    };
+    if let Some(dir) = path.parent() {
+        match std::fs::create_dir_all(dir) {
+            Ok(()) => {}
+            Err(err) => {
+                log::error!("Could not create the application data folder: {:?}", err);
+                return;
+            }
+        }
+    }
+    let contents = match serde_json::to_string_pretty(recent_files) {
+        Ok(contents) => contents,
+        Err(err) => {
+            log::error!("Could not serialize the recent files: {:?}", err);
+            return;
+        }
+    };
+    match std::fs::write(&path, contents) {
+        Ok(()) => {}
+        Err(err) => log::error!("Could not write the recent files: {:?}", err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wettkampf(name: &str) -> Wettkampf {
+        return Wettkampf {
+            wk_name: name.to_string(),
+            wk_date: "2026-10-18".to_string(),
+            ..Wettkampf::default()
+        };
+    }
+
+    fn paths(recent_files: &[RecentFile]) -> Vec<&str> {
+        return recent_files.iter().map(|recent_file| recent_file.path.to_str().unwrap()).collect();
+    }
+
+    #[test]
+    fn new_files_come_first() {
+        let mut recent_files = Vec::new();
+        insert_recent_file(&mut recent_files, Path::new("A.wkdata"), &wettkampf("A"));
+        insert_recent_file(&mut recent_files, Path::new("B.wkdata"), &wettkampf("B"));
+        assert_eq!(paths(&recent_files), vec!["B.wkdata", "A.wkdata"]);
+        assert_eq!(recent_files[0].wk_name, "B");
+        assert_eq!(recent_files[0].wk_date, "2026-10-18");
+    }
+
+    #[test]
+    fn a_known_file_moves_to_the_front_once() {
+        let mut recent_files = Vec::new();
+        for name in ["A", "B", "C"] {
+            insert_recent_file(&mut recent_files, Path::new(&format!("{name}.wkdata")), &wettkampf(name));
+        }
+        insert_recent_file(&mut recent_files, Path::new("A.wkdata"), &wettkampf("A umbenannt"));
+        assert_eq!(paths(&recent_files), vec!["A.wkdata", "C.wkdata", "B.wkdata"]);
+        // The entry is updated to the competition as it is now
+        assert_eq!(recent_files[0].wk_name, "A umbenannt");
+
+        // Opening the most recent file again changes nothing else
+        insert_recent_file(&mut recent_files, Path::new("A.wkdata"), &wettkampf("A umbenannt"));
+        assert_eq!(paths(&recent_files), vec!["A.wkdata", "C.wkdata", "B.wkdata"]);
+    }
+
+    #[test]
+    fn the_oldest_files_are_forgotten() {
+        let mut recent_files = Vec::new();
+        for index in 0..MAX_RECENT_FILES + 2 {
+            insert_recent_file(&mut recent_files, Path::new(&format!("{index}.wkdata")), &wettkampf("Wettkampf"));
+        }
+        assert_eq!(recent_files.len(), MAX_RECENT_FILES);
+        assert_eq!(recent_files[0].path, Path::new(&format!("{}.wkdata", MAX_RECENT_FILES + 1)));
+        assert_eq!(recent_files[MAX_RECENT_FILES - 1].path, Path::new("2.wkdata"));
+
+        // Moving a file to the front of a full list drops no other file
+        insert_recent_file(&mut recent_files, Path::new("2.wkdata"), &wettkampf("Wettkampf"));
+        assert_eq!(recent_files.len(), MAX_RECENT_FILES);
+        assert_eq!(recent_files[0].path, Path::new("2.wkdata"));
+        assert_eq!(recent_files[MAX_RECENT_FILES - 1].path, Path::new("3.wkdata"));
+    }
+}
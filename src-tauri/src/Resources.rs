@@ -0,0 +1,84 @@
+use crate::Settings::current_settings;
+use std::path::PathBuf;
+
+// Pack files at compile time and write them to disk at runtime... Currently the only way to embed files within the binary cross-platform
+#[cfg(not(target_os = "windows"))]
+static TEMPLATE_FILE_BINARY: &[u8] = include_bytes!(r"../../res/Vorlage_Einsatzplan_Leer.docx");
+#[cfg(not(target_os = "windows"))]
+static TABLE_FILE_BINARY: &[u8] = include_bytes!(r"../../res/Tabelle_Vorlage_Leer.docx");
+#[cfg(target_os = "windows")]
+static TEMPLATE_FILE_BINARY: &[u8] = include_bytes!(r"..\..\res\Vorlage_Einsatzplan_Leer.docx");
+#[cfg(target_os = "windows")]
+static TABLE_FILE_BINARY: &[u8] = include_bytes!(r"..\..\res\Tabelle_Vorlage_Leer.docx");
+
+/// Returns the folder where the application stores all of its data (Logs, Resources, ...).
+/// Returns: None, if the Base Dirs of the platform could not be determined.
+pub fn application_data_dir() -> Option<PathBuf> {
+    directories::BaseDirs::new()
+        .map(|dirs| dirs.data_dir().join("de.philippremy.dtb-kampfrichtereinsatzplaene"))
+}
+
+/// Reads a template from the template folder of the settings or else the Resources folder.
+/// Falls back to the embedded copy if the file is missing or unreadable.
+/// Param 1: The file name of the template.
+/// Returns: The raw template file or a description of what went wrong.
+pub fn read_template(file_name: &str) -> Result<Vec<u8>, String> {
+    if let Some(dir) = current_settings().template_dir {
+        match std::fs::read(dir.join(file_name)) {
+            Ok(bytes) => return Ok(bytes),
+            // The folder only has to contain the templates that were changed
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => {
+                log::warn!("Could not read template {file_name} from {}, using the built-in one: {err}", dir.display());
+            }
+        }
+    }
+    if let Some(dir) = application_data_dir() {
+        match std::fs::read(dir.join("Resources").join(file_name)) {
+            Ok(bytes) => return Ok(bytes),
+            // Expected for the CLI, if the app never ran on this machine
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => {
+                log::warn!("Could not read template {file_name} from the Resources folder, using the embedded one: {err}");
+            }
+        }
+    }
+    return match file_name {
+        "Vorlage_Einsatzplan_Leer.docx" => Ok(TEMPLATE_FILE_BINARY.to_vec()),
+        "Tabelle_Vorlage_Leer.docx" => Ok(TABLE_FILE_BINARY.to_vec()),
+        _ => Err(format!("Unknown template file: {file_name}")),
+    };
+}
+
+/// Writes the embedded templates into the Resources folder, where the plan generator looks for them first.
+/// Called by the app on startup. The CLI does not write them, read_template falls back to the embedded copies.
+/// Returns: A Result containing the Resources folder or a description of what went wrong.
+pub fn write_templates() -> Result<PathBuf, String> {
+    let application_resources_dir = match application_data_dir() {
+        Some(dir) => dir.join("Resources"),
+        None => {
+            return Err("Could not get the Base Dirs. Important files will be missing and we cannot get them from anywhere else.".to_string());
+        }
+    };
+    // Create the folder if it does not exist!
+    match std::fs::create_dir_all(application_resources_dir.clone()) {
+        Ok(()) => {}
+        Err(err) => return Err(format!("Could not create the AppData dir: {:?}", err)),
+    }
+    // Copy the stuff to there and we should be good to go.
+    match std::fs::write(
+        application_resources_dir.join("Vorlage_Einsatzplan_Leer.docx"),
+        TEMPLATE_FILE_BINARY,
+    ) {
+        Ok(()) => {}
+        Err(e) => return Err(format!("Could not write the template file: {e}")),
+    }
+    match std::fs::write(
+        application_resources_dir.join("Tabelle_Vorlage_Leer.docx"),
+        TABLE_FILE_BINARY,
+    ) {
+        Ok(()) => {}
+        Err(e) => return Err(format!("Could not write the table file: {e}")),
+    }
+    return Ok(application_resources_dir);
+}
@@ -0,0 +1,531 @@
+use crate::Disciplines::{Discipline, DISCIPLINES};
+use crate::DocxImpl::DocxPackage;
+use crate::DocxReader::{parse_xml, resolve_relationship, XmlElement};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+// Reads the judge lists exported from GymNet, the member administration of the DTB.
+// GymNet exports CSV files (separated by semicolons, UTF-8 or Windows-1252) and XLSX files.
+// The columns are found by their header, so their order and additional columns do not matter.
+
+/// The license levels of judges, from the lowest to the highest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum LicenseLevel {
+    D,
+    C,
+    B,
+    A,
+    International,
+}
+
+/// A judge of the roster, e.g. for autocompletion and name checks in the editor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RosterJudge {
+    /// "Vorname Nachname", the way names are written in the plans.
+    pub name: String,
+    pub club: String,
+    /// None if the export contains no or an unknown license.
+    pub license: Option<LicenseLevel>,
+    pub disciplines: Vec<Discipline>,
+}
+
+impl LicenseLevel {
+    /// Parses texts like "B", "B-Lizenz", "Lizenzstufe B" or "International".
+    fn parse(value: &str) -> Option<LicenseLevel> {
+        let value = value.to_lowercase();
+        if value.contains("international") || value.contains("irv") {
+            return Some(LicenseLevel::International);
+        }
+        for token in value.split(|c: char| !c.is_alphanumeric()) {
+            match token {
+                "a" => return Some(LicenseLevel::A),
+                "b" => return Some(LicenseLevel::B),
+                "c" => return Some(LicenseLevel::C),
+                "d" => return Some(LicenseLevel::D),
+                _ => {}
+            }
+        }
+        return None;
+    }
+}
+
+/// Reads a judge list exported from GymNet.
+/// Param 1: Path of a .csv or .xlsx file
+/// Returns: The judges in the order of the file, or a description of the problem
+pub fn read_roster(path: &Path) -> Result<Vec<RosterJudge>, String> {
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(err) => return Err(format!("Failed to read {}: {err}", path.display())),
+    };
+    // XLSX files are zip archives, which is more reliable than the file extension
+    let rows = if bytes.starts_with(b"PK\x03\x04") {
+        read_xlsx_rows(bytes)?
+    } else {
+        read_csv_rows(&decode_text(&bytes))
+    };
+    return parse_roster(&rows);
+}
+
+/// Finds the columns by their header and reads one judge per row.
+/// Judges listed twice are merged.
+fn parse_roster(rows: &[Vec<String>]) -> Result<Vec<RosterJudge>, String> {
+    let (header_index, columns) = match rows
+        .iter()
+        .enumerate()
+        .find_map(|(index, row)| find_columns(row).map(|columns| (index, columns)))
+    {
+        Some(found) => found,
+        None => return Err("The file contains no column with the names of the judges".to_string()),
+    };
+
+    let mut roster: Vec<RosterJudge> = Vec::new();
+    for row in &rows[header_index + 1..] {
+        let cell = |column: Option<usize>| -> &str {
+            return column.and_then(|column| row.get(column)).map(|value| value.trim()).unwrap_or("");
+        };
+        let name = match columns.name {
+            Some(_) => normalize_name(cell(columns.name)),
+            None => format!("{} {}", cell(columns.first_name), cell(columns.last_name))
+                .trim()
+                .to_string(),
+        };
+        if name.is_empty() {
+            continue;
+        }
+
+        let mut disciplines = parse_disciplines(cell(columns.disciplines), true);
+        for (column, flagged) in &columns.discipline_flags {
+            if is_flag_set(cell(Some(*column))) {
+                disciplines.extend(flagged.iter().cloned());
+            }
+        }
+        let judge = RosterJudge {
+            name,
+            club: cell(columns.club).to_string(),
+            license: LicenseLevel::parse(cell(columns.license)),
+            disciplines,
+        };
+
+        match roster.iter_mut().find(|known| is_same_name(&known.name, &judge.name)) {
+            Some(known) => {
+                known.license = known.license.max(judge.license);
+                known.disciplines.extend(judge.disciplines);
+                if known.club.is_empty() {
+                    known.club = judge.club;
+                }
+            }
+            None => roster.push(judge),
+        }
+    }
+    for judge in &mut roster {
+        let mut unique: Vec<Discipline> = Vec::new();
+        for discipline in judge.disciplines.drain(..) {
+            if !unique.contains(&discipline) {
+                unique.push(discipline);
+            }
+        }
+        judge.disciplines = unique;
+    }
+    return Ok(roster);
+}
+
+/// Whether two judge names refer to the same person, ignoring case and surrounding spaces.
+fn is_same_name(first: &str, second: &str) -> bool {
+    return name_key(first) == name_key(second);
+}
+
+/// Returns: The name in the form used for comparisons, see is_same_name.
+/// Also used for club names, which are typed in by hand just like judge names.
+pub fn name_key(name: &str) -> String {
+    return name.trim().to_lowercase();
+}
+
+#[derive(Debug, Default)]
+struct Columns {
+    name: Option<usize>,
+    first_name: Option<usize>,
+    last_name: Option<usize>,
+    club: Option<usize>,
+    license: Option<usize>,
+    disciplines: Option<usize>,
+    /// Columns with one discipline each, marked with e.g. "x" or "ja".
+    discipline_flags: Vec<(usize, Vec<Discipline>)>,
+}
+
+/// Returns: The columns if the row is a header, i.e. contains a column with names.
+fn find_columns(row: &[String]) -> Option<Columns> {
+    let mut columns = Columns::default();
+    for (index, header) in row.iter().enumerate() {
+        let header = header.trim().trim_end_matches([':', '*']).to_lowercase();
+        // The license before the names, as it may be called e.g. "Kampfrichterlizenz"
+        if header.contains("lizenz") || header.contains("qualifikation") || header.contains("stufe") {
+            columns.license = Some(index);
+        } else if header == "vorname" {
+            columns.first_name = Some(index);
+        } else if header == "nachname" || header == "familienname" {
+            columns.last_name = Some(index);
+        } else if header == "name" || header.starts_with("kampfrichter") {
+            columns.name = Some(index);
+        } else if header.starts_with("verein") || header == "club" {
+            columns.club = Some(index);
+        } else if header.starts_with("disziplin") || header.starts_with("wettkampfart") {
+            columns.disciplines = Some(index);
+        } else {
+            let disciplines = parse_disciplines(&header, false);
+            if !disciplines.is_empty() {
+                columns.discipline_flags.push((index, disciplines));
+            }
+        }
+    }
+    // Next to "Vorname", "Name" is the last name
+    if columns.first_name.is_some() && columns.last_name.is_none() {
+        columns.last_name = columns.name.take();
+    }
+    let has_names = columns.name.is_some() || (columns.first_name.is_some() && columns.last_name.is_some());
+    return if has_names { Some(columns) } else { None };
+}
+
+/// Turns "Nachname, Vorname" into "Vorname Nachname".
+fn normalize_name(name: &str) -> String {
+    return match name.split_once(',') {
+        Some((last_name, first_name)) if !first_name.contains(',') => {
+            format!("{} {}", first_name.trim(), last_name.trim()).trim().to_string()
+        }
+        _ => name.to_string(),
+    };
+}
+
+fn is_flag_set(value: &str) -> bool {
+    let value = value.to_lowercase();
+    return !matches!(value.as_str(), "" | "-" | "0" | "nein" | "n" | "false" | "falsch");
+}
+
+/// Maps the discipline names of GymNet to ours. General names stand for several disciplines,
+/// e.g. "Cyr" for both programs.
+/// Param 2: Whether unknown names are kept as Discipline::Other
+fn parse_disciplines(value: &str, keep_unknown: bool) -> Vec<Discipline> {
+    let of_sport = |sport: &str| -> Vec<Discipline> {
+        return DISCIPLINES.iter().filter(|discipline| discipline.sport() == sport).cloned().collect();
+    };
+    let mut disciplines = Vec::new();
+    for part in value.split([',', ';', '/', '|', '+', '\n']).map(|part| part.trim()) {
+        let lowercase = part.to_lowercase();
+        if lowercase.is_empty() {
+            continue;
+        }
+        if let Some(discipline) = DISCIPLINES.iter().find(|discipline| discipline.as_str().to_lowercase() == lowercase) {
+            disciplines.push(discipline.clone());
+        } else if lowercase.contains("gerade") {
+            if lowercase.contains("ohne") {
+                disciplines.push(Discipline::GeradeturnenOhneMusik);
+            } else if lowercase.contains("musik") {
+                disciplines.push(Discipline::GeradeturnenAufMusik);
+            } else {
+                disciplines.extend([Discipline::GeradeturnenOhneMusik, Discipline::GeradeturnenAufMusik]);
+            }
+        } else if lowercase.contains("spirale") {
+            disciplines.push(Discipline::Spiraleturnen);
+        } else if lowercase.contains("sprung") {
+            disciplines.push(Discipline::Sprung);
+        } else if lowercase.contains("artist") {
+            disciplines.push(Discipline::ArtistischesProgramm);
+        } else if lowercase.contains("techn") {
+            disciplines.push(Discipline::TechnischesProgramm);
+        } else if lowercase.contains("cyr") {
+            disciplines.extend(of_sport("Cyr Wheel"));
+        } else if lowercase.contains("rhönrad") || lowercase.contains("rhoenrad") {
+            disciplines.extend(of_sport("Rhönradturnen"));
+        } else if keep_unknown {
+            disciplines.push(Discipline::Other(part.to_string()));
+        }
+    }
+    return disciplines;
+}
+
+/// The characters of Windows-1252 from 0x80 to 0x9F, where Latin-1 has control characters.
+/// Among them are Š, Ž and Œ, which appear in names. The five unassigned bytes keep their Latin-1 meaning.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+/// Older GymNet versions export Windows-1252. Apart from 0x80 to 0x9F it is the same as Latin-1,
+/// whose bytes are the code points of their characters.
+fn decode_text(bytes: &[u8]) -> String {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    return match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => bytes
+            .iter()
+            .map(|byte| match byte {
+                0x80..=0x9F => WINDOWS_1252_HIGH[(byte - 0x80) as usize],
+                _ => *byte as char,
+            })
+            .collect(),
+    };
+}
+
+/// Splits CSV text into rows, with quoted cells as in RFC 4180.
+/// The delimiter is the most frequent of ';', '\t' and ',' in the first line.
+fn read_csv_rows(text: &str) -> Vec<Vec<String>> {
+    let first_line = text.lines().next().unwrap_or("");
+    let delimiter = [';', '\t', ',']
+        .into_iter()
+        .max_by_key(|delimiter| (first_line.matches(*delimiter).count(), std::cmp::Reverse(*delimiter == ',')))
+        .unwrap();
+
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    cell.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                cell.push(c);
+            }
+        } else if c == '"' {
+            in_quotes = true;
+        } else if c == delimiter {
+            row.push(std::mem::take(&mut cell));
+        } else if c == '\n' || c == '\r' {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                chars.next();
+            }
+            row.push(std::mem::take(&mut cell));
+            rows.push(std::mem::take(&mut row));
+        } else {
+            cell.push(c);
+        }
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+    return rows;
+}
+
+/// Reads the cells of the first worksheet as text.
+fn read_xlsx_rows(bytes: Vec<u8>) -> Result<Vec<Vec<String>>, String> {
+    let mut package = DocxPackage::open(bytes)?;
+    let workbook = parse_xml(&package.read_xml_part("xl/workbook.xml")?)?;
+    let sheet_id = workbook.path(&["sheets", "sheet"]).and_then(|sheet| sheet.attribute("r:id"));
+    let sheet_part = match sheet_id {
+        Some(id) => resolve_relationship(&mut package, "xl/workbook.xml", id)?,
+        None => None,
+    };
+    let sheet = parse_xml(&package.read_xml_part(sheet_part.as_deref().unwrap_or("xl/worksheets/sheet1.xml"))?)?;
+
+    let shared_strings: Vec<String> = match package.read_part("xl/sharedStrings.xml")? {
+        Some(bytes) => parse_xml(&String::from_utf8_lossy(&bytes))?
+            .elements()
+            .filter(|element| element.name == "si")
+            .map(rich_text)
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let mut rows = Vec::new();
+    for row in sheet.child("sheetData").iter().flat_map(|data| data.elements()) {
+        if row.name != "row" {
+            continue;
+        }
+        let mut cells: Vec<String> = Vec::new();
+        for cell in row.elements().filter(|element| element.name == "c") {
+            let column = cell.attribute("r").and_then(column_index).unwrap_or(cells.len());
+            let value = cell.child("v").map(|value| value.text()).unwrap_or_default();
+            let text = match cell.attribute("t") {
+                Some("s") => value
+                    .trim()
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| shared_strings.get(index).cloned())
+                    .unwrap_or_default(),
+                Some("inlineStr") => cell.child("is").map(rich_text).unwrap_or_default(),
+                _ => value,
+            };
+            if cells.len() <= column {
+                cells.resize(column + 1, String::new());
+            }
+            cells[column] = text;
+        }
+        rows.push(cells);
+    }
+    return Ok(rows);
+}
+
+/// The text of a string item, either plain (<t>) or formatted in runs (<r><t>).
+/// Phonetic hints (<rPh>) are skipped.
+fn rich_text(item: &XmlElement) -> String {
+    let mut text = String::new();
+    for element in item.elements() {
+        match element.name.as_str() {
+            "t" => text.push_str(&element.text()),
+            "r" => text.push_str(&element.child("t").map(|t| t.text()).unwrap_or_default()),
+            _ => {}
+        }
+    }
+    return text;
+}
+
+/// Turns a cell reference like "C12" into the zero-based column index 2.
+fn column_index(reference: &str) -> Option<usize> {
+    let letters: Vec<char> = reference.chars().take_while(|c| c.is_ascii_alphabetic()).collect();
+    if letters.is_empty() {
+        return None;
+    }
+    let number = letters
+        .iter()
+        .fold(0, |number, letter| number * 26 + (letter.to_ascii_uppercase() as usize - 'A' as usize + 1));
+    return Some(number - 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_windows_1252() {
+        // "Name;Verein" and "Šimić, Zoë;TV Müllheim – Süd" as written by older GymNet versions
+        let mut bytes = b"Name;Verein\r\n".to_vec();
+        bytes.extend_from_slice(b"\x8Aimi\xE6, Zo\xEB;TV M\xFCllheim \x96 S\xFCd\r\n");
+        let text = decode_text(&bytes);
+
+        assert_eq!(text, "Name;Verein\r\n\u{160}imi\u{E6}, Zo\u{EB};TV Müllheim \u{2013} Süd\r\n");
+        let roster = parse_roster(&read_csv_rows(&text)).unwrap();
+        assert_eq!(roster[0].name, "Zo\u{EB} \u{160}imi\u{E6}");
+        assert_eq!(roster[0].club, "TV Müllheim \u{2013} Süd");
+    }
+
+    #[test]
+    fn decodes_utf8_with_and_without_bom() {
+        assert_eq!(decode_text("Jürgen Œhl".as_bytes()), "Jürgen Œhl");
+        assert_eq!(decode_text(b"\xEF\xBB\xBFName;Verein"), "Name;Verein");
+    }
+
+    #[test]
+    fn reads_quoted_cells() {
+        let text = "Name;Verein;Bemerkung\n\"Schmidt; Anna\";\"TV \"\"Frisch Auf\"\" Nord\";\"Zwei\nZeilen\"\r\nBen Weber;;\n";
+
+        assert_eq!(
+            read_csv_rows(text),
+            vec![
+                vec!["Name", "Verein", "Bemerkung"],
+                vec!["Schmidt; Anna", "TV \"Frisch Auf\" Nord", "Zwei\nZeilen"],
+                vec!["Ben Weber", "", ""],
+            ]
+        );
+    }
+
+    #[test]
+    fn detects_the_delimiter() {
+        assert_eq!(read_csv_rows("Name,Verein\nAnna Schmidt,TV Nord"), vec![vec!["Name", "Verein"], vec!["Anna Schmidt", "TV Nord"]]);
+        assert_eq!(read_csv_rows("Name\tVerein\nAnna Schmidt\tTV Nord"), vec![vec!["Name", "Verein"], vec!["Anna Schmidt", "TV Nord"]]);
+        // A comma inside a name does not win against the semicolons
+        assert_eq!(read_csv_rows("Name;Verein;Lizenz, Stufe\n"), vec![vec!["Name", "Verein", "Lizenz, Stufe"]]);
+    }
+
+    #[test]
+    fn turns_last_name_first_into_first_name_first() {
+        let roster = parse_roster(&read_csv_rows("Kampfrichter;Verein\nSchmidt, Anna;TV Nord\nBen Weber;TSV Süd\nA, B, C;\n")).unwrap();
+        let names: Vec<&str> = roster.iter().map(|judge| judge.name.as_str()).collect();
+
+        assert_eq!(names, vec!["Anna Schmidt", "Ben Weber", "A, B, C"]);
+    }
+
+    #[test]
+    fn joins_separate_name_columns() {
+        let text = "Name;Vorname;Verein;Lizenzstufe\nSchmidt;Anna;TV Nord;A-Lizenz\nWeber;Ben;TSV Süd;\n";
+        let roster = parse_roster(&read_csv_rows(text)).unwrap();
+
+        assert_eq!(roster.len(), 2);
+        assert_eq!(roster[0].name, "Anna Schmidt");
+        assert_eq!(roster[0].license, Some(LicenseLevel::A));
+        assert_eq!(roster[1].name, "Ben Weber");
+        assert_eq!(roster[1].license, None);
+    }
+
+    #[test]
+    fn merges_judges_listed_twice() {
+        let text = "Name;Verein;Lizenz;Disziplinen;Sprung\nSchmidt, Anna;;C;Spiraleturnen;\nanna schmidt ;TV Nord;B;Spiraleturnen;x\n";
+        let roster = parse_roster(&read_csv_rows(text)).unwrap();
+
+        assert_eq!(
+            roster,
+            vec![RosterJudge {
+                name: "Anna Schmidt".to_string(),
+                club: "TV Nord".to_string(),
+                license: Some(LicenseLevel::B),
+                disciplines: vec![Discipline::Spiraleturnen, Discipline::Sprung],
+            }]
+        );
+    }
+
+    #[test]
+    fn license_headers_are_no_name_columns() {
+        for text in ["Kampfrichter;Kampfrichterlizenz\nAnna Schmidt;B-Lizenz\n", "Kampfrichterstufe;Kampfrichter\nB;Anna Schmidt\n"] {
+            let roster = parse_roster(&read_csv_rows(text)).unwrap();
+            assert_eq!(roster[0].name, "Anna Schmidt");
+            assert_eq!(roster[0].license, Some(LicenseLevel::B));
+        }
+        assert!(parse_roster(&read_csv_rows("Kampfrichterlizenz;Verein\nB;TV Nord\n")).is_err());
+    }
+
+    #[test]
+    fn reads_xlsx() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("Kampfrichter.xlsx");
+        let rows = read_xlsx_rows(std::fs::read(&path).unwrap()).unwrap();
+
+        // The first sheet of the workbook, with shared and inline strings, rich text and gaps between the cells
+        let mut header = vec!["Name", "Verein", "", "Kampfrichterlizenz"];
+        header.resize(26, "");
+        header.push("Sprung");
+        assert_eq!(rows[0], header);
+        assert_eq!(rows[1][..4], ["Schmidt, Anna", "TV Nord", "", "B-Lizenz"]);
+        assert_eq!(rows[1][26], "1");
+        assert_eq!(rows[2], vec!["Weber, Ben ", "", "", "C"]);
+
+        assert_eq!(
+            read_roster(&path).unwrap(),
+            vec![
+                RosterJudge {
+                    name: "Anna Schmidt".to_string(),
+                    club: "TV Nord".to_string(),
+                    license: Some(LicenseLevel::B),
+                    disciplines: vec![Discipline::Sprung],
+                },
+                RosterJudge {
+                    name: "Ben Weber".to_string(),
+                    club: String::new(),
+                    license: Some(LicenseLevel::C),
+                    disciplines: Vec::new(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_the_column_of_a_cell() {
+        assert_eq!(column_index("A1"), Some(0));
+        assert_eq!(column_index("d12"), Some(3));
+        assert_eq!(column_index("Z3"), Some(25));
+        assert_eq!(column_index("AA3"), Some(26));
+        assert_eq!(column_index("AB100"), Some(27));
+        assert_eq!(column_index("12"), None);
+    }
+
+    #[test]
+    fn refuses_files_without_names() {
+        assert!(parse_roster(&read_csv_rows("Verein;Lizenz\nTV Nord;A\n")).is_err());
+        assert!(parse_roster(&[]).is_err());
+    }
+}
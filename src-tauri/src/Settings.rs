@@ -0,0 +1,363 @@
+use crate::types::ApplicationError;
+use crate::Errors::DetailedError;
+use crate::FileFormat::DEFAULT_BACKUP_COUNT;
+use crate::Resources::application_data_dir;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+// The preferences of the user, stored as settings.json in the application data folder.
+// Values missing in the file (e.g. written by an older version) get their default.
+
+/// The most previous versions a save may keep, see FileFormat::write_wk_file.
+pub const MAX_BACKUP_COUNT: usize = 20;
+/// The allowed range of minutes between two autosaves, see Autosave.rs.
+pub const AUTOSAVE_INTERVAL_MINUTES_RANGE: std::ops::RangeInclusive<u64> = 1..=60;
+/// The only server the account the app was built with is sent to, see SmtpAuth::BuiltIn.
+pub const BUILT_IN_SMTP_HOST: &str = "smtp-mail.outlook.com";
+
+/// The settings in use, read from disk on first access.
+static SETTINGS: RwLock<Option<Settings>> = RwLock::new(None);
+
+/// How bug reports, feedback and support requests leave the app.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MailTransport {
+    Smtp,
+    /// Writes every mail as .eml file into a folder instead of sending it, e.g. to test the mails without a server.
+    File,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SmtpTls {
+    /// Connects in plain text and upgrades with STARTTLS, usually on port 587.
+    StartTls,
+    /// Connects with TLS right away, usually on port 465.
+    Implicit,
+    /// No encryption at all, only meant for a local test server.
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SmtpAuth {
+    /// The account the app was built with, see MAIL_ADDRESS and MAIL_PASSWORD in MailImpl.rs.
+    /// Only usable with BUILT_IN_SMTP_HOST and TLS, see Settings::can_use_built_in_account.
+    BuiltIn,
+    /// smtp_username and smtp_password.
+    Login,
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub mail_transport: MailTransport,
+    /// The SMTP server bug reports, feedback and support requests are sent through.
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_tls: SmtpTls,
+    pub smtp_auth: SmtpAuth,
+    /// Only used with SmtpAuth::Login. Stored in plain text, like the rest of the settings.
+    pub smtp_username: String,
+    pub smtp_password: String,
+    /// The sender address of the mails, most servers only accept the address of the account.
+    pub mail_from: String,
+    /// Where bug reports, feedback and support requests go to, e.g. the support of a regional association.
+    pub mail_to: String,
+    /// The folder MailTransport::File writes to, None for the Mails folder in the application data folder.
+    pub mail_dir: Option<PathBuf>,
+    /// The folder the save and export dialogs start in, None for the default of the system.
+    pub default_save_dir: Option<PathBuf>,
+    /// A folder with own versions of the templates, None to use the built-in ones.
+    /// Templates missing in the folder are taken from the built-in ones.
+    pub template_dir: Option<PathBuf>,
+    /// Whether to look for updates on startup.
+    pub check_for_updates: bool,
+    /// Filled in as the responsible person of new competitions.
+    pub default_responsible_person: String,
+    /// How many previous versions of a file are kept as backup when saving.
+    pub backup_count: usize,
+    /// How often the documents with unsaved changes are autosaved.
+    pub autosave_interval_minutes: u64,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        return Settings {
+            mail_transport: MailTransport::Smtp,
+            smtp_host: BUILT_IN_SMTP_HOST.to_string(),
+            smtp_port: 587,
+            smtp_tls: SmtpTls::StartTls,
+            smtp_auth: SmtpAuth::BuiltIn,
+            smtp_username: String::new(),
+            smtp_password: String::new(),
+            mail_from: "dtb-kampfrichtereinsatzplaene@outlook.com".to_string(),
+            mail_to: "philipp.remy@dtb.de".to_string(),
+            mail_dir: None,
+            default_save_dir: None,
+            template_dir: None,
+            check_for_updates: true,
+            default_responsible_person: String::new(),
+            backup_count: DEFAULT_BACKUP_COUNT,
+            autosave_interval_minutes: 1,
+        };
+    }
+}
+
+impl Settings {
+    /// Returns: Whether the account the app was built with may be sent to the SMTP server, which
+    /// is only the case for the server it belongs to and an encrypted connection.
+    pub fn can_use_built_in_account(&self) -> bool {
+        return self.smtp_host.trim().eq_ignore_ascii_case(BUILT_IN_SMTP_HOST) && self.smtp_tls != SmtpTls::None;
+    }
+
+    /// Checks every value for being usable.
+    /// Returns: An InvalidSettingsError naming the first invalid value.
+    pub fn validate(&self) -> Result<(), DetailedError> {
+        if self.smtp_host.trim().is_empty() || self.smtp_host.contains(char::is_whitespace) {
+            return Err(invalid_setting("smtp_host", format!("Ungültiger SMTP-Server: \"{}\"", self.smtp_host)));
+        }
+        if self.smtp_port == 0 {
+            return Err(invalid_setting("smtp_port", "Der SMTP-Port darf nicht 0 sein."));
+        }
+        if self.smtp_auth == SmtpAuth::BuiltIn && !self.can_use_built_in_account() {
+            return Err(invalid_setting(
+                "smtp_auth",
+                format!("Das mitgelieferte Konto kann nur mit {BUILT_IN_SMTP_HOST} und verschlüsselter Verbindung verwendet werden."),
+            ));
+        }
+        if self.smtp_auth == SmtpAuth::Login && self.smtp_username.trim().is_empty() {
+            return Err(invalid_setting("smtp_username", "Für die Anmeldung am SMTP-Server fehlt der Benutzername."));
+        }
+        if !is_mail_address(&self.mail_from) {
+            return Err(invalid_setting("mail_from", format!("Ungültige Absenderadresse: \"{}\"", self.mail_from)));
+        }
+        if !is_mail_address(&self.mail_to) {
+            return Err(invalid_setting("mail_to", format!("Ungültige Empfängeradresse: \"{}\"", self.mail_to)));
+        }
+        if let Some(dir) = &self.mail_dir {
+            if !dir.is_dir() {
+                return Err(invalid_setting("mail_dir", "Der Ordner für die Mails existiert nicht.").with_path(dir));
+            }
+        }
+        if let Some(dir) = &self.default_save_dir {
+            if !dir.is_dir() {
+                return Err(invalid_setting("default_save_dir", "Der Speicherort existiert nicht.").with_path(dir));
+            }
+        }
+        if let Some(dir) = &self.template_dir {
+            if !dir.is_dir() {
+                return Err(invalid_setting("template_dir", "Der Vorlagenordner existiert nicht.").with_path(dir));
+            }
+        }
+        if self.backup_count > MAX_BACKUP_COUNT {
+            return Err(invalid_setting("backup_count", format!("Es können höchstens {MAX_BACKUP_COUNT} Sicherungskopien behalten werden.")));
+        }
+        if !AUTOSAVE_INTERVAL_MINUTES_RANGE.contains(&self.autosave_interval_minutes) {
+            return Err(invalid_setting(
+                "autosave_interval_minutes",
+                format!(
+                    "Das Autospeichern muss alle {} bis {} Minuten erfolgen.",
+                    AUTOSAVE_INTERVAL_MINUTES_RANGE.start(),
+                    AUTOSAVE_INTERVAL_MINUTES_RANGE.end()
+                ),
+            ));
+        }
+        return Ok(());
+    }
+}
+
+/// Returns: Whether the text looks like a mail address. The server has the final say.
+pub fn is_mail_address(address: &str) -> bool {
+    return match address.split_once('@') {
+        Some((local, domain)) => !local.is_empty() && domain.contains('.') && !address.contains(char::is_whitespace),
+        None => false,
+    };
+}
+
+fn invalid_setting(field: &str, message: impl Into<String>) -> DetailedError {
+    return DetailedError::new(ApplicationError::InvalidSettingsError).with_field(field).with_message(message);
+}
+
+fn settings_path() -> Option<PathBuf> {
+    return application_data_dir().map(|dir| dir.join("settings.json"));
+}
+
+/// Returns: A copy of the settings in use.
+pub fn current_settings() -> Settings {
+    if let Some(settings) = read_lock().as_ref() {
+        return settings.clone();
+    }
+    let mut settings = write_lock();
+    // Another thread might have loaded them in the meantime
+    return settings.get_or_insert_with(load_settings).clone();
+}
+
+/// Validates the settings, writes them to disk and uses them from now on.
+/// Returns: An InvalidSettingsError or SettingsWriteError to be shown by the frontend.
+pub fn store_settings(settings: Settings) -> Result<(), DetailedError> {
+    settings.validate()?;
+    let path = match settings_path() {
+        Some(path) => path,
+        None => {
+            return Err(DetailedError::new(ApplicationError::SettingsWriteError).caused_by_text("Could not get the Base Dirs for the settings"));
+        }
+    };
+    let contents = match serde_json::to_string_pretty(&settings) {
+        Ok(contents) => contents,
+        Err(err) => return Err(DetailedError::new(ApplicationError::SettingsWriteError).caused_by(&err)),
+    };
+
+    // Written next to the old file first, so a crash never leaves half a settings file
+    let temporary_path = path.with_extension("json.tmp");
+    let written = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|()| std::fs::write(&temporary_path, contents))
+        .and_then(|()| std::fs::rename(&temporary_path, &path));
+    match written {
+        Ok(()) => {}
+        Err(err) => return Err(DetailedError::new(ApplicationError::SettingsWriteError).with_path(&path).caused_by(&err)),
+    }
+
+    *write_lock() = Some(settings);
+    return Ok(());
+}
+
+/// Reads the settings from disk. Missing, unreadable or invalid settings fall back to the defaults.
+fn load_settings() -> Settings {
+    let path = match settings_path() {
+        Some(path) => path,
+        None => return Settings::default(),
+    };
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            if err.kind() != std::io::ErrorKind::NotFound {
+                log::warn!("Could not read the settings, using the defaults: {:?}", err);
+            }
+            return Settings::default();
+        }
+    };
+    return parse_settings(&contents);
+}
+
+/// The settings stored in settings.json, the values missing in it set to their defaults.
+/// Returns: The defaults if the settings cannot be deserialized or are invalid.
+fn parse_settings(contents: &str) -> Settings {
+    let mut settings: Settings = match serde_json::from_str(contents) {
+        Ok(settings) => settings,
+        Err(err) => {
+            log::warn!("Could not deserialize the settings, using the defaults: {:?}", err);
+            return Settings::default();
+        }
+    };
+    // Folders that were moved or deleted since are forgotten, instead of dropping all settings
+    settings.default_save_dir = settings.default_save_dir.filter(|dir| dir.is_dir());
+    settings.template_dir = settings.template_dir.filter(|dir| dir.is_dir());
+    settings.mail_dir = settings.mail_dir.filter(|dir| dir.is_dir());
+    // A server changed while keeping the built-in account must not get its credentials
+    if settings.smtp_auth == SmtpAuth::BuiltIn && !settings.can_use_built_in_account() {
+        log::warn!("The built-in mail account cannot be used with {}, connecting without authentication", settings.smtp_host);
+        settings.smtp_auth = SmtpAuth::None;
+    }
+    return match settings.validate() {
+        Ok(()) => settings,
+        Err(err) => {
+            log::warn!("The stored settings are invalid, using the defaults: {err}");
+            Settings::default()
+        }
+    };
+}
+
+// The settings are only replaced as a whole, so they are consistent even if a thread panicked while holding the lock
+fn read_lock() -> RwLockReadGuard<'static, Option<Settings>> {
+    return SETTINGS.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+}
+
+fn write_lock() -> RwLockWriteGuard<'static, Option<Settings>> {
+    return SETTINGS.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_autosave_interval(minutes: u64) -> Settings {
+        return Settings {
+            autosave_interval_minutes: minutes,
+            ..Settings::default()
+        };
+    }
+
+    #[test]
+    fn the_defaults_are_valid() {
+        assert!(Settings::default().validate().is_ok());
+    }
+
+    #[test]
+    fn checks_the_autosave_interval_bounds() {
+        for minutes in [0, 61] {
+            let err = with_autosave_interval(minutes).validate().unwrap_err();
+            assert_eq!(err.code(), ApplicationError::InvalidSettingsError);
+            assert_eq!(err.field(), Some("autosave_interval_minutes"));
+        }
+        for minutes in [1, 60] {
+            assert!(with_autosave_interval(minutes).validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn missing_values_get_their_defaults() {
+        assert_eq!(parse_settings("{}"), Settings::default());
+
+        let settings = parse_settings(r#"{"smtp_port": 465, "smtp_tls": "Implicit", "backup_count": 3}"#);
+        assert_eq!(
+            settings,
+            Settings {
+                smtp_port: 465,
+                smtp_tls: SmtpTls::Implicit,
+                backup_count: 3,
+                ..Settings::default()
+            }
+        );
+    }
+
+    #[test]
+    fn unreadable_or_invalid_settings_fall_back_to_the_defaults() {
+        assert_eq!(parse_settings("not json"), Settings::default());
+        assert_eq!(parse_settings(r#"{"smtp_port": 0, "backup_count": 3}"#), Settings::default());
+        assert_eq!(parse_settings(r#"{"autosave_interval_minutes": 61}"#), Settings::default());
+    }
+
+    #[test]
+    fn moved_folders_are_forgotten() {
+        let missing = std::env::temp_dir().join(format!("kre-settings-{}-missing", std::process::id()));
+        let contents = serde_json::json!({ "template_dir": missing, "default_save_dir": std::env::temp_dir() }).to_string();
+        let settings = parse_settings(&contents);
+        assert_eq!(settings.template_dir, None);
+        assert_eq!(settings.default_save_dir, Some(std::env::temp_dir()));
+    }
+
+    #[test]
+    fn the_built_in_account_is_not_sent_to_other_servers() {
+        let settings = parse_settings(r#"{"smtp_host": "mail.example.org", "smtp_auth": "BuiltIn"}"#);
+        assert_eq!(settings.smtp_host, "mail.example.org");
+        assert_eq!(settings.smtp_auth, SmtpAuth::None);
+
+        let unencrypted = parse_settings(r#"{"smtp_tls": "None", "smtp_auth": "BuiltIn"}"#);
+        assert_eq!(unencrypted.smtp_host, BUILT_IN_SMTP_HOST);
+        assert_eq!(unencrypted.smtp_auth, SmtpAuth::None);
+
+        let built_in = parse_settings(r#"{"smtp_host": "SMTP-Mail.Outlook.com", "smtp_auth": "BuiltIn"}"#);
+        assert_eq!(built_in.smtp_auth, SmtpAuth::BuiltIn);
+
+        // Saving such settings is refused instead
+        let err = Settings {
+            smtp_host: "mail.example.org".to_string(),
+            ..Settings::default()
+        }
+        .validate()
+        .unwrap_err();
+        assert_eq!(err.field(), Some("smtp_auth"));
+    }
+}
@@ -0,0 +1,408 @@
+use crate::types::{Kampfgericht, Kampfrichter};
+use crate::Disciplines::JudgeRole;
+use crate::Roster::{name_key, LicenseLevel, RosterJudge};
+use crate::Validation::mark_conflicts;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+// Fills the empty judge slots of all Kampfgerichte from the judge roster.
+// Judges entered by hand are kept and count towards the constraints like assigned ones.
+// The slots are filled one at a time, always taking the slot with the fewest candidates left,
+// so rare licenses are not used up by slots that many judges could fill.
+// Ties are broken by a seeded hash, so the same seed always gives the same plan.
+
+/// Why a slot stayed empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum UnfilledReason {
+    /// The discipline is unknown to this version, so are its roles.
+    UnknownDiscipline,
+    /// No judge of the roster has the license for the role and the discipline.
+    NoLicensedJudge,
+    /// All licensed judges belong to a club competing at the table.
+    OnlyOwnClubJudges,
+    /// All licensed judges are already assigned in this session.
+    AllJudgesAssigned,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnfilledSlot {
+    pub table_id: String,
+    pub table_name: String,
+    /// None if no role of the table could be filled, see UnfilledReason::UnknownDiscipline.
+    pub role: Option<JudgeRole>,
+    pub required: bool,
+    pub reason: UnfilledReason,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SolverResult {
+    pub wk_judgingtables: HashMap<String, Kampfgericht>,
+    pub wk_replacement_judges: Vec<String>,
+    pub unfilled: Vec<UnfilledSlot>,
+}
+
+struct Slot {
+    /// Position in the editor, used for sorting the unfilled slots.
+    position: usize,
+    table_id: String,
+    role: JudgeRole,
+    required: bool,
+    tie_breaker: u64,
+}
+
+/// The minimum license for a role. The supervising judges need a higher license than the execution judges.
+pub fn minimum_license(role: &JudgeRole) -> LicenseLevel {
+    return match role {
+        JudgeRole::Ok => LicenseLevel::A,
+        JudgeRole::Sk1 | JudgeRole::Sk2 => LicenseLevel::B,
+        JudgeRole::Ak1 | JudgeRole::Ak2 | JudgeRole::Ak3 | JudgeRole::Ak4 => LicenseLevel::C,
+        JudgeRole::Aik1 | JudgeRole::Aik2 | JudgeRole::Aik3 | JudgeRole::Aik4 => LicenseLevel::C,
+        JudgeRole::Other(_) => LicenseLevel::D,
+    };
+}
+
+/// Fills all empty slots of the tables.
+/// Constraints: a judge is assigned at most once per session (preliminaries and finals, like the conflict check),
+/// holds the license for the role and the discipline and does not judge a table their club competes at.
+/// Among the possible judges the one with the fewest assignments so far is taken, then the one with the lowest license.
+/// Param 1: The judging tables, already assigned judges are kept
+/// Param 2: The current replacement judges
+/// Param 3: The judge roster
+/// Param 4: Seed for breaking ties
+/// Returns: The filled tables, the replacement judges (extended by every judge of the roster without an assignment)
+/// and every slot that stayed empty together with the reason.
+pub fn assign_judges(
+    tables: &HashMap<String, Kampfgericht>,
+    replacement_judges: &[String],
+    roster: &[RosterJudge],
+    seed: u64,
+) -> SolverResult {
+    let mut tables = tables.clone();
+    let mut unfilled: Vec<(usize, UnfilledSlot)> = Vec::new();
+
+    // Names per session (finals or not) and number of assignments per judge, counting the ones entered by hand
+    let mut sessions: HashMap<bool, HashSet<String>> = HashMap::new();
+    let mut assignments: HashMap<String, usize> = HashMap::new();
+    for table in tables.values() {
+        for judge in table.judges.values() {
+            let key = name_key(&judge.name);
+            if key.is_empty() {
+                continue;
+            }
+            sessions.entry(table.table_is_finale).or_default().insert(key.clone());
+            *assignments.entry(key).or_default() += 1;
+        }
+    }
+
+    // Sorted, so the result does not depend on the order of the HashMap
+    let mut table_ids: Vec<String> = tables.keys().cloned().collect();
+    table_ids.sort();
+    let mut slots = Vec::new();
+    for table_id in &table_ids {
+        let table = &tables[table_id];
+        let roles = match table.table_kind.roles() {
+            Some(roles) => roles,
+            None => {
+                let slot = UnfilledSlot {
+                    table_id: table_id.clone(),
+                    table_name: table.table_name.clone(),
+                    role: None,
+                    required: true,
+                    reason: UnfilledReason::UnknownDiscipline,
+                };
+                unfilled.push((slots.len(), slot));
+                continue;
+            }
+        };
+        for schema in roles {
+            let is_empty = match table.judges.get(&schema.role) {
+                Some(judge) => judge.name.trim().is_empty(),
+                None => true,
+            };
+            if is_empty {
+                slots.push(Slot {
+                    position: slots.len(),
+                    tie_breaker: tie_breaker(seed, &format!("{table_id}/{}", schema.role.as_str())),
+                    table_id: table_id.clone(),
+                    role: schema.role,
+                    required: schema.required,
+                });
+            }
+        }
+    }
+
+    while !slots.is_empty() {
+        // Required roles first, then the slot with the fewest candidates
+        let (index, candidates) = slots
+            .iter()
+            .enumerate()
+            .map(|(index, slot)| {
+                let table = &tables[&slot.table_id];
+                let candidates: Vec<&RosterJudge> = roster
+                    .iter()
+                    .filter(|judge| is_licensed(judge, table, &slot.role))
+                    .filter(|judge| !is_own_club(judge, table))
+                    .filter(|judge| !is_in_session(&sessions, table, judge))
+                    .collect();
+                return (index, candidates);
+            })
+            .min_by_key(|(index, candidates)| (!slots[*index].required, candidates.len(), slots[*index].tie_breaker))
+            .unwrap();
+        let slot = slots.swap_remove(index);
+        let chosen = candidates.into_iter().min_by_key(|judge| {
+            (
+                assignments.get(&name_key(&judge.name)).copied().unwrap_or(0),
+                judge.license,
+                tie_breaker(seed, &judge.name),
+            )
+        });
+
+        let table = tables.get_mut(&slot.table_id).unwrap();
+        match chosen {
+            Some(judge) => {
+                let key = name_key(&judge.name);
+                sessions.entry(table.table_is_finale).or_default().insert(key.clone());
+                *assignments.entry(key).or_default() += 1;
+                table.judges.insert(
+                    slot.role.clone(),
+                    Kampfrichter {
+                        role: slot.role,
+                        name: judge.name.clone(),
+                        doubleFound: false,
+                    },
+                );
+            }
+            None => {
+                let reason = explain_unfilled(roster, table, &slot.role);
+                let unfilled_slot = UnfilledSlot {
+                    table_id: slot.table_id,
+                    table_name: table.table_name.clone(),
+                    role: Some(slot.role),
+                    required: slot.required,
+                    reason,
+                };
+                unfilled.push((slot.position, unfilled_slot));
+            }
+        }
+    }
+    mark_conflicts(&mut tables);
+
+    // Everyone without an assignment stays available as a replacement
+    let mut replacements: Vec<String> = Vec::new();
+    let names = replacement_judges.iter().map(|name| name.trim()).chain(roster.iter().map(|judge| judge.name.as_str()));
+    for name in names {
+        let key = name_key(name);
+        let is_unassigned = assignments.get(&key).copied().unwrap_or(0) == 0;
+        if !key.is_empty() && is_unassigned && !replacements.iter().any(|known| name_key(known) == key) {
+            replacements.push(name.to_string());
+        }
+    }
+
+    unfilled.sort_by(|(a_position, a), (b_position, b)| a.table_name.cmp(&b.table_name).then(a_position.cmp(b_position)));
+    return SolverResult {
+        wk_judgingtables: tables,
+        wk_replacement_judges: replacements,
+        unfilled: unfilled.into_iter().map(|(_, slot)| slot).collect(),
+    };
+}
+
+/// Whether the judge may take the role at the table.
+/// Judges without a known license count as unlicensed, judges without disciplines as licensed for all of them.
+fn is_licensed(judge: &RosterJudge, table: &Kampfgericht, role: &JudgeRole) -> bool {
+    let has_license = judge.license.is_some_and(|license| license >= minimum_license(role));
+    let has_discipline = judge.disciplines.is_empty() || judge.disciplines.contains(&table.table_kind);
+    return has_license && has_discipline;
+}
+
+fn is_own_club(judge: &RosterJudge, table: &Kampfgericht) -> bool {
+    let club = name_key(&judge.club);
+    return !club.is_empty() && table.table_clubs.iter().any(|table_club| name_key(table_club) == club);
+}
+
+fn is_in_session(sessions: &HashMap<bool, HashSet<String>>, table: &Kampfgericht, judge: &RosterJudge) -> bool {
+    return sessions
+        .get(&table.table_is_finale)
+        .is_some_and(|names| names.contains(&name_key(&judge.name)));
+}
+
+/// Returns: The first constraint that rules out every judge of the roster.
+fn explain_unfilled(roster: &[RosterJudge], table: &Kampfgericht, role: &JudgeRole) -> UnfilledReason {
+    let licensed: Vec<&RosterJudge> = roster.iter().filter(|judge| is_licensed(judge, table, role)).collect();
+    if licensed.is_empty() {
+        return UnfilledReason::NoLicensedJudge;
+    }
+    if licensed.iter().all(|judge| is_own_club(judge, table)) {
+        return UnfilledReason::OnlyOwnClubJudges;
+    }
+    return UnfilledReason::AllJudgesAssigned;
+}
+
+/// A pseudo random number that only depends on the seed and the text (FNV-1a, then SplitMix64).
+/// Not the std hasher, whose output may change between Rust versions.
+fn tie_breaker(seed: u64, text: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in text.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    let mut value = hash ^ seed.wrapping_add(0x9e3779b97f4a7c15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+    return value ^ (value >> 31);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Disciplines::Discipline;
+
+    fn judge(name: &str, club: &str, license: LicenseLevel) -> RosterJudge {
+        return RosterJudge {
+            name: name.to_string(),
+            club: club.to_string(),
+            license: Some(license),
+            disciplines: Vec::new(),
+        };
+    }
+
+    fn table(id: &str, clubs: &[&str]) -> Kampfgericht {
+        return Kampfgericht {
+            uniqueID: id.to_string(),
+            table_name: format!("KG {id}"),
+            table_kind: Discipline::Sprung,
+            table_clubs: clubs.iter().map(|club| club.to_string()).collect(),
+            ..Kampfgericht::default()
+        };
+    }
+
+    fn tables(tables: Vec<Kampfgericht>) -> HashMap<String, Kampfgericht> {
+        return tables.into_iter().map(|table| (table.uniqueID.clone(), table)).collect();
+    }
+
+    fn roster() -> Vec<RosterJudge> {
+        let mut roster = Vec::new();
+        for (index, club) in ["TV Nord", "TSV Süd", "SC West", "TG Ost"].iter().enumerate() {
+            roster.push(judge(&format!("Anna {index}"), club, LicenseLevel::A));
+            roster.push(judge(&format!("Ben {index}"), club, LicenseLevel::B));
+            roster.push(judge(&format!("Clara {index}"), club, LicenseLevel::B));
+            for number in 0..4 {
+                roster.push(judge(&format!("David {index}{number}"), club, LicenseLevel::C));
+            }
+        }
+        return roster;
+    }
+
+    /// Returns: The table, role and reason of every unfilled slot, UnfilledSlot has no PartialEq.
+    fn unfilled(result: &SolverResult) -> Vec<(String, Option<JudgeRole>, UnfilledReason)> {
+        return result
+            .unfilled
+            .iter()
+            .map(|slot| (slot.table_id.clone(), slot.role.clone(), slot.reason))
+            .collect();
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_plan() {
+        let tables = tables(vec![table("1", &["TV Nord"]), table("2", &["TSV Süd"]), table("3", &[])]);
+        let roster = roster();
+
+        let first = assign_judges(&tables, &[], &roster, 42);
+        let second = assign_judges(&tables, &[], &roster, 42);
+
+        assert_eq!(first.wk_judgingtables, second.wk_judgingtables);
+        assert_eq!(first.wk_replacement_judges, second.wk_replacement_judges);
+        assert_eq!(unfilled(&first), unfilled(&second));
+    }
+
+    #[test]
+    fn assigned_judges_meet_the_constraints() {
+        let tables = tables(vec![table("1", &["TV Nord"]), table("2", &["TSV Süd", "SC West"]), table("3", &[])]);
+        let roster = roster();
+
+        for seed in 0..20 {
+            let result = assign_judges(&tables, &[], &roster, seed);
+            let mut seen = HashSet::new();
+            for table in result.wk_judgingtables.values() {
+                for (role, assigned) in &table.judges {
+                    let judge = roster.iter().find(|judge| judge.name == assigned.name).unwrap();
+                    assert!(judge.license.unwrap() >= minimum_license(role), "{} judges {}", judge.name, role.as_str());
+                    assert!(!table.table_clubs.contains(&judge.club), "{} judges their own club", judge.name);
+                    assert!(seen.insert(judge.name.clone()), "{} is assigned twice", judge.name);
+                    assert!(!assigned.doubleFound);
+                }
+            }
+            // Every slot is filled, the remaining judges become replacements
+            assert!(result.unfilled.is_empty());
+            assert_eq!(seen.len() + result.wk_replacement_judges.len(), roster.len());
+        }
+    }
+
+    #[test]
+    fn judges_entered_by_hand_are_kept_and_not_assigned_again() {
+        let mut first = table("1", &[]);
+        first.judges.insert(
+            JudgeRole::Ok,
+            Kampfrichter {
+                role: JudgeRole::Ok,
+                name: "Anna 0".to_string(),
+                doubleFound: false,
+            },
+        );
+        let tables = tables(vec![first, table("2", &[])]);
+        let roster = roster();
+
+        let result = assign_judges(&tables, &[], &roster, 1);
+
+        assert_eq!(result.wk_judgingtables["1"].judges[&JudgeRole::Ok].name, "Anna 0");
+        assert_ne!(result.wk_judgingtables["2"].judges[&JudgeRole::Ok].name, "Anna 0");
+    }
+
+    #[test]
+    fn unfilled_slots_name_the_constraint() {
+        let unknown = Kampfgericht {
+            table_kind: Discipline::Other("Trampolin".to_string()),
+            ..table("0", &[])
+        };
+        let roster = vec![
+            judge("Anna", "TV Nord", LicenseLevel::A),
+            judge("Ben", "TV Nord", LicenseLevel::B),
+            judge("Clara", "TSV Süd", LicenseLevel::C),
+            judge("David", "TSV Süd", LicenseLevel::C),
+        ];
+
+        // The four judges fill the required roles, none is left for the optional ones
+        let result = assign_judges(&tables(vec![unknown.clone(), table("1", &[])]), &[], &roster, 3);
+        assert_eq!(
+            unfilled(&result),
+            vec![
+                ("0".to_string(), None, UnfilledReason::UnknownDiscipline),
+                ("1".to_string(), Some(JudgeRole::Sk2), UnfilledReason::AllJudgesAssigned),
+                ("1".to_string(), Some(JudgeRole::Ak3), UnfilledReason::AllJudgesAssigned),
+                ("1".to_string(), Some(JudgeRole::Ak4), UnfilledReason::AllJudgesAssigned),
+            ]
+        );
+
+        let result = assign_judges(&tables(vec![table("1", &["TV Nord"])]), &[], &roster, 3);
+        let reasons = unfilled(&result);
+        assert!(reasons.contains(&("1".to_string(), Some(JudgeRole::Ok), UnfilledReason::OnlyOwnClubJudges)));
+        assert!(reasons.contains(&("1".to_string(), Some(JudgeRole::Sk1), UnfilledReason::OnlyOwnClubJudges)));
+
+        let roster = vec![judge("Clara", "TSV Süd", LicenseLevel::C)];
+        let result = assign_judges(&tables(vec![table("1", &[])]), &[], &roster, 3);
+        assert!(unfilled(&result).contains(&("1".to_string(), Some(JudgeRole::Ok), UnfilledReason::NoLicensedJudge)));
+    }
+
+    #[test]
+    fn unlicensed_and_other_discipline_judges_are_not_assigned() {
+        let mut unlicensed = judge("Anna", "", LicenseLevel::A);
+        unlicensed.license = None;
+        let mut trampoline = judge("Ben", "", LicenseLevel::A);
+        trampoline.disciplines = vec![Discipline::Spiraleturnen];
+        let roster = vec![unlicensed, trampoline];
+
+        let result = assign_judges(&tables(vec![table("1", &[])]), &[], &roster, 0);
+
+        assert!(result.wk_judgingtables["1"].judges.is_empty());
+        assert_eq!(result.wk_replacement_judges, vec!["Anna".to_string(), "Ben".to_string()]);
+    }
+}
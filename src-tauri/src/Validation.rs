@@ -0,0 +1,230 @@
+use crate::types::{ApplicationError, Kampfgericht, Wettkampf};
+use crate::Disciplines::JudgeRole;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// A single seat of a judge in a Kampfgericht.
+#[derive(Debug, Clone, Serialize)]
+pub struct JudgeAssignment {
+    pub table_id: String,
+    pub table_name: String,
+    pub role: JudgeRole,
+}
+
+/// A judge that is assigned more than once, either in the preliminaries or in the finals.
+#[derive(Debug, Clone, Serialize)]
+pub struct JudgeConflict {
+    pub judge_name: String,
+    pub is_finale: bool,
+    pub assignments: Vec<JudgeAssignment>,
+}
+
+impl std::fmt::Display for JudgeConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let assignments: Vec<String> = self
+            .assignments
+            .iter()
+            .map(|assignment| format!("{} ({})", assignment.table_name, assignment.role.label()))
+            .collect();
+        return write!(
+            f,
+            "{} ist {} mehrfach eingeteilt: {}",
+            self.judge_name,
+            if self.is_finale { "im Finale" } else { "im Vorkampf" },
+            assignments.join(", ")
+        );
+    }
+}
+
+/// Names are compared without surrounding whitespace, empty names are never a conflict.
+fn normalize_name(name: &str) -> &str {
+    return name.trim();
+}
+
+/// Finds all judges that are assigned more than once.
+/// Preliminaries and finals are checked separately, as a judge may judge both.
+/// Param 1: The judging tables of the competition.
+/// Returns: The conflicts, sorted by finals and the judge name.
+pub fn find_conflicts(tables: &HashMap<String, Kampfgericht>) -> Vec<JudgeConflict> {
+    // BTreeMap, so the result does not depend on the order of the HashMap
+    let mut seats: BTreeMap<(bool, String), Vec<JudgeAssignment>> = BTreeMap::new();
+
+    for (table_id, table) in tables {
+        for (role, judge) in &table.judges {
+            let name = normalize_name(&judge.name);
+            if name.is_empty() {
+                continue;
+            }
+            seats
+                .entry((table.table_is_finale, name.to_string()))
+                .or_default()
+                .push(JudgeAssignment {
+                    table_id: table_id.clone(),
+                    table_name: table.table_name.clone(),
+                    role: role.clone(),
+                });
+        }
+    }
+
+    return seats
+        .into_iter()
+        .filter(|(_, assignments)| assignments.len() > 1)
+        .map(|((is_finale, judge_name), mut assignments)| {
+            assignments.sort_by(|a, b| a.table_name.cmp(&b.table_name).then(a.role.as_str().cmp(b.role.as_str())));
+            JudgeConflict {
+                judge_name,
+                is_finale,
+                assignments,
+            }
+        })
+        .collect();
+}
+
+/// Sets doubleFound on every judge from the backend's own conflict detection,
+/// instead of trusting the flag sent by the frontend.
+pub fn mark_conflicts(tables: &mut HashMap<String, Kampfgericht>) {
+    let conflicts = find_conflicts(tables);
+    for table in tables.values_mut() {
+        let is_finale = table.table_is_finale;
+        for judge in table.judges.values_mut() {
+            let name = normalize_name(&judge.name);
+            judge.doubleFound = conflicts
+                .iter()
+                .any(|conflict| conflict.is_finale == is_finale && conflict.judge_name == name);
+        }
+    }
+}
+
+/// Checks whether plans may be created from the competition.
+/// Param 1: The competition to check.
+/// Param 2: Whether the user accepted the conflicts. They are only logged then.
+/// Returns: NoError or UnresolvedJudgeConflictsError.
+pub fn check_before_generation(wettkampf: &Wettkampf, force: bool) -> ApplicationError {
+    let conflicts = find_conflicts(&wettkampf.wk_judgingtables);
+    if conflicts.is_empty() {
+        return ApplicationError::NoError;
+    }
+    for conflict in &conflicts {
+        log::warn!("{conflict}");
+    }
+    if force {
+        log::warn!("Creating the plans despite {} unresolved conflict(s), as requested.", conflicts.len());
+        return ApplicationError::NoError;
+    }
+    return ApplicationError::UnresolvedJudgeConflictsError;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Kampfrichter;
+
+    fn table(id: &str, is_finale: bool, judges: &[(JudgeRole, &str)]) -> Kampfgericht {
+        return Kampfgericht {
+            uniqueID: id.to_string(),
+            table_name: format!("KG {id}"),
+            table_is_finale: is_finale,
+            judges: judges
+                .iter()
+                .map(|(role, name)| {
+                    let judge = Kampfrichter {
+                        role: role.clone(),
+                        name: name.to_string(),
+                        doubleFound: false,
+                    };
+                    return (role.clone(), judge);
+                })
+                .collect(),
+            ..Kampfgericht::default()
+        };
+    }
+
+    fn tables(tables: Vec<Kampfgericht>) -> HashMap<String, Kampfgericht> {
+        return tables.into_iter().map(|table| (table.uniqueID.clone(), table)).collect();
+    }
+
+    #[test]
+    fn mark_conflicts_flags_every_seat_of_a_double_booked_judge() {
+        let mut tables = tables(vec![
+            table("1", false, &[(JudgeRole::Ok, "Anna Schmidt"), (JudgeRole::Sk1, "Ben Weber")]),
+            table("2", false, &[(JudgeRole::Ak1, " Anna Schmidt "), (JudgeRole::Sk1, "Clara Wolf")]),
+        ]);
+        mark_conflicts(&mut tables);
+
+        assert!(tables["1"].judges[&JudgeRole::Ok].doubleFound);
+        assert!(tables["2"].judges[&JudgeRole::Ak1].doubleFound);
+        assert!(!tables["1"].judges[&JudgeRole::Sk1].doubleFound);
+        assert!(!tables["2"].judges[&JudgeRole::Sk1].doubleFound);
+    }
+
+    #[test]
+    fn mark_conflicts_clears_resolved_conflicts() {
+        let mut tables = tables(vec![
+            table("1", false, &[(JudgeRole::Ok, "Anna Schmidt")]),
+            table("2", false, &[(JudgeRole::Ok, "Anna Schmidt")]),
+        ]);
+        mark_conflicts(&mut tables);
+        tables.get_mut("2").unwrap().judges.get_mut(&JudgeRole::Ok).unwrap().name = "Ben Weber".to_string();
+        mark_conflicts(&mut tables);
+
+        assert!(!tables["1"].judges[&JudgeRole::Ok].doubleFound);
+        assert!(!tables["2"].judges[&JudgeRole::Ok].doubleFound);
+    }
+
+    #[test]
+    fn preliminaries_and_finals_are_checked_separately() {
+        let mut tables = tables(vec![
+            table("1", false, &[(JudgeRole::Ok, "Anna Schmidt")]),
+            table("2", true, &[(JudgeRole::Ok, "Anna Schmidt")]),
+            table("3", true, &[(JudgeRole::Ok, ""), (JudgeRole::Sk1, "  ")]),
+            table("4", true, &[(JudgeRole::Ok, "")]),
+        ]);
+        mark_conflicts(&mut tables);
+
+        assert!(find_conflicts(&tables).is_empty());
+        assert!(tables.values().flat_map(|table| table.judges.values()).all(|judge| !judge.doubleFound));
+    }
+
+    #[test]
+    fn find_conflicts_lists_the_seats_sorted() {
+        let tables = tables(vec![
+            table("b", true, &[(JudgeRole::Sk2, "Anna Schmidt")]),
+            table("a", true, &[(JudgeRole::Sk1, "Anna Schmidt"), (JudgeRole::Ok, "Anna Schmidt")]),
+        ]);
+        let conflicts = find_conflicts(&tables);
+
+        assert_eq!(conflicts.len(), 1);
+        assert!(conflicts[0].is_finale);
+        assert_eq!(conflicts[0].judge_name, "Anna Schmidt");
+        let seats: Vec<(&str, &JudgeRole)> = conflicts[0].assignments.iter().map(|seat| (seat.table_name.as_str(), &seat.role)).collect();
+        assert_eq!(seats, vec![("KG a", &JudgeRole::Ok), ("KG a", &JudgeRole::Sk1), ("KG b", &JudgeRole::Sk2)]);
+    }
+
+    #[test]
+    fn check_before_generation_refuses_conflicts_unless_forced() {
+        let wettkampf = Wettkampf {
+            wk_judgingtables: tables(vec![
+                table("1", false, &[(JudgeRole::Ok, "Anna Schmidt")]),
+                table("2", false, &[(JudgeRole::Ok, "Anna Schmidt")]),
+            ]),
+            ..Wettkampf::default()
+        };
+
+        assert_eq!(check_before_generation(&wettkampf, false), ApplicationError::UnresolvedJudgeConflictsError);
+        assert_eq!(check_before_generation(&wettkampf, true), ApplicationError::NoError);
+    }
+
+    #[test]
+    fn check_before_generation_accepts_a_competition_without_conflicts() {
+        let wettkampf = Wettkampf {
+            wk_judgingtables: tables(vec![
+                table("1", false, &[(JudgeRole::Ok, "Anna Schmidt")]),
+                table("2", false, &[(JudgeRole::Ok, "Ben Weber")]),
+            ]),
+            ..Wettkampf::default()
+        };
+
+        assert_eq!(check_before_generation(&wettkampf, false), ApplicationError::NoError);
+        assert_eq!(check_before_generation(&Wettkampf::default(), false), ApplicationError::NoError);
+    }
+}
@@ -0,0 +1,185 @@
+// Headless command line interface for generating plans without a window.
+// Shares the data types and the plan generator with the GUI application.
+#![allow(unused_doc_comments)]
+#![allow(non_snake_case)]
+
+use dtb_kampfrichtereinsatzplaene_lib::types::{ApplicationError, Wettkampf};
+use dtb_kampfrichtereinsatzplaene_lib::DocxImpl::create_tables_docx;
+use dtb_kampfrichtereinsatzplaene_lib::Errors::DetailedError;
+use dtb_kampfrichtereinsatzplaene_lib::PdfImpl::create_tables_pdf;
+use dtb_kampfrichtereinsatzplaene_lib::{FileFormat, Logging, Validation, APP_VERSION};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+static USAGE: &str = "\
+Usage:
+    kre-cli generate --input <wk.json> [--docx <out.docx>] [--pdf <out.pdf>] [--force]
+    kre-cli check --input <wk.json>
+    kre-cli --help
+    kre-cli --version
+
+Commands:
+    generate    Creates the plans from a competition file saved by the app.
+                At least one of --docx or --pdf has to be given.
+                Refuses to run if judges are double-booked, unless --force is given.
+    check       Lists all double-booked judges of a competition file.
+                Exits with 1 if there are any.
+";
+
+/// Arguments of the 'generate' command.
+#[derive(Debug, Default)]
+struct GenerateArgs {
+    input: Option<PathBuf>,
+    docx: Option<PathBuf>,
+    pdf: Option<PathBuf>,
+    force: bool,
+}
+
+fn main() -> ExitCode {
+    // The shared modules report their problems through the log, which goes to the console here
+    Logging::init_console_logging();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(|arg| arg.as_str()) {
+        Some("generate") => {
+            let generate_args = match parse_generate_args(&args[1..]) {
+                Ok(generate_args) => generate_args,
+                Err(err) => {
+                    eprintln!("{err}\n\n{USAGE}");
+                    return ExitCode::from(2);
+                }
+            };
+            return generate(generate_args);
+        }
+        Some("check") => {
+            let input = match args.get(1..) {
+                Some([flag, input]) if flag == "--input" || flag == "-i" => PathBuf::from(input),
+                _ => {
+                    eprintln!("Usage: kre-cli check --input <wk.json>\n\n{USAGE}");
+                    return ExitCode::from(2);
+                }
+            };
+            return check(input);
+        }
+        Some("--version") | Some("-V") => {
+            println!("kre-cli (DTB Kampfrichtereinsatzpläne) v{APP_VERSION}");
+            return ExitCode::SUCCESS;
+        }
+        Some("--help") | Some("-h") | Some("help") => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Some(other) => {
+            eprintln!("Unknown command: {other}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+        None => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    }
+}
+
+fn parse_generate_args(args: &[String]) -> Result<GenerateArgs, String> {
+    let mut generate_args = GenerateArgs::default();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let target = match arg.as_str() {
+            "--force" => {
+                generate_args.force = true;
+                continue;
+            }
+            "--input" | "-i" => &mut generate_args.input,
+            "--docx" => &mut generate_args.docx,
+            "--pdf" => &mut generate_args.pdf,
+            other => return Err(format!("Unknown argument: {other}")),
+        };
+        match iter.next() {
+            Some(value) => *target = Some(PathBuf::from(value)),
+            None => return Err(format!("Missing value for argument: {arg}")),
+        }
+    }
+    if generate_args.input.is_none() {
+        return Err("Missing required argument: --input".to_string());
+    }
+    if generate_args.docx.is_none() && generate_args.pdf.is_none() {
+        return Err("Nothing to generate: Provide --docx and/or --pdf".to_string());
+    }
+    return Ok(generate_args);
+}
+
+/// Deserializes the file and migrates it to the current format.
+fn load_wettkampf(input: &Path) -> Option<Wettkampf> {
+    return match FileFormat::read_wk_file(input) {
+        Ok(wettkampf) => Some(wettkampf),
+        Err(err) if err.code() == ApplicationError::NewerFileFormatError => {
+            eprintln!("{} was written by a newer version of DTB Kampfrichtereinsatzpläne. Please update kre-cli.", input.display());
+            None
+        }
+        Err(err) => {
+            eprintln!("Could not load the competition file: {err}");
+            None
+        }
+    };
+}
+
+fn check(input: PathBuf) -> ExitCode {
+    let wettkampf = match load_wettkampf(&input) {
+        Some(wettkampf) => wettkampf,
+        None => return ExitCode::FAILURE,
+    };
+    let conflicts = Validation::find_conflicts(&wettkampf.wk_judgingtables);
+    if conflicts.is_empty() {
+        println!("No double-booked judges in {}", input.display());
+        return ExitCode::SUCCESS;
+    }
+    for conflict in &conflicts {
+        println!("{conflict}");
+    }
+    return ExitCode::FAILURE;
+}
+
+fn generate(args: GenerateArgs) -> ExitCode {
+    // Parsing guarantees that we have an input file
+    let input = args.input.unwrap();
+
+    let wettkampf = match load_wettkampf(&input) {
+        Some(wettkampf) => wettkampf,
+        None => return ExitCode::FAILURE,
+    };
+
+    // Same rule as in the app: No plans with double-booked judges, unless forced
+    match Validation::check_before_generation(&wettkampf, args.force) {
+        ApplicationError::NoError => {}
+        ApplicationError::UnresolvedJudgeConflictsError => {
+            eprintln!("Refusing to create plans with double-booked judges. Resolve them or pass --force.");
+            return ExitCode::FAILURE;
+        }
+        err => {
+            eprintln!("Could not check {}: {}", input.display(), DetailedError::new(err));
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if let Some(docx) = args.docx {
+        match create_tables_docx(&wettkampf, docx.clone()) {
+            ApplicationError::NoError => println!("Created {}", docx.display()),
+            err => {
+                eprintln!("Failed to create {}: {}", docx.display(), DetailedError::new(err));
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if let Some(pdf) = args.pdf {
+        match create_tables_pdf(&wettkampf, pdf.clone()) {
+            ApplicationError::NoError => println!("Created {}", pdf.display()),
+            err => {
+                eprintln!("Failed to create {}: {}", pdf.display(), DetailedError::new(err));
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    return ExitCode::SUCCESS;
+}
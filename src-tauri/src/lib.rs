@@ -0,0 +1,30 @@
+// The code shared by the app (main.rs) and the command line interface (bin/kre-cli.rs).
+#![allow(unused_doc_comments)]
+#![allow(non_snake_case)]
+#![warn(clippy::undocumented_unsafe_blocks)]
+
+pub mod Autosave;
+pub mod Diagnostics;
+pub mod Disciplines;
+pub mod Documents;
+pub mod DocxImpl;
+pub mod DocxReader;
+pub mod Errors;
+pub mod FileFormat;
+pub mod History;
+pub mod MailImpl;
+pub mod Logging;
+pub mod Outbox;
+pub mod types;
+pub mod PdfImpl;
+pub mod RecentFiles;
+pub mod Resources;
+pub mod Roster;
+pub mod Settings;
+pub mod Solver;
+pub mod Validation;
+
+// Statics
+pub static LLVM_VER: &str = env!("VERGEN_RUSTC_LLVM_VERSION");
+pub static TARGET_TRIPLE: &str = env!("VERGEN_CARGO_TARGET_TRIPLE");
+pub static APP_VERSION: &str = env!("CARGO_PKG_VERSION");
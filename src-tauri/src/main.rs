@@ -4,55 +4,51 @@
 #![allow(non_snake_case)]
 #![warn(clippy::undocumented_unsafe_blocks)]
 
-use crate::types::{
-    ApplicationError, FrontendStorage, Storage, UpdateAvailablePayload, UpdateProgressPayload,
+use dtb_kampfrichtereinsatzplaene_lib::types::{
+    ApplicationError, FrontendStorage, UpdateAvailablePayload, UpdateProgressPayload, Wettkampf,
 };
-use crate::MailImpl::{send_mail, MessageKind};
-use crate::FFI::{create_tables_docx, create_tables_pdf};
-use tauri::menu::{AboutMetadataBuilder, Menu, MenuBuilder, MenuItemBuilder, SubmenuBuilder};
+use dtb_kampfrichtereinsatzplaene_lib::Autosave::{find_restorable_autosaves, read_autosave, remove_autosave, write_autosave, AutosaveInfo};
+use dtb_kampfrichtereinsatzplaene_lib::Diagnostics::write_diagnostics;
+use dtb_kampfrichtereinsatzplaene_lib::Documents::{Document, DocumentRegistry};
+use dtb_kampfrichtereinsatzplaene_lib::Errors::DetailedError;
+use dtb_kampfrichtereinsatzplaene_lib::MailImpl::{current_log_attachment, MessageKind};
+use dtb_kampfrichtereinsatzplaene_lib::Outbox::{cancel_message, list_pending_messages, send_mail_unless_busy, send_mail_via_outbox, send_pending_messages, MailDelivery, PendingMessage, OUTBOX_CHECK_INTERVAL};
+use dtb_kampfrichtereinsatzplaene_lib::Disciplines::{discipline_schemas, DisciplineSchema, DISCIPLINES};
+use dtb_kampfrichtereinsatzplaene_lib::DocxImpl::create_tables_docx;
+use dtb_kampfrichtereinsatzplaene_lib::PdfImpl::create_tables_pdf;
+use dtb_kampfrichtereinsatzplaene_lib::RecentFiles::{add_recent_file, load_recent_files, remove_recent_file, RecentFile};
+use dtb_kampfrichtereinsatzplaene_lib::Roster::{read_roster, RosterJudge};
+use dtb_kampfrichtereinsatzplaene_lib::Solver::{assign_judges, SolverResult};
+use dtb_kampfrichtereinsatzplaene_lib::FileFormat::{read_latest_valid_backup, read_wk_file, write_wk_file};
+use dtb_kampfrichtereinsatzplaene_lib::Settings::{current_settings, store_settings, MAX_BACKUP_COUNT};
+use dtb_kampfrichtereinsatzplaene_lib::Validation::{check_before_generation, find_conflicts, JudgeConflict};
+use dtb_kampfrichtereinsatzplaene_lib::{Logging, Resources, Settings, APP_VERSION};
+use tauri::menu::{AboutMetadataBuilder, Menu, MenuBuilder, MenuItemBuilder, Submenu, SubmenuBuilder};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind, MessageDialogResult};
 use tauri_plugin_updater::UpdaterExt;
-use std::collections::HashMap;
-use std::fs::File;
 use std::panic::PanicHookInfo;
 use std::path::PathBuf;
 use std::process::{abort, Command};
 use std::sync::atomic::{AtomicBool, AtomicI8, AtomicU64, Ordering};
-use std::sync::{Arc, LazyLock};
 use std::{env, thread};
-use tauri::{AppHandle, Emitter, Manager, State, Wry};
+use tauri::{AppHandle, Emitter, Manager, State, WebviewWindow, Wry};
 use tokio::time::sleep;
 
-mod FFI;
-mod MailImpl;
-mod log;
-mod types;
-mod PrintToPdfImpl;
-
-// Force PlatformWebview to be Send
-struct PlatformWebViewWrapper {
-    inner: Option<tauri::webview::PlatformWebview>
-}
-unsafe impl Send for PlatformWebViewWrapper {}
-
 // Statics
-static mut SAVE_PATH: Option<String> = None;
-static LLVM_VER: &'static str = env!("VERGEN_RUSTC_LLVM_VERSION");
-static TARGET_TRIPLE: &'static str = env!("VERGEN_CARGO_TARGET_TRIPLE");
-static APP_VERSION: &'static str = env!("CARGO_PKG_VERSION");
-static mut STDOUT_FILE: Option<String> = None;
-static mut STDERR_FILE: Option<String> = None;
+static CLOSE_SAVE: &str = "Speichern";
+static CLOSE_DISCARD: &str = "Nicht speichern";
+static CLOSE_CANCEL: &str = "Abbrechen";
+/// Windows users expect Ctrl+Y, the Editor also takes Ctrl+Shift+Z there. The other systems use Shift+Z.
+#[cfg(target_os = "windows")]
+static REDO_ACCELERATOR: &str = "Ctrl+Y";
+#[cfg(not(target_os = "windows"))]
+static REDO_ACCELERATOR: &str = "CmdOrCtrl+Shift+Z";
 static mut MAINWINDOW_LOADED: AtomicBool = AtomicBool::new(false);
 static mut UPDATE_REQUESTED: AtomicI8 = AtomicI8::new(0);
 static mut UPDATE_PROGRESS: AtomicU64 = AtomicU64::new(0);
-static PLATFORM_WEBVIEW: LazyLock<Arc<tokio::sync::Mutex<PlatformWebViewWrapper>>> = LazyLock::new(|| {
-    Arc::new(
-        tokio::sync::Mutex::new(
-            PlatformWebViewWrapper {
-                inner: None
-            }
-        )
-    )
-});
+/// Set while quitting waits for the user to decide about the unsaved changes, see RunEvent::ExitRequested.
+/// Cancelling any of the questions clears it, so the app keeps running.
+static QUIT_PENDING: AtomicBool = AtomicBool::new(false);
 
 #[tauri::command]
 fn update_mainwindow_loading_state(visible: bool) {
@@ -63,121 +59,68 @@ fn update_mainwindow_loading_state(visible: bool) {
 }
 
 // MARK: Func: Update Storage Data
-/// Function to update the global storage from the frontend.
+/// Function to update the document of the calling editor window from the frontend.
 /// Param 1: The frontend storage struct provided by Javascript
-/// Param 2: The managed Storage state object provided by Tauri.
-/// Returns: An ApplicationError to be handled by the frontend.
+/// Param 2: The managed DocumentRegistry state object provided by Tauri.
+/// Param 3: The calling window, provided by Tauri.
+/// Returns: The error to be shown by the frontend, if the document could not be updated.
 #[tauri::command]
 fn update_storage_data(
     frontend_storage: FrontendStorage,
-    storage: State<Storage>,
-) -> ApplicationError {
-    // Update all data. First, lock the relevant object and then update it.
-    // Immediately after drop the lock.
-
-    // For wk_name
-    match storage.wk_name.lock() {
-        Ok(mut guard) => {
-            *guard = frontend_storage.wk_name;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return ApplicationError::MutexPoisonedError;
-        }
-    };
-
-    // For wk_date
-    match storage.wk_date.lock() {
-        Ok(mut guard) => {
-            *guard = frontend_storage.wk_date;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return ApplicationError::MutexPoisonedError;
-        }
-    };
-
-    // For wk_responsible_person
-    match storage.wk_responsible_person.lock() {
-        Ok(mut guard) => {
-            *guard = frontend_storage.wk_responsible_person;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return ApplicationError::MutexPoisonedError;
-        }
-    };
-
-    // For wk_judgesmeeting_time
-    match storage.wk_judgesmeeting_time.lock() {
-        Ok(mut guard) => {
-            *guard = frontend_storage.wk_judgesmeeting_time;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return ApplicationError::MutexPoisonedError;
-        }
-    };
-
-    // For wk_replacement_judges
-    match storage.wk_replacement_judges.lock() {
-        Ok(mut guard) => {
-            *guard = frontend_storage.wk_replacement_judges.unwrap();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return ApplicationError::MutexPoisonedError;
-        }
-    };
-
-    // For wk_judgingtables
-    match storage.wk_judgingtables.lock() {
-        Ok(mut guard) => {
-            *guard = frontend_storage.wk_judgingtables.unwrap();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return ApplicationError::MutexPoisonedError;
-        }
+    documents: State<DocumentRegistry>,
+    window: WebviewWindow,
+) -> Result<(), DetailedError> {
+    let result = documents.apply_frontend(window.label(), frontend_storage);
+    refresh_window_title(&window, &documents);
+    return match result {
+        Ok(_) => Ok(()),
+        Err(err) => Err(err.into()),
     };
-
-    // No error occured, return NoError variant.
-    return ApplicationError::NoError;
 }
 
 // MARK: Func: Create Wettkampf Window
 /// Tauri Command for creating a window that creates a new Wettkampf
 #[tauri::command]
-async fn create_wettkampf(app_handle: AppHandle) -> ApplicationError {
+async fn create_wettkampf(app_handle: AppHandle) -> Result<(), DetailedError> {
+    return match open_create_wettkampf_window(&app_handle) {
+        ApplicationError::NoError => Ok(()),
+        err => Err(err.into()),
+    };
+}
+
+/// Opens the window for creating a new Wettkampf, or focuses it if it is already open.
+/// Returns: An ApplicationError to be handled by the frontend.
+fn open_create_wettkampf_window(app_handle: &AppHandle) -> ApplicationError {
+    if let Some(window) = app_handle.get_webview_window("createWettkampf") {
+        match window.set_focus() {
+            Ok(()) => {}
+            Err(err) => log::error!("Could not focus the CreateWettkampf window: {:?}", err),
+        }
+        return ApplicationError::NoError;
+    }
 
     let create_wettkampf_window = match tauri::WebviewWindowBuilder::new(
-        &app_handle,
+        app_handle,
         "createWettkampf",
         tauri::WebviewUrl::App(PathBuf::from("createWettkampf.html"))
     )
     .inner_size(515.0, 600.0)
     .title("Wettkampf erstellen")
     .focused(true)
-    .menu(build_menus(MenuKind::CreateWettkampf, &app_handle))
+    .menu(build_menus(MenuKind::CreateWettkampf, app_handle))
     .center()
     .build()
     {
         Ok(window) => window,
         Err(err) => {
-            eprintln!("Could not build CreateWettkampf window: {:?}", err);
+            log::error!("Could not build CreateWettkampf window: {:?}", err);
             return ApplicationError::TauriWindowCreationError;
         }
     };
     match create_wettkampf_window.show() {
         Ok(()) => {}
         Err(err) => {
-            eprintln!("Could not show CreateWettkampf window: {:?}", err);
+            log::error!("Could not show CreateWettkampf window: {:?}", err);
             return ApplicationError::TauriWindowShowError;
         }
     }
@@ -187,776 +130,956 @@ async fn create_wettkampf(app_handle: AppHandle) -> ApplicationError {
 // MARK: Func: Sync WK Data and open Editor
 /// Syncs the initial WK data and hands off to the GUI WK Editor.
 /// Param 1: FrontendStorage data struct (provided by the Frontend) through serde::Deserialize
-/// Param 2: State<'_ Storage> global storage provided by Tauri.
-/// Returns: A Result always containing an Ok(ApplicationError) value - never void or something went terribly wrong.
+/// Param 2: State<'_ DocumentRegistry> document registry provided by Tauri.
+/// Returns: The error to be shown by the frontend, if the editor could not be opened.
 /// CAVEATS: async functions cannot simply use borrowed data like State<T>, so we need the anonymous lifetime specifier "'_" and have to return a Result.
 #[tauri::command]
 async fn sync_wk_data_and_open_editor(
     data: FrontendStorage,
-    storage: State<'_, Storage>,
+    documents: State<'_, DocumentRegistry>,
     app_handle: AppHandle,
-) -> Result<ApplicationError, ()> {
-    match storage.wk_name.lock() {
-        Ok(mut guard) => {
-            *guard = data.wk_name.clone();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
-
-    match storage.wk_place.lock() {
-        Ok(mut guard) => {
-            *guard = data.wk_place;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
-
-    match storage.wk_date.lock() {
-        Ok(mut guard) => {
-            *guard = data.wk_date;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
-
-    match storage.wk_judgesmeeting_time.lock() {
-        Ok(mut guard) => {
-            *guard = data.wk_judgesmeeting_time;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
-
-    match storage.wk_responsible_person.lock() {
-        Ok(mut guard) => {
-            *guard = data.wk_responsible_person;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
+) -> Result<(), DetailedError> {
+    // A new Wettkampf starts empty and gets its own window, the other open ones are not touched
+    let mut wettkampf = Wettkampf::default();
+    wettkampf.apply_frontend(data);
+    let document = Document::new(wettkampf, None);
+    let title = document.title();
+    let label = documents.open(document);
+
+    let result = open_editor_window(&app_handle, &label, title);
+    if result != ApplicationError::NoError {
+        documents.close(&label);
+        return Err(result.into());
+    }
+    return Ok(());
+}
 
-    // Create the Editor Window
+/// Creates the editor window of a document.
+/// Param 2: The label the document was registered with
+/// Returns: An ApplicationError to be handled by the frontend.
+fn open_editor_window(app_handle: &AppHandle, label: &str, title: String) -> ApplicationError {
     let editor_window = match tauri::WebviewWindowBuilder::new(
-        &app_handle,
-        "editor",
+        app_handle,
+        label,
         tauri::WebviewUrl::App(PathBuf::from("editor.html")),
     )
     .inner_size(1250.0, 800.0)
-    .title(format!["{} (nicht gespeichert)", data.wk_name])
+    .title(title)
     .focused(true)
-    .menu(build_menus(MenuKind::Editor, &app_handle))
+    .menu(build_menus(MenuKind::Editor, app_handle))
     .center()
     .build()
     {
         Ok(window) => window,
         Err(err) => {
-            eprintln!("Could not build the Editor window: {:?}", err);
-            return Ok(ApplicationError::TauriWindowCreationError);
+            log::error!("Could not build the Editor window: {:?}", err);
+            return ApplicationError::TauriWindowCreationError;
         }
     };
     match editor_window.show() {
         Ok(()) => {}
         Err(err) => {
-            eprintln!("Could not show the Editor window: {:?}", err);
-            return Ok(ApplicationError::TauriWindowShowError);
+            log::error!("Could not show the Editor window: {:?}", err);
+            return ApplicationError::TauriWindowShowError;
         }
     }
-
-    return Ok(ApplicationError::NoError);
+    return ApplicationError::NoError;
 }
 
+/// Returns: The Wettkampf of the calling editor window and where it was saved, if it was.
 #[tauri::command]
 async fn get_wk_data_to_frontend(
-    storage: State<'_, Storage>,
-) -> Result<(FrontendStorage, Option<String>), ApplicationError> {
-    let mut frontend_storage = FrontendStorage::default();
-    match storage.wk_name.lock() {
-        Ok(guard) => {
-            frontend_storage.wk_name = (*guard).clone();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Err(ApplicationError::MutexPoisonedError);
-        }
-    }
-
-    match storage.wk_place.lock() {
-        Ok(guard) => {
-            frontend_storage.wk_place = (*guard).clone();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Err(ApplicationError::MutexPoisonedError);
-        }
-    }
-
-    match storage.wk_date.lock() {
-        Ok(guard) => {
-            frontend_storage.wk_date = (*guard).clone();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Err(ApplicationError::MutexPoisonedError);
-        }
-    }
+    documents: State<'_, DocumentRegistry>,
+    window: WebviewWindow,
+) -> Result<(FrontendStorage, Option<String>), DetailedError> {
+    let document = documents.get(window.label())?;
+    let save_path = document.save_path.map(|path| path.to_string_lossy().to_string());
+    return Ok((document.wettkampf.to_frontend(), save_path));
+}
 
-    match storage.wk_judgesmeeting_time.lock() {
-        Ok(guard) => {
-            frontend_storage.wk_judgesmeeting_time = (*guard).clone();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Err(ApplicationError::MutexPoisonedError);
-        }
-    }
+/// Returns the role schema of every discipline, so the frontend does not have to hardcode them
+#[tauri::command]
+fn get_discipline_schemas() -> Vec<DisciplineSchema> {
+    return discipline_schemas();
+}
 
-    match storage.wk_responsible_person.lock() {
-        Ok(guard) => {
-            frontend_storage.wk_responsible_person = (*guard).clone();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Err(ApplicationError::MutexPoisonedError);
-        }
-    }
+/// Returns all double-booked judges of the given (unsaved) frontend data
+#[tauri::command]
+fn get_judge_conflicts(frontendstorage: FrontendStorage) -> Vec<JudgeConflict> {
+    return match frontendstorage.wk_judgingtables {
+        Some(tables) => find_conflicts(&tables),
+        None => Vec::new(),
+    };
+}
 
-    match storage.wk_replacement_judges.lock() {
-        Ok(guard) => {
-            if guard.is_empty() {
-                frontend_storage.wk_replacement_judges = Some(Vec::new());
-            } else {
-                frontend_storage.wk_replacement_judges = Some((*guard).clone());
-            }
-        }
+// MARK: Func: Judge Roster
+/// Imports a judge list exported from GymNet and replaces the roster of the Wettkampf of the calling window.
+/// Param 1: Path of the .csv or .xlsx file
+/// Returns: The imported judges, so the editor can offer them for autocompletion.
+#[tauri::command]
+fn import_judge_roster(
+    filepath: String,
+    documents: State<DocumentRegistry>,
+    window: WebviewWindow,
+) -> Result<Vec<RosterJudge>, DetailedError> {
+    let roster = match read_roster(&PathBuf::from(&filepath)) {
+        Ok(roster) => roster,
         Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Err(ApplicationError::MutexPoisonedError);
+            let err = DetailedError::new(ApplicationError::RosterImportError).with_path(&filepath).caused_by_text(err);
+            log::error!("Failed to import the judge roster: {err}");
+            return Err(err);
         }
-    }
+    };
+    documents.update(window.label(), |document| {
+        document.change(|wettkampf| wettkampf.wk_judge_roster = roster.clone());
+    })?;
+    refresh_window_title(&window, &documents);
+    return Ok(roster);
+}
 
-    match storage.wk_judgingtables.lock() {
-        Ok(guard) => {
-            if guard.is_empty() {
-                frontend_storage.wk_judgingtables = Some(HashMap::new());
-            } else {
-                frontend_storage.wk_judgingtables = Some((*guard).clone());
-            }
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Err(ApplicationError::MutexPoisonedError);
-        }
-    }
+/// Returns: The judge roster of the Wettkampf of the calling window, empty if none was imported.
+#[tauri::command]
+fn get_judge_roster(documents: State<DocumentRegistry>, window: WebviewWindow) -> Result<Vec<RosterJudge>, DetailedError> {
+    return Ok(documents.get(window.label())?.wettkampf.wk_judge_roster);
+}
 
-    unsafe { return Ok((frontend_storage, SAVE_PATH.clone())) };
+/// Fills the empty judge slots of the given (unsaved) frontend data with judges of the roster.
+/// Param 2: Seed for breaking ties, the same seed always gives the same assignment
+/// Returns: The filled tables, the new replacement judges and every slot that stayed empty.
+#[tauri::command]
+fn auto_assign_judges(
+    frontendstorage: FrontendStorage,
+    seed: u64,
+    documents: State<DocumentRegistry>,
+    window: WebviewWindow,
+) -> Result<SolverResult, DetailedError> {
+    let roster = documents.get(window.label())?.wettkampf.wk_judge_roster;
+    return Ok(assign_judges(
+        &frontendstorage.wk_judgingtables.unwrap_or_default(),
+        &frontendstorage.wk_replacement_judges.unwrap_or_default(),
+        &roster,
+        seed,
+    ));
 }
 
 #[tauri::command]
 async fn sync_to_backend_and_save(
     frontendstorage: FrontendStorage,
     filepath: String,
-    storage: State<'_, Storage>,
-) -> Result<ApplicationError, ()> {
-    match storage.wk_name.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_name.clone();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
-
-    match storage.wk_place.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_place;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
-
-    match storage.wk_date.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_date;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
-
-    match storage.wk_judgesmeeting_time.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_judgesmeeting_time;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
-
-    match storage.wk_responsible_person.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_responsible_person;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
+    documents: State<'_, DocumentRegistry>,
+    window: WebviewWindow,
+) -> Result<(), DetailedError> {
+    documents.apply_frontend(window.label(), frontendstorage)?;
+    let result = save_document(window.app_handle(), &documents, window.label(), std::path::Path::new(&filepath));
+    refresh_window_title(&window, &documents);
+    return result;
+}
 
-    match storage.wk_replacement_judges.lock() {
-        Ok(mut guard) => {
-            *guard = match frontendstorage.wk_replacement_judges {
-                Some(map) => map,
-                None => Vec::new(),
-            };
-            drop(guard);
-        }
+// MARK: Func: Save Document
+/// Writes the Wettkampf of a document to disk and remembers the path, also in the recent files.
+/// Param 2: The document registry
+/// Param 3: The label of the window of the document
+/// Param 4: Where to save the file
+/// Returns: The error to be shown by the frontend, if the file could not be saved.
+fn save_document(app_handle: &AppHandle, documents: &DocumentRegistry, label: &str, filepath: &std::path::Path) -> Result<(), DetailedError> {
+    let document = documents.get(label)?;
+    let (wettkampf, position) = (document.wettkampf, document.history.position());
+
+    // Write file at path, keeping the previous version as backup!
+    match write_wk_file(filepath, &wettkampf, current_settings().backup_count) {
+        Ok(()) => {}
         Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+            log::error!("Could not save the Wettkampf: {err}");
+            return Err(err);
         }
     }
 
-    match storage.wk_judgingtables.lock() {
-        Ok(mut guard) => {
-            *guard = match frontendstorage.wk_judgingtables {
-                Some(map) => map,
-                None => HashMap::new(),
-            };
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
+    // Remember where this was saved. Changes made while writing are not in the file, so they stay unsaved
+    let saved = documents.update(label, |document| {
+        document.save_path = Some(filepath.to_path_buf());
+        document.saved_position = Some(position);
+        return document.clone();
+    });
+    let document = saved?;
+    remember_recent_file(app_handle, filepath, &wettkampf);
+    // The autosave is only needed as long as there are unsaved changes
+    if !document.is_dirty() {
+        remove_autosave(&document.autosave_id);
     }
+    return Ok(());
+}
 
-    // Serialize data
-    let serialized_data = match serde_json::to_string(storage.inner()) {
-        Ok(data) => data,
-        Err(err) => {
-            eprintln!("Failed to serialize storage data: {:?}", err);
-            return Ok(ApplicationError::JSONSerializeError);
-        }
+/// Sets the title of an editor window to the name, file and save state of its document.
+fn refresh_window_title(window: &WebviewWindow, documents: &DocumentRegistry) {
+    let document = match documents.get(window.label()) {
+        Ok(document) => document,
+        Err(_) => return,
     };
-
-    // Write file at path!
-    match std::fs::write(filepath, serialized_data) {
+    match window.set_title(&document.title()) {
         Ok(()) => {}
-        Err(err) => {
-            eprintln!("Failed to write serialized wk data to file: {:?}", err);
-            return Ok(ApplicationError::RustWriteFileError);
-        }
+        Err(err) => log::error!("Could not set the title of the Editor window: {:?}", err),
     }
-
-    return Ok(ApplicationError::NoError);
 }
 
-/// Function to sync all stuff and create the plans using FFI
+/// Function to sync all stuff and create the plans
 #[tauri::command]
 async fn sync_to_backend_and_create_docx(
     frontendstorage: FrontendStorage,
     filepath: String,
-    storage: State<'_, Storage>,
-) -> Result<ApplicationError, ()> {
-    match storage.wk_name.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_name.clone();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
+    force: bool,
+    documents: State<'_, DocumentRegistry>,
+    window: WebviewWindow,
+) -> Result<(), DetailedError> {
+    let wettkampf = documents.apply_frontend(window.label(), frontendstorage)?;
+    refresh_window_title(&window, &documents);
+
+    // Refuse to create plans with double-booked judges, unless the user accepted them
+    match check_before_generation(&wettkampf, force) {
+        ApplicationError::NoError => {}
+        err => return Err(err.into()),
+    }
+
+    return match create_tables_docx(&wettkampf, PathBuf::from(&filepath)) {
+        ApplicationError::NoError => Ok(()),
+        err => Err(DetailedError::new(err).with_path(&filepath)),
+    };
+}
 
-    match storage.wk_place.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_place;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
+/// Function to sync all stuff and create the plans as PDF
+#[tauri::command]
+async fn sync_to_backend_and_create_pdf(
+    frontendstorage: FrontendStorage,
+    filepath: String,
+    force: bool,
+    documents: State<'_, DocumentRegistry>,
+    window: WebviewWindow,
+) -> Result<(), DetailedError> {
+    let wettkampf = documents.apply_frontend(window.label(), frontendstorage)?;
+    refresh_window_title(&window, &documents);
+
+    // Refuse to create plans with double-booked judges, unless the user accepted them
+    match check_before_generation(&wettkampf, force) {
+        ApplicationError::NoError => {}
+        err => return Err(err.into()),
+    }
+
+    return match create_tables_pdf(&wettkampf, PathBuf::from(&filepath)) {
+        ApplicationError::NoError => Ok(()),
+        err => Err(DetailedError::new(err).with_path(&filepath)),
+    };
+}
 
-    match storage.wk_date.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_date;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+// Function for loading a file from disk into a new document
+// Then open the editor, or focus the editor it is already open in
+#[tauri::command]
+async fn import_wk_file_and_open_editor(filepath: String, app_handle: AppHandle) -> Result<(), DetailedError> {
+    // Opening a corrupt file asks for its backup with a blocking dialog, which must not hold up a worker of the async runtime
+    let handle = app_handle.clone();
+    let opened = tauri::async_runtime::spawn_blocking(move || {
+        return open_wk_file(&handle, &handle.state::<DocumentRegistry>(), PathBuf::from(&filepath));
+    });
+    return match opened.await {
+        Ok(result) => result,
+        Err(err) => {
+            log::error!("Opening the Wettkampf did not finish: {:?}", err);
+            Err(DetailedError::new(ApplicationError::UnknownError).caused_by(&err))
         }
-    }
+    };
+}
 
-    match storage.wk_judgesmeeting_time.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_judgesmeeting_time;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+/// Opens a Wettkampf file in a new editor window, or focuses the window it is already open in.
+/// Blocks while asking for the backup of a corrupt file, so it must neither run on the main thread nor in the async runtime.
+/// Returns: The error to be shown by the frontend, if the file could not be opened.
+fn open_wk_file(app_handle: &AppHandle, documents: &DocumentRegistry, path: PathBuf) -> Result<(), DetailedError> {
+    // Opening the same file twice would let the two windows overwrite each other's changes
+    if let Some(label) = documents.find_by_path(&path) {
+        if let Some(window) = app_handle.get_webview_window(&label) {
+            match window.set_focus() {
+                Ok(()) => {}
+                Err(err) => log::error!("Could not focus the Editor window: {:?}", err),
+            }
+            return Ok(());
         }
     }
 
-    match storage.wk_responsible_person.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_responsible_person;
-            drop(guard);
-        }
+    // Deserialize the file and migrate it to the current format
+    let document = match read_wk_file(&path) {
+        Ok(wettkampf) => Document::new(wettkampf, Some(path.clone())),
         Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+            log::error!("Could not open the Wettkampf: {err}");
+            // The file is corrupt (e.g. truncated), offer the last version that can still be read
+            if err.code() != ApplicationError::JSONDeserializeImporterError {
+                return Err(err);
+            }
+            match offer_backup(app_handle, &path) {
+                Some(wettkampf) => {
+                    // The backup differs from the corrupt file on disk, saving it repairs the file
+                    let mut document = Document::new(wettkampf, Some(path.clone()));
+                    document.saved_position = None;
+                    document
+                }
+                None => return Err(err),
+            }
         }
-    }
+    };
 
-    match storage.wk_replacement_judges.lock() {
-        Ok(mut guard) => {
-            *guard = match frontendstorage.wk_replacement_judges {
-                Some(map) => map,
-                None => Vec::new(),
-            };
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
+    // Register the document together with its path, so we know where this was saved!
+    let title = document.title();
+    let wettkampf = document.wettkampf.clone();
+    let label = documents.open(document);
+
+    // Open the Editor!
+    let result = open_editor_window(app_handle, &label, title);
+    if result != ApplicationError::NoError {
+        documents.close(&label);
+        return Err(result.into());
     }
+    remember_recent_file(app_handle, &path, &wettkampf);
+    return Ok(());
+}
 
-    match storage.wk_judgingtables.lock() {
-        Ok(mut guard) => {
-            *guard = match frontendstorage.wk_judgingtables {
-                Some(map) => map,
-                None => HashMap::new(),
-            };
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+/// Asks whether the most recent readable backup of a corrupt competition file should be opened instead.
+/// Returns: The competition of the backup, None if there is no backup or the user declined.
+fn offer_backup(app_handle: &AppHandle, path: &std::path::Path) -> Option<Wettkampf> {
+    // The backups of earlier saves are still there, even if fewer are kept now
+    let (backup, wettkampf) = read_latest_valid_backup(path, MAX_BACKUP_COUNT)?;
+    let backup_date = match std::fs::metadata(&backup).and_then(|metadata| metadata.modified()) {
+        Ok(modified) => chrono::DateTime::<chrono::Local>::from(modified).format(" vom %d.%m.%Y %H:%M").to_string(),
+        Err(_) => String::new(),
+    };
+    let open_backup = app_handle
+        .dialog()
+        .message(format!(
+            "Die Datei \"{}\" ist beschädigt und kann nicht geöffnet werden. Es gibt aber eine Sicherung{backup_date}.\n\nSoll stattdessen die Sicherung geöffnet werden? Beim nächsten Speichern ersetzt sie die beschädigte Datei.",
+            path.display()
+        ))
+        .title("Datei beschädigt")
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom("Sicherung öffnen".to_string(), "Abbrechen".to_string()))
+        .blocking_show();
+    return if open_backup { Some(wettkampf) } else { None };
+}
+
+// MARK: Func: Autosave
+/// Writes the autosave of every document with unsaved changes, see Autosave.rs.
+fn autosave_documents(documents: &DocumentRegistry) {
+    for document in documents.dirty_documents() {
+        match write_autosave(&document.autosave_id, &document.wettkampf, document.save_path.as_deref()) {
+            Ok(()) => {}
+            Err(err) => log::error!("Autosave of {} failed: {err}", document.wettkampf.wk_name),
         }
     }
+}
 
-    return Ok(create_tables_docx(storage.inner(), PathBuf::from(filepath)).unwrap());
+/// Returns: The autosaves of a previous run that are newer than their files, so the main window can offer to restore them.
+#[tauri::command]
+fn get_restorable_autosaves() -> Vec<AutosaveInfo> {
+    return find_restorable_autosaves();
 }
 
-// Windows requires this function to run asynchronously!
-#[cfg(target_os = "macos")]
+/// Opens an autosave in a new editor window. The restored document counts as changed until it is saved.
+/// Param 1: The id of the autosave, as returned by get_restorable_autosaves
 #[tauri::command]
-async fn sync_to_backend_and_create_pdf(
-    frontendstorage: FrontendStorage,
-    filepath: String,
-    storage: State<'_, Storage>,
-    app_handle: AppHandle
-) -> Result<ApplicationError, ()> {
-    match storage.wk_name.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_name.clone();
-            drop(guard);
-        }
+async fn restore_autosave(
+    id: String,
+    documents: State<'_, DocumentRegistry>,
+    app_handle: AppHandle,
+) -> Result<(), DetailedError> {
+    let (wettkampf, save_path) = match read_autosave(&id) {
+        Ok(autosave) => autosave,
         Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+            log::error!("Could not restore the autosave {id}: {err}");
+            return Err(err);
         }
-    }
+    };
+    let document = Document::restored(wettkampf, save_path, id);
+    let title = document.title();
+    let label = documents.open(document);
 
-    match storage.wk_place.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_place;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
+    let result = open_editor_window(&app_handle, &label, title);
+    if result != ApplicationError::NoError {
+        // Keep the autosave, so restoring can be tried again on the next start
+        documents.close(&label);
+        return Err(result.into());
     }
+    return Ok(());
+}
 
-    match storage.wk_date.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_date;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
+/// Deletes an autosave the user does not want to restore.
+#[tauri::command]
+fn discard_autosave(id: String) {
+    remove_autosave(&id);
+}
 
-    match storage.wk_judgesmeeting_time.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_judgesmeeting_time;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
+// MARK: Func: Undo / Redo
+/// Reverts the last change of the Wettkampf of the calling window, see History.rs.
+/// Returns: The description of the reverted change, None if there was nothing to undo.
+#[tauri::command]
+fn undo_edit(documents: State<DocumentRegistry>, window: WebviewWindow) -> Result<Option<String>, DetailedError> {
+    return Ok(step_history(&window, &documents, true)?);
+}
 
-    match storage.wk_responsible_person.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_responsible_person;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
+/// Applies the last undone change of the Wettkampf of the calling window again.
+/// Returns: The description of the change, None if there was nothing to redo.
+#[tauri::command]
+fn redo_edit(documents: State<DocumentRegistry>, window: WebviewWindow) -> Result<Option<String>, DetailedError> {
+    return Ok(step_history(&window, &documents, false)?);
+}
 
-    match storage.wk_replacement_judges.lock() {
-        Ok(mut guard) => {
-            *guard = match frontendstorage.wk_replacement_judges {
-                Some(map) => map,
-                None => Vec::new(),
-            };
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+/// Undoes or redoes a step and sends the resulting Wettkampf to the editor with the wkDataChanged event.
+fn step_history(window: &WebviewWindow, documents: &DocumentRegistry, undo: bool) -> Result<Option<String>, ApplicationError> {
+    let (description, wettkampf) = documents.update(window.label(), |document| {
+        let description = if undo {
+            document.history.undo(&mut document.wettkampf)
+        } else {
+            document.history.redo(&mut document.wettkampf)
+        };
+        return (description, document.wettkampf.clone());
+    })?;
+    if description.is_some() {
+        match window.emit_to(window.label(), "wkDataChanged", wettkampf.to_frontend()) {
+            Ok(()) => {}
+            Err(err) => log::error!("Failed to emit wkDataChanged to the Editor window: {:?}", err),
         }
+        refresh_window_title(window, documents);
     }
+    return Ok(description.map(|description| description.to_string()));
+}
 
-    match storage.wk_judgingtables.lock() {
-        Ok(mut guard) => {
-            *guard = match frontendstorage.wk_judgingtables {
-                Some(map) => map,
-                None => HashMap::new(),
-            };
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
+/// Returns: The focused editor window, which the Edit menu refers to. On macOS all windows share one menu.
+fn focused_editor_window(app_handle: &AppHandle) -> Option<WebviewWindow> {
+    let documents = app_handle.state::<DocumentRegistry>();
+    return app_handle
+        .webview_windows()
+        .into_values()
+        .find(|window| documents.contains(window.label()) && window.is_focused().unwrap_or(false));
+}
 
-    // Process backend library docx and html
-    let library_code = create_tables_pdf(storage.inner(), PathBuf::from(filepath.clone())).unwrap();
+// MARK: Func: Recent Files
+/// Returns: The recently opened and saved Wettkampf files, newest first, for the main window.
+#[tauri::command]
+fn get_recent_files() -> Vec<RecentFile> {
+    return load_recent_files();
+}
 
-    // Check if this succeeded.
-    if library_code != ApplicationError::NoError {
-        return Ok(library_code);
-    }
+/// Puts a file at the top of the recent files and updates the Open Recent menus.
+fn remember_recent_file(app_handle: &AppHandle, path: &std::path::Path, wettkampf: &Wettkampf) {
+    add_recent_file(path, wettkampf);
+    refresh_recent_files_menus(app_handle);
+}
 
-    // Fetch the generated html file
-    let generated_html = filepath.clone().replace(".pdf", "_temp.html");
-    let generated_docx = filepath.clone().replace(".pdf", "_temp.docx");
+/// Rebuilds the Open Recent submenu of every window, as the menus are only built with their window.
+fn refresh_recent_files_menus(app_handle: &AppHandle) {
+    let mut menus: Vec<Menu<Wry>> = app_handle
+        .webview_windows()
+        .values()
+        .filter_map(|window| window.menu())
+        .collect();
+    menus.extend(app_handle.menu());
+    for menu in menus {
+        let recent_submenu = menu
+            .get("fileMenu")
+            .and_then(|item| item.as_submenu().cloned())
+            .and_then(|file_submenu| file_submenu.get("recentFiles"))
+            .and_then(|item| item.as_submenu().cloned());
+        if let Some(recent_submenu) = recent_submenu {
+            fill_recent_files_submenu(&recent_submenu, app_handle);
+        }
+    }
+}
 
-    // Get the shared PDF View
-    let platform_webview = PLATFORM_WEBVIEW.lock().await;
-    let errno = platform_webview.print_pdf(PathBuf::from(generated_html.clone()), PathBuf::from(filepath.clone()), app_handle, generated_docx, generated_html);
+/// Replaces the entries of an Open Recent submenu with the current recent files.
+fn fill_recent_files_submenu(submenu: &Submenu<Wry>, app_handle: &AppHandle) {
+    while let Ok(Some(_)) = submenu.remove_at(0) {}
 
-    if errno != ApplicationError::NoError {
-        return Ok(errno);
+    let recent_files = load_recent_files();
+    let items = if recent_files.is_empty() {
+        vec![MenuItemBuilder::new("Keine Einträge").enabled(false).build(app_handle)]
+    } else {
+        recent_files
+            .iter()
+            .map(|recent_file| {
+                let file_name = recent_file.path.file_name().unwrap_or_default().to_string_lossy().to_string();
+                let text = if recent_file.wk_name.is_empty() {
+                    file_name
+                } else {
+                    format!("{} - {}", recent_file.wk_name, file_name)
+                };
+                return MenuItemBuilder::new(text)
+                    .id(format!("openRecent:{}", recent_file.path.to_string_lossy()))
+                    .build(app_handle);
+            })
+            .collect()
+    };
+    for item in items {
+        match item.and_then(|item| submenu.append(&item)) {
+            Ok(()) => {}
+            Err(err) => log::error!("Could not add an entry to the recent files menu: {:?}", err),
+        }
     }
+}
 
-    return Ok(ApplicationError::NoError);
+// MARK: Func: Settings
+/// Returns: The settings in use, for the settings window and the dialogs of the frontend.
+#[tauri::command]
+fn get_settings() -> Settings::Settings {
+    return current_settings();
 }
 
-// Windows requires this function to run synchronously!
-#[cfg(target_os = "windows")]
+/// Returns: The settings of a fresh installation, so the settings window can reset them.
 #[tauri::command]
-fn sync_to_backend_and_create_pdf(
-    frontendstorage: FrontendStorage,
-    filepath: String,
-    storage: State<'_, Storage>,
-    app_handle: AppHandle
-) -> Result<ApplicationError, ()> {
-    match storage.wk_name.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_name.clone();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
+fn get_default_settings() -> Settings::Settings {
+    return Settings::Settings::default();
+}
 
-    match storage.wk_place.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_place;
-            drop(guard);
-        }
+/// Validates the settings from the settings window and uses them from now on, see Settings.rs.
+/// Returns: The error naming the invalid setting, to be shown by the settings window.
+#[tauri::command]
+fn update_settings(settings: Settings::Settings) -> Result<(), DetailedError> {
+    return match store_settings(settings) {
+        Ok(()) => Ok(()),
         Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+            log::error!("Could not store the settings: {err}");
+            Err(err)
         }
-    }
+    };
+}
 
-    match storage.wk_date.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_date;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
+/// Opens the settings window, or focuses it if it is already open.
+fn open_settings_window(app_handle: &AppHandle) {
+    if let Some(window) = app_handle.get_webview_window("settingsWindow") {
+        match window.set_focus() {
+            Ok(()) => {}
+            Err(err) => log::error!("Could not focus the Settings window: {:?}", err),
+        }
+        return;
+    }
+    let settings_window = tauri::WebviewWindowBuilder::new(app_handle, "settingsWindow", tauri::WebviewUrl::App(PathBuf::from("settings.html")))
+        .inner_size(560.0, 640.0)
+        .title("Einstellungen")
+        .center()
+        .focused(true)
+        .menu(build_menus(MenuKind::Settings, app_handle))
+        .build();
+    match settings_window.and_then(|window| window.show()) {
+        Ok(()) => {}
+        Err(err) => log::error!("Could not open the Settings window: {:?}", err),
     }
+}
 
-    match storage.wk_judgesmeeting_time.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_judgesmeeting_time;
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
+// MARK: Func: Logs
+/// Returns: The sessions with logs for the log viewer, the most recent first.
+#[tauri::command]
+fn get_log_sessions() -> Vec<Logging::LogSession> {
+    return Logging::list_log_sessions();
+}
 
-    match storage.wk_responsible_person.lock() {
-        Ok(mut guard) => {
-            *guard = frontendstorage.wk_responsible_person;
-            drop(guard);
-        }
+/// Reads the records of a session for the log viewer, which polls the log of the current session.
+/// Param 1: The id of the session, see get_log_sessions
+/// Param 2: The offset returned by the previous call, 0 to read the whole log
+/// Param 3: The rotations returned by the previous call, 0 to read the whole log
+/// Returns: The records after the offset and where to continue
+#[tauri::command]
+fn read_log_records(session: String, offset: u64, rotations: u64) -> Result<Logging::LogChunk, DetailedError> {
+    return match Logging::read_log(&session, offset, rotations) {
+        Ok(chunk) => Ok(chunk),
         Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+            log::error!("Could not read the log of {session}: {err}");
+            Err(err)
         }
-    }
+    };
+}
 
-    match storage.wk_replacement_judges.lock() {
-        Ok(mut guard) => {
-            *guard = match frontendstorage.wk_replacement_judges {
-                Some(map) => map,
-                None => Vec::new(),
-            };
-            drop(guard);
-        }
+/// Writes the records selected in the log viewer, already formatted by the frontend, to a file.
+#[tauri::command]
+fn export_log_records(filepath: String, contents: String) -> Result<(), DetailedError> {
+    return match Logging::export_log_records(std::path::Path::new(&filepath), &contents) {
+        Ok(()) => Ok(()),
         Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+            log::error!("Could not export the log records: {err}");
+            Err(err)
         }
-    }
+    };
+}
 
-    match storage.wk_judgingtables.lock() {
-        Ok(mut guard) => {
-            *guard = match frontendstorage.wk_judgingtables {
-                Some(map) => map,
-                None => HashMap::new(),
-            };
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
+/// Opens the log viewer, or focuses it if it is already open.
+fn open_log_viewer_window(app_handle: &AppHandle) {
+    if let Some(window) = app_handle.get_webview_window("logViewer") {
+        match window.set_focus() {
+            Ok(()) => {}
+            Err(err) => log::error!("Could not focus the log viewer: {:?}", err),
+        }
+        return;
+    }
+    let log_viewer = tauri::WebviewWindowBuilder::new(app_handle, "logViewer", tauri::WebviewUrl::App(PathBuf::from("logs.html")))
+        .inner_size(900.0, 600.0)
+        .title("Logs")
+        .center()
+        .focused(true)
+        .menu(build_menus(MenuKind::LogViewer, app_handle))
+        .build();
+    match log_viewer.and_then(|window| window.show()) {
+        Ok(()) => {}
+        Err(err) => log::error!("Could not open the log viewer: {:?}", err),
     }
+}
 
-    // Process backend library docx and html
-    let library_code = create_tables_pdf(storage.inner(), PathBuf::from(filepath.clone())).unwrap();
-
-    // Check if this succeeded.
-    if library_code != ApplicationError::NoError {
-        return Ok(library_code);
-    }
+// MARK: Func: What's new
+/// The changelog of this version from docs/, which every release has to add.
+static CHANGELOG: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../docs/DTB-Kampfrichtereinsatzpläne_v", env!("CARGO_PKG_VERSION"), "_Changelog.md"));
+
+/// Shows the release notes of this version in a message dialog.
+fn show_whats_new(app_handle: &AppHandle) {
+    app_handle
+        .dialog()
+        .message(release_notes(CHANGELOG))
+        .title(format!("Neu in Version {APP_VERSION}"))
+        .kind(MessageDialogKind::Info)
+        .show(|_| {});
+}
 
-    // Fetch the generated html file
-    let generated_html = filepath.clone().replace(".pdf", "_temp.html");
-    let generated_docx = filepath.clone().replace(".pdf", "_temp.docx");
+/// Param 1: A changelog from docs/
+/// Returns: Its entries below "Versionshinweise", one per line, without the markdown checkboxes.
+fn release_notes(changelog: &str) -> String {
+    let notes: Vec<String> = changelog
+        .lines()
+        .skip_while(|line| !line.starts_with("### Versionshinweise"))
+        .skip(1)
+        .take_while(|line| !line.starts_with("---"))
+        .filter_map(|line| line.trim().strip_prefix("- [x]").map(|entry| format!("• {}", entry.trim())))
+        .collect();
+    return notes.join("\n");
+}
 
-    // Get the shared PDF View
-    let platform_webview = PLATFORM_WEBVIEW.blocking_lock();
-    let errno = platform_webview.print_pdf(PathBuf::from(generated_html.clone()), PathBuf::from(filepath.clone()), app_handle, generated_docx, generated_html);
+// MARK: Func: Diagnostics
+const DIAGNOSTICS_ANONYMIZED: &str = "Anonymisiert beifügen";
+const DIAGNOSTICS_UNCHANGED: &str = "Unverändert beifügen";
+const DIAGNOSTICS_WITHOUT: &str = "Nicht beifügen";
+
+/// Saves a diagnostics bundle for bug reports without a connection, see Diagnostics.rs.
+/// If an editor window is focused, asks whether its competition should be included, and with which names.
+/// Runs on its own thread, as it asks with blocking dialogs.
+fn save_diagnostics_from_menu(app_handle: &AppHandle) {
+    // Taken before the dialogs, which move the focus away from the editor
+    let wettkampf = focused_editor_window(app_handle)
+        .and_then(|window| app_handle.state::<DocumentRegistry>().get(window.label()).ok())
+        .map(|document| document.wettkampf);
+    let handle = app_handle.clone();
+    thread::spawn(move || {
+        let (wettkampf, anonymize) = match wettkampf {
+            Some(wettkampf) => {
+                let result = handle
+                    .dialog()
+                    .message(format!(
+                        "Soll der geöffnete Wettkampf \"{}\" den Diagnosedaten beigefügt werden?\n\nBeim anonymisierten Beifügen werden die Namen der Kampfrichter, der Vereine und der/des Verantwortlichen durch Platzhalter ersetzt. Die Logs sowie die Namen, Adressen und Ordner aus den Einstellungen werden dann weggelassen.",
+                        wettkampf.wk_name
+                    ))
+                    .title("Diagnosedaten speichern")
+                    .kind(MessageDialogKind::Info)
+                    .buttons(MessageDialogButtons::YesNoCancelCustom(
+                        DIAGNOSTICS_ANONYMIZED.to_string(),
+                        DIAGNOSTICS_UNCHANGED.to_string(),
+                        DIAGNOSTICS_WITHOUT.to_string(),
+                    ))
+                    .blocking_show_with_result();
+                // Depending on the platform, the custom buttons are reported by their text or as Yes/No/Cancel
+                match result {
+                    MessageDialogResult::Yes => (Some(wettkampf), true),
+                    MessageDialogResult::Custom(button) if button == DIAGNOSTICS_ANONYMIZED => (Some(wettkampf), true),
+                    MessageDialogResult::No => (Some(wettkampf), false),
+                    MessageDialogResult::Custom(button) if button == DIAGNOSTICS_UNCHANGED => (Some(wettkampf), false),
+                    _ => (None, false),
+                }
+            }
+            None => (None, false),
+        };
 
-    if errno != ApplicationError::NoError {
-        return Ok(errno);
-    }
+        let mut file_dialog = handle
+            .dialog()
+            .file()
+            .add_filter("ZIP-Archiv (.zip)", &["zip"])
+            .set_title("Diagnosedaten speichern")
+            .set_file_name(format!("Diagnosedaten_{}.zip", chrono::Local::now().format("%Y-%m-%d_%H-%M")));
+        if let Some(dir) = current_settings().default_save_dir {
+            file_dialog = file_dialog.set_directory(dir);
+        }
+        let path = match file_dialog.blocking_save_file().map(|path| path.into_path()) {
+            Some(Ok(path)) => path,
+            Some(Err(err)) => {
+                log::error!("The chosen save path is not a file path: {:?}", err);
+                return;
+            }
+            None => return,
+        };
+        // Linux might not add the proper file extension
+        let path = match path.extension() {
+            Some(extension) if extension == "zip" => path,
+            _ => PathBuf::from(format!("{}.zip", path.to_string_lossy())),
+        };
 
-    return Ok(ApplicationError::NoError);
+        let (message, title, kind) = match write_diagnostics(&path, wettkampf.as_ref(), anonymize) {
+            Ok(()) => (
+                format!("Die Diagnosedaten wurden unter \"{}\" gespeichert. Sie können die Datei einem Fehlerbericht beifügen.", path.display()),
+                "Diagnosedaten gespeichert",
+                MessageDialogKind::Info,
+            ),
+            Err(err) => {
+                log::error!("Could not save the diagnostics: {err}");
+                (err.message().to_string(), "Fehler beim Speichern", MessageDialogKind::Error)
+            }
+        };
+        handle.dialog().message(message).title(title).kind(kind).show(|_| {});
+    });
 }
 
-// Function for loading a file from disk and importing this into frontend storage
-// Then open the editor
-#[tauri::command]
-async fn import_wk_file_and_open_editor(
-    filepath: String,
-    storage: State<'_, Storage>,
-    app_handle: AppHandle,
-) -> Result<ApplicationError, ()> {
-    // Set the static thing so we know where this was saved!
-    unsafe { SAVE_PATH = Some(filepath.clone()) };
-
-    // Deserialize the file
-    let imported_storage: Storage = match serde_json::from_reader(File::open(filepath).unwrap()) {
-        Ok(storage) => storage,
-        Err(err) => {
-            eprintln!("Could not deserialize the wk data from the imported file (Maybe the file is corrupt or invalid?): {:?}", err);
-            return Ok(ApplicationError::JSONDeserializeImporterError);
-        }
-    };
+// MARK: Func: Menu Actions
+/// Lets the user pick a Wettkampf file and opens it, like the button in the main window.
+fn open_wk_file_from_dialog(app_handle: &AppHandle) {
+    let handle = app_handle.clone();
+    let mut file_dialog = app_handle
+        .dialog()
+        .file()
+        .add_filter("Wettkampfdatei (.wkdata)", &["wkdata"])
+        .set_title("Wettkampf öffnen");
+    if let Some(dir) = current_settings().default_save_dir {
+        file_dialog = file_dialog.set_directory(dir);
+    }
+    file_dialog.pick_file(move |path| {
+        let path = match path.map(|path| path.into_path()) {
+            Some(Ok(path)) => path,
+            Some(Err(err)) => {
+                log::error!("The chosen file is not a file path: {:?}", err);
+                return;
+            }
+            None => return,
+        };
+        open_wk_file_and_report(&handle, path);
+    });
+}
 
-    // Update the storage
-    match storage.wk_name.lock() {
-        Ok(mut guard) => {
-            *guard = imported_storage.wk_name.lock().unwrap().clone();
-            drop(guard);
-        }
+/// Opens a Wettkampf file chosen in the menu or passed by the operating system.
+/// There is no frontend to report to, so errors are shown in a dialog.
+/// Must not run on the main thread, as opening a corrupt file asks for its backup with a blocking dialog.
+fn open_wk_file_and_report(app_handle: &AppHandle, path: PathBuf) {
+    let documents = app_handle.state::<DocumentRegistry>();
+    match open_wk_file(app_handle, &documents, path.clone()) {
+        Ok(()) => {}
         Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+            // A file that is gone for good has no place in the recent files
+            if !path.is_file() {
+                remove_recent_file(&path);
+                refresh_recent_files_menus(app_handle);
+            }
+            app_handle
+                .dialog()
+                .message(format!("Der Wettkampf \"{}\" konnte nicht geöffnet werden. {}", path.display(), err.message()))
+                .title("Fehler beim Öffnen")
+                .kind(MessageDialogKind::Error)
+                .show(|_| {});
         }
     }
+}
 
-    match storage.wk_place.lock() {
-        Ok(mut guard) => {
-            *guard = imported_storage.wk_place.lock().unwrap().clone();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
+/// Hands a menu entry over to the focused editor window with the menuAction event.
+/// Saving, exporting and the table operations run in the editor, so the menu does the same as its buttons.
+fn forward_menu_action(app_handle: &AppHandle, action: &str) {
+    let window = match focused_editor_window(app_handle) {
+        Some(window) => window,
+        None => return,
+    };
+    match window.emit_to(window.label(), "menuAction", action) {
+        Ok(()) => {}
+        Err(err) => log::error!("Failed to emit menuAction to the Editor window: {:?}", err),
     }
+}
 
-    match storage.wk_date.lock() {
-        Ok(mut guard) => {
-            *guard = imported_storage.wk_date.lock().unwrap().clone();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+/// Closes the focused window. Editor windows with unsaved changes ask first, see confirm_close.
+fn close_focused_window(app_handle: &AppHandle) {
+    let window = app_handle
+        .webview_windows()
+        .into_values()
+        .find(|window| window.is_focused().unwrap_or(false));
+    if let Some(window) = window {
+        match window.close() {
+            Ok(()) => {}
+            Err(err) => log::error!("Could not close the window {}: {:?}", window.label(), err),
         }
     }
+}
 
-    match storage.wk_judgesmeeting_time.lock() {
-        Ok(mut guard) => {
-            *guard = imported_storage
-                .wk_judgesmeeting_time
-                .lock()
-                .unwrap()
-                .clone();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+/// Brings the main window to the front.
+fn show_main_window(app_handle: &AppHandle) {
+    if let Some(window) = app_handle.get_webview_window("mainUI") {
+        match window.show().and_then(|()| window.set_focus()) {
+            Ok(()) => {}
+            Err(err) => log::error!("Could not show the main window: {:?}", err),
         }
     }
+}
 
-    match storage.wk_responsible_person.lock() {
-        Ok(mut guard) => {
-            *guard = imported_storage
-                .wk_responsible_person
-                .lock()
-                .unwrap()
-                .clone();
-            drop(guard);
-        }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
-        }
-    }
+// MARK: Func: Launch Files
+/// Collects the Wettkampf files from the command line of a launch, e.g. after double-clicking a file.
+/// Param 1: The arguments of the launch, including the executable
+/// Param 2: The working directory of the launch, relative paths are resolved against it
+/// Returns: The paths of the Wettkampf files.
+fn wk_files_from_args(args: &[String], cwd: &std::path::Path) -> Vec<PathBuf> {
+    return args
+        .iter()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .map(|arg| cwd.join(arg))
+        .filter(|path| path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("wkdata")))
+        .collect();
+}
 
-    match storage.wk_replacement_judges.lock() {
-        Ok(mut guard) => {
-            *guard = imported_storage
-                .wk_replacement_judges
-                .lock()
-                .unwrap()
-                .clone();
-            drop(guard);
+/// Opens each file in its own editor window, one after another.
+/// Runs on its own thread, see open_wk_file_and_report.
+fn open_wk_files(app_handle: &AppHandle, paths: Vec<PathBuf>) {
+    if paths.is_empty() {
+        return;
+    }
+    let handle = app_handle.clone();
+    thread::spawn(move || {
+        for path in paths {
+            open_wk_file_and_report(&handle, path);
         }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+    });
+}
+
+/// Brings the app to the front when it is launched a second time without a file.
+fn bring_app_to_front(app_handle: &AppHandle) {
+    let window = app_handle
+        .get_webview_window("mainUI")
+        .or_else(|| app_handle.webview_windows().into_values().next());
+    if let Some(window) = window {
+        match window.unminimize().and_then(|()| window.show()).and_then(|()| window.set_focus()) {
+            Ok(()) => {}
+            Err(err) => log::error!("Could not bring the window {} to the front: {:?}", window.label(), err),
         }
     }
+}
+
+// MARK: Func: Confirm Close
+/// Asks whether the unsaved changes of an editor window should be saved before the window is closed.
+/// A Wettkampf that was never saved asks for the path first. Cancelling any of the dialogs keeps the window open.
+fn confirm_close(window: WebviewWindow) {
+    let document = match window.state::<DocumentRegistry>().get(window.label()) {
+        Ok(document) => document,
+        Err(_) => return,
+    };
 
-    match storage.wk_judgingtables.lock() {
-        Ok(mut guard) => {
-            *guard = imported_storage.wk_judgingtables.lock().unwrap().clone();
-            drop(guard);
+    let dialog = window
+        .dialog()
+        .message(format!(
+            "Die Änderungen an \"{}\" wurden noch nicht gespeichert. Sollen sie vor dem Schließen gespeichert werden?",
+            document.wettkampf.wk_name
+        ))
+        .title("Ungespeicherte Änderungen")
+        .kind(MessageDialogKind::Warning)
+        .parent(&window)
+        .buttons(MessageDialogButtons::YesNoCancelCustom(
+            CLOSE_SAVE.to_string(),
+            CLOSE_DISCARD.to_string(),
+            CLOSE_CANCEL.to_string(),
+        ));
+    dialog.show_with_result(move |result| {
+        // Depending on the platform, the custom buttons are reported by their text or as Yes/No/Cancel
+        let save = match result {
+            MessageDialogResult::Yes => true,
+            MessageDialogResult::No => false,
+            MessageDialogResult::Custom(button) if button == CLOSE_SAVE => true,
+            MessageDialogResult::Custom(button) if button == CLOSE_DISCARD => false,
+            _ => {
+                QUIT_PENDING.store(false, Ordering::Relaxed);
+                return;
+            }
+        };
+        if !save {
+            // The changes are dropped on purpose, so there is nothing to restore later
+            match window.state::<DocumentRegistry>().update(window.label(), |document| document.saved_position = Some(document.history.position())) {
+                Ok(()) => {}
+                Err(err) => log::error!("Could not discard the changes of the Editor window: {:?}", err),
+            }
+            close_editor_window(&window);
+            return;
         }
-        Err(err) => {
-            eprintln!("Failed to acquire lock of mutex: {:?}", err);
-            return Ok(ApplicationError::MutexPoisonedError);
+        match document.save_path {
+            Some(path) => save_and_close(window, path),
+            None => {
+                let mut file_dialog = window
+                    .dialog()
+                    .file()
+                    .add_filter("Wettkampfdatei (.wkdata)", &["wkdata"])
+                    .set_title("Wettkampf speichern unter...")
+                    .set_parent(&window);
+                if let Some(dir) = current_settings().default_save_dir {
+                    file_dialog = file_dialog.set_directory(dir);
+                }
+                file_dialog.save_file(move |path| {
+                    let path = match path.map(|path| path.into_path()) {
+                        Some(Ok(path)) => path,
+                        Some(Err(err)) => {
+                            log::error!("The chosen save path is not a file path: {:?}", err);
+                            QUIT_PENDING.store(false, Ordering::Relaxed);
+                            return;
+                        }
+                        None => {
+                            QUIT_PENDING.store(false, Ordering::Relaxed);
+                            return;
+                        }
+                    };
+                    // Linux might not add the proper file extension
+                    let path = match path.extension() {
+                        Some(extension) if extension == "wkdata" => path,
+                        _ => PathBuf::from(format!("{}.wkdata", path.to_string_lossy())),
+                    };
+                    save_and_close(window, path);
+                });
+            }
         }
-    }
+    });
+}
 
-    // Open the Editor!
-    let editor_window = match tauri::WebviewWindowBuilder::new(
-        &app_handle,
-        "editor",
-        tauri::WebviewUrl::App(PathBuf::from("editor.html")),
-    )
-    .inner_size(1250.0, 800.0)
-    .title(format![
-        "{} (gespeichert)",
-        imported_storage.wk_name.lock().unwrap()
-    ])
-    .focused(true)
-    .menu(build_menus(MenuKind::Editor, &app_handle))
-    .center()
-    .build()
-    {
-        Ok(window) => window,
-        Err(err) => {
-            eprintln!("Could not build the Editor window: {:?}", err);
-            return Ok(ApplicationError::TauriWindowCreationError);
-        }
-    };
-    match editor_window.show() {
-        Ok(()) => {}
+/// Saves the document of an editor window and closes the window, unless saving failed.
+fn save_and_close(window: WebviewWindow, path: PathBuf) {
+    let documents = window.state::<DocumentRegistry>();
+    match save_document(window.app_handle(), &documents, window.label(), &path) {
+        Ok(()) => close_editor_window(&window),
         Err(err) => {
-            eprintln!("Could not show the Editor window: {:?}", err);
-            return Ok(ApplicationError::TauriWindowShowError);
+            // The window stays open, so does the app
+            QUIT_PENDING.store(false, Ordering::Relaxed);
+            refresh_window_title(&window, &documents);
+            window
+                .dialog()
+                .message(format!("Der Wettkampf konnte nicht gespeichert werden. {}", err.message()))
+                .title("Fehler beim Speichern")
+                .kind(MessageDialogKind::Error)
+                .parent(&window)
+                .show(|_| {});
         }
     }
+}
 
-    return Ok(ApplicationError::NoError);
+/// Closes an editor window without asking again. Its document is removed once the window is destroyed.
+fn close_editor_window(window: &WebviewWindow) {
+    match window.destroy() {
+        Ok(()) => {}
+        Err(err) => log::error!("Could not close the Editor window: {:?}", err),
+    }
 }
 
-#[cfg(not(target_os = "linux"))]
 #[tauri::command]
-fn show_item_in_folder(path: String) -> Result<(), String> {
+fn show_item_in_folder(path: String) -> Result<(), DetailedError> {
+    let error = |cause: &(dyn std::error::Error + 'static)| {
+        return DetailedError::new(ApplicationError::ShowItemInFolderError).with_path(&path).caused_by(cause);
+    };
+
     #[cfg(target_os = "windows")]
     {
         Command::new("explorer")
             .args(["/select,", &path]) // The comma after select is not a typo
             .spawn()
-            .map_err(|e| e.to_string())?;
+            .map_err(|err| error(&err))?;
     }
 
     #[cfg(target_os = "macos")]
@@ -966,19 +1089,52 @@ fn show_item_in_folder(path: String) -> Result<(), String> {
             Command::new("open")
                 .args([&path])
                 .spawn()
-                .map_err(|e| e.to_string())?;
+                .map_err(|err| error(&err))?;
         } else {
             Command::new("open")
                 .args(["-R", &path])
                 .spawn()
-                .map_err(|e| e.to_string())?;
+                .map_err(|err| error(&err))?;
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        // Ask the file manager to select the item via the FileManager1 D-Bus interface.
+        // Not every desktop implements it, so fall back to opening the folder with xdg-open.
+        let path_buf = PathBuf::from(&path);
+        let uri = tauri::Url::from_file_path(&path_buf)
+            .map_err(|()| DetailedError::new(ApplicationError::ShowItemInFolderError).with_path(&path).caused_by_text("Could not convert the path to a file URI"))?;
+        let dbus_succeeded = Command::new("dbus-send")
+            .args([
+                "--session",
+                "--dest=org.freedesktop.FileManager1",
+                "--type=method_call",
+                "/org/freedesktop/FileManager1",
+                "org.freedesktop.FileManager1.ShowItems",
+                format!("array:string:{uri}").as_str(),
+                "string:",
+            ])
+            .status()
+            .map(|status| status.success())
+            .unwrap_or(false);
+        if !dbus_succeeded {
+            let folder = if path_buf.is_dir() {
+                path_buf
+            } else {
+                path_buf.parent().map(|parent| parent.to_path_buf()).unwrap_or(path_buf)
+            };
+            Command::new("xdg-open")
+                .arg(folder)
+                .spawn()
+                .map_err(|err| error(&err))?;
         }
     }
-    Ok(())
+    return Ok(());
 }
 
 #[tauri::command]
-fn open_bug_reporter(kind: String, app_handle: AppHandle) -> Result<ApplicationError, ()> {
+fn open_bug_reporter(kind: String, app_handle: AppHandle) -> Result<(), DetailedError> {
     // If we already found the window, show it. Then we should not create a new one or we might
     // panic.
     if app_handle.windows().contains_key("bugReport") {
@@ -986,15 +1142,15 @@ fn open_bug_reporter(kind: String, app_handle: AppHandle) -> Result<ApplicationE
         let bug_report_windows = match windows.get("bugReport") {
             Some(window) => window,
             None => {
-                eprintln!("Failed to get the bugReporter window from HashMap, although it was expected to be present.");
-                return Ok(ApplicationError::TauriExistingWindowNotFoundError);
+                log::error!("Failed to get the bugReporter window from HashMap, although it was expected to be present.");
+                return Err(ApplicationError::TauriExistingWindowNotFoundError.into());
             }
         };
         match bug_report_windows.show() {
-            Ok(()) => return Ok(ApplicationError::NoError),
+            Ok(()) => return Ok(()),
             Err(err) => {
-                eprintln!("Failed to show BugReport Window: {:?}", err);
-                return Ok(ApplicationError::TauriWindowShowError);
+                log::error!("Failed to show BugReport Window: {:?}", err);
+                return Err(ApplicationError::TauriWindowShowError.into());
             }
         }
     }
@@ -1025,19 +1181,19 @@ fn open_bug_reporter(kind: String, app_handle: AppHandle) -> Result<ApplicationE
     {
         Ok(win) => win,
         Err(err) => {
-            eprintln!("Could not create BugReporter window: {:?}", err);
-            return Ok(ApplicationError::TauriWindowCreationError);
+            log::error!("Could not create BugReporter window: {:?}", err);
+            return Err(ApplicationError::TauriWindowCreationError.into());
         }
     };
     match window.show() {
         Ok(()) => {}
         Err(err) => {
-            eprintln!("Could not show BugReporter window: {:?}", err);
-            return Ok(ApplicationError::TauriWindowShowError);
+            log::error!("Could not show BugReporter window: {:?}", err);
+            return Err(ApplicationError::TauriWindowShowError.into());
         }
     }
 
-    return Ok(ApplicationError::NoError);
+    return Ok(());
 }
 
 #[tauri::command]
@@ -1048,7 +1204,7 @@ async fn send_mail_from_frontend(
     message: String,
     sendlogs: bool,
     kind: String,
-) -> Result<ApplicationError, ()> {
+) -> Result<MailDelivery, DetailedError> {
     // First, we need to build a HTML element from the message. Replace all newline chars with <br />
     let message_formatted = message.replace("\n", "<br />");
 
@@ -1086,14 +1242,50 @@ async fn send_mail_from_frontend(
         mail_type = MessageKind::Unknown;
     }
 
-    return Ok(send_mail(mail_type, final_message, sendlogs).await);
+    // Goes through the outbox, so the message is not lost without a connection
+    let log = if sendlogs { current_log_attachment() } else { None };
+    return match send_mail_via_outbox(mail_type, final_message, log).await {
+        Ok(delivery) => Ok(delivery),
+        Err(err) => {
+            log::error!("The message is lost: {err}");
+            Err(err)
+        }
+    };
 }
 
 // This is only used in the panic hook, therefore we don't care about return values
 // or anything related. We will abort() anyways.
+// Whatever cannot be sent now stays in the outbox for the next launch.
 #[tokio::main]
 async fn send_mail_from_panic(kind: MessageKind, body: String) {
-    send_mail(kind, body, true).await;
+    send_mail_unless_busy(kind, body, current_log_attachment()).await;
+}
+
+// MARK: Func: Outbox
+/// Returns: The messages to the developers that are not sent yet, for the bug reporter.
+#[tauri::command]
+fn get_outbox_messages() -> Vec<PendingMessage> {
+    return list_pending_messages();
+}
+
+/// Removes a message from the outbox, so it is never sent.
+#[tauri::command]
+async fn cancel_outbox_message(id: String) -> Result<(), DetailedError> {
+    return match cancel_message(&id).await {
+        Ok(()) => Ok(()),
+        Err(err) => {
+            log::error!("Could not cancel the message {id}: {err}");
+            Err(err)
+        }
+    };
+}
+
+/// Tries to send all messages of the outbox now, instead of waiting for their next attempt.
+/// Returns: The messages that are still not sent
+#[tauri::command]
+async fn retry_outbox_messages() -> Vec<PendingMessage> {
+    send_pending_messages(true).await;
+    return list_pending_messages();
 }
 
 #[tauri::command]
@@ -1111,6 +1303,9 @@ fn update_app(requested: bool) {
 // MARK: Main Function
 /// Main application entry function.
 fn main() {
+    // Install the logger first, so even the earliest problems end up in the log of this session
+    Logging::init_logging();
+
     // Set panic hook to send mail if possible
     std::panic::set_hook(Box::new(|info: &PanicHookInfo| {
         // Execute the regular hook
@@ -1126,7 +1321,7 @@ fn main() {
         let thread = thread::current();
         let name = thread.name().unwrap_or("<unnamed>");
 
-        eprintln!("Thread '{name}' panicked at {location}:\n{msg}");
+        log::error!("Thread '{name}' panicked at {location}:\n{msg}");
 
         // Now hope that everything is flushed!
         // We can then try to send an email with all the data
@@ -1145,100 +1340,71 @@ fn main() {
                 abort();
             }
             Err(err) => {
-                eprintln!("Failed to join PanicMailThread: {:?}", err);
+                log::error!("Failed to join PanicMailThread: {:?}", err);
                 abort();
             }
         }
     }));
 
-    // Rebase all StdOut and StdErr happenings
-    #[cfg(not(debug_assertions))]
-    match log::activateLogging() {
-        Ok(()) => {}
-        Err(err) => {
-            panic!(
-                "Could not redirect StdOut and StdErr successfully: {:?}",
-                err
-            );
-        }
-    }
-
-    // Pack files at compile time and write them to disk at runtime... Currently the only way to embed files within the binary cross-platform
-    #[cfg(not(target_os = "windows"))]
-    let template_file_binary = include_bytes!(r"../../res/Vorlage_Einsatzplan_Leer.docx");
-    #[cfg(not(target_os = "windows"))]
-    let table_file_binary = include_bytes!(r"../../res/Tabelle_Vorlage_Leer.docx");
-    #[cfg(target_os = "windows")]
-    let template_file_binary = include_bytes!(r"..\..\res\Vorlage_Einsatzplan_Leer.docx");
-    #[cfg(target_os = "windows")]
-    let table_file_binary = include_bytes!(r"..\..\res\Tabelle_Vorlage_Leer.docx");
-
-    match directories::BaseDirs::new() {
-        None => {
-            panic!("Could not get the Windows Base Dirs. Important files will be missing and we cannot get them from anywhere else, so we exit here.")
-        }
-        Some(dirs) => {
-            let appdata_roaming_dir = dirs.data_dir();
-            let application_resources_dir = appdata_roaming_dir
-                .join("de.philippremy.dtb-kampfrichtereinsatzplaene")
-                .join("Resources");
-            // Create the folder if it does not exist!
-            match std::fs::create_dir_all(application_resources_dir.clone()) {
-                Ok(()) => {}
-                Err(err) => {
-                    panic!("Could not create the AppData dir: {:?}", err);
-                }
-            }
-            // Copy the stuff to there and we should be good to go.
-            // Write file at path!
-            match std::fs::write(
-                application_resources_dir
-                    .clone()
-                    .join("Vorlage_Einsatzplan_Leer.docx"),
-                template_file_binary,
-            ) {
-                Ok(()) => {}
-                Err(e) => panic!("Could not write the template file: {e}"),
-            }
-            // Write file at path!
-            match std::fs::write(
-                application_resources_dir
-                    .clone()
-                    .join("Tabelle_Vorlage_Leer.docx"),
-                table_file_binary,
-            ) {
-                Ok(()) => {}
-                Err(e) => panic!("Could not write the table file: {e}"),
-            }
-        }
+    // Write the templates to disk, the plan generator reads them from there
+    match Resources::write_templates() {
+        Ok(_) => {}
+        Err(err) => panic!("{err}"),
     }
 
-    #[cfg(not(target_os = "linux"))]
     tauri::Builder::default()
+        // Has to be the first plugin: a second launch hands its files over to the running app and quits right away
+        .plugin(tauri_plugin_single_instance::init(|app_handle, args, cwd| {
+            let paths = wk_files_from_args(&args, std::path::Path::new(&cwd));
+            if paths.is_empty() {
+                bring_app_to_front(app_handle);
+            }
+            open_wk_files(app_handle, paths);
+        }))
         .plugin(tauri_plugin_updater::Builder::new().target(tauri::utils::platform::target_triple().unwrap()).build())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
-        .manage(Storage::default())
-        .invoke_handler(tauri::generate_handler![update_storage_data, create_wettkampf, sync_wk_data_and_open_editor, get_wk_data_to_frontend, sync_to_backend_and_save, sync_to_backend_and_create_docx, sync_to_backend_and_create_pdf, import_wk_file_and_open_editor, show_item_in_folder, open_bug_reporter, send_mail_from_frontend, update_mainwindow_loading_state, update_app])
+        .manage(DocumentRegistry::default())
+        .invoke_handler(tauri::generate_handler![update_storage_data, create_wettkampf, sync_wk_data_and_open_editor, get_wk_data_to_frontend, get_discipline_schemas, get_judge_conflicts, import_judge_roster, get_judge_roster, auto_assign_judges, sync_to_backend_and_save, sync_to_backend_and_create_docx, sync_to_backend_and_create_pdf, import_wk_file_and_open_editor, get_restorable_autosaves, restore_autosave, discard_autosave, undo_edit, redo_edit, get_recent_files, get_settings, get_default_settings, update_settings, get_log_sessions, read_log_records, export_log_records, show_item_in_folder, open_bug_reporter, send_mail_from_frontend, get_outbox_messages, cancel_outbox_message, retry_outbox_messages, update_mainwindow_loading_state, update_app])
         .setup(|app| {
             let handle = app.handle().clone();
 
+            // Only now, a second launch has handed its files over to this one and quit by the time setup runs
+            match Logging::start_session_log() {
+                Ok(()) => {}
+                Err(err) => log::error!("Only logging to the console: {err}"),
+            }
+
             // Set App Menu
             app.set_menu(build_menus(MenuKind::Default, &handle)).unwrap();
 
-            // Build the PDF Window
-            let pdf_window = tauri::WebviewWindowBuilder::new(&handle, "pdfWindow", tauri::WebviewUrl::App(PathBuf::from("blank.html")))
-                .visible(false)
-                .accept_first_mouse(false)
-                .build()
-                .unwrap();
-            pdf_window.with_webview(|webview|{
+            // Open the files the app was launched with (Windows and Linux, macOS sends RunEvent::Opened instead)
+            let cwd = env::current_dir().unwrap_or_default();
+            open_wk_files(&handle, wk_files_from_args(&env::args().collect::<Vec<String>>(), &cwd));
 
-                let mut lockGuard = PLATFORM_WEBVIEW.blocking_lock();
-                lockGuard.inner = Some(webview);
+            // Autosave the documents with unsaved changes, so they survive a crash
+            let autosave_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    sleep(std::time::Duration::from_secs(current_settings().autosave_interval_minutes * 60)).await;
+                    autosave_documents(&autosave_handle.state::<DocumentRegistry>());
+                }
+            });
 
-            }).unwrap();
+            // Retry the messages to the developers that could not be sent, also those of earlier launches
+            let outbox_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    if send_pending_messages(false).await {
+                        match outbox_handle.emit("outboxChanged", ()) {
+                            Ok(()) => {}
+                            Err(err) => log::error!("Failed to emit outboxChanged to frontend: {:?}", err),
+                        }
+                    }
+                    sleep(OUTBOX_CHECK_INTERVAL).await;
+                }
+            });
 
             tauri::async_runtime::spawn(async move {
 
@@ -1251,13 +1417,17 @@ fn main() {
                     }
                 }
 
+                if !current_settings().check_for_updates {
+                    return;
+                }
+
                 match handle.updater().unwrap().check().await {
                     Ok(update_option) => {
                         match update_option {
                             Some(update) => {
                                 match handle.emit_to("mainUI", "updateIsAvailable", UpdateAvailablePayload{ body: update.body.clone().unwrap(), date: update.date.unwrap().to_string(), version: update.version.clone() }) {
                                     Ok(()) => {},
-                                    Err(err) => { eprintln!("Failed to emit updateIsAvailable to frontend: {:?}", err); }
+                                    Err(err) => { log::error!("Failed to emit updateIsAvailable to frontend: {:?}", err); }
                                 }
         
                                 // We have to wait for an answer from the frontend
@@ -1284,22 +1454,22 @@ fn main() {
                                             UPDATE_PROGRESS.store(temp_progress, Ordering::Relaxed);
                                             match handle.emit_to("mainUI", "updateHasProgress", UpdateProgressPayload{ chunk_len: temp_progress as usize, content_len: content_length }) {
                                                 Ok(()) => {},
-                                                Err(err) => { eprintln!("Failed to emit updateHasProgress to frontend: {:?}", err); }
+                                                Err(err) => { log::error!("Failed to emit updateHasProgress to frontend: {:?}", err); }
                                             }
                                         }, || {
                                             match handle.emit_to("mainUI", "updateIsDownloaded", {}) {
                                                 Ok(()) => {},
-                                                Err(err) => { eprintln!("Failed to emit updateIsDownloaded to frontend: {:?}", err); }
+                                                Err(err) => { log::error!("Failed to emit updateIsDownloaded to frontend: {:?}", err); }
                                             }
                                         }).await {
                                             Ok(()) => {},
                                             Err(err) => {
-                                                eprintln!("Could not download and install the update: {:?}", err);
+                                                log::error!("Could not download and install the update: {:?}", err);
                                             }
                                         }
                                     }
                                     else {
-                                        eprintln!("Unexpected atomic value of UPDATE_REQUESTED found: {:?}", UPDATE_REQUESTED.load(Ordering::Relaxed));
+                                        log::error!("Unexpected atomic value of UPDATE_REQUESTED found: {:?}", UPDATE_REQUESTED.load(Ordering::Relaxed));
                                     }
                                 }
                             }
@@ -1309,10 +1479,10 @@ fn main() {
                         }
                     },
                     Err(err) => {
-                        eprintln!("Could not fetch Update information: {:?}", err);
+                        log::error!("Could not fetch Update information: {:?}", err);
                         match handle.clone().emit_to("mainUI", "updateThrewError", err.to_string()) {
                             Ok(()) => {},
-                            Err(err) => { eprintln!("Failed to emit updateThrewError to frontend: {:?}", err); }
+                            Err(err) => { log::error!("Failed to emit updateThrewError to frontend: {:?}", err); }
                         }
                     }
                 }
@@ -1322,6 +1492,9 @@ fn main() {
             // Handle Menu Events
             app.on_menu_event(|app_handle, ev|{
                 match ev.id.0.as_str() {
+                    "showSettings" => {
+                        open_settings_window(app_handle);
+                    },
                     "showLicenses" => {
                         if app_handle.windows().contains_key("licenseWindow") {
                             let windows = app_handle.windows();
@@ -1342,27 +1515,66 @@ fn main() {
                         license_window.show().unwrap();
                     },
                     "showLogs" => {
-                        unsafe {
-                            if STDOUT_FILE.clone().is_some() {
-                                match show_item_in_folder(STDOUT_FILE.clone().unwrap()) {
-                                    Ok(()) => {}
-                                    Err(err) => { eprintln!("Unable to show the logs in Explorer/Finder: {err}") },
-                                }
-                            } else {
-                                eprintln!("STDOUT_FILE and STDERR_FILE are 'None': Either we are running a development build or the piping process was not successful.");
+                        open_log_viewer_window(app_handle);
+                    },
+                    "saveDiagnostics" => {
+                        save_diagnostics_from_menu(app_handle);
+                    },
+                    "whatsnew" => {
+                        show_whats_new(app_handle);
+                    },
+                    "undo" | "redo" => {
+                        if let Some(window) = focused_editor_window(app_handle) {
+                            let documents = app_handle.state::<DocumentRegistry>();
+                            match step_history(&window, &documents, ev.id.0 == "undo") {
+                                Ok(_) => {}
+                                Err(err) => log::error!("Could not undo or redo in the Editor window: {:?}", err),
                             }
                         }
                     },
+                    "newWettkampf" => {
+                        match open_create_wettkampf_window(app_handle) {
+                            ApplicationError::NoError => {}
+                            err => log::error!("Could not open the CreateWettkampf window: {:?}", err),
+                        }
+                    },
+                    "openWettkampf" => {
+                        open_wk_file_from_dialog(app_handle);
+                    },
+                    "closeWindow" => {
+                        close_focused_window(app_handle);
+                    },
+                    "showMainWindow" => {
+                        show_main_window(app_handle);
+                    },
+                    "save" | "saveAs" | "exportDocx" | "exportPdf" | "editWkData" | "addReplacementJudges" | "importRoster" | "autoAssignJudges" => {
+                        forward_menu_action(app_handle, ev.id.0.as_str());
+                    },
+                    id if id.starts_with("openRecent:") => {
+                        open_wk_files(app_handle, vec![PathBuf::from(id.trim_start_matches("openRecent:"))]);
+                    },
+                    id if id.starts_with("addTable:") => {
+                        forward_menu_action(app_handle, id);
+                    },
                     "contactSupport" => {
-                        open_bug_reporter(String::from("SUPPORT"), app_handle.clone()).unwrap();
+                        match open_bug_reporter(String::from("SUPPORT"), app_handle.clone()) {
+                            Ok(()) => {}
+                            Err(err) => log::error!("Could not open the BugReporter window: {err}"),
+                        }
                     },
                     "bugReport" => {
-                        open_bug_reporter(String::from("BUG"), app_handle.clone()).unwrap();
+                        match open_bug_reporter(String::from("BUG"), app_handle.clone()) {
+                            Ok(()) => {}
+                            Err(err) => log::error!("Could not open the BugReporter window: {err}"),
+                        }
                     },
                     "feedback" => {
-                        open_bug_reporter(String::from("FEEDBACK"), app_handle.clone()).unwrap();
+                        match open_bug_reporter(String::from("FEEDBACK"), app_handle.clone()) {
+                            Ok(()) => {}
+                            Err(err) => log::error!("Could not open the BugReporter window: {err}"),
+                        }
                     },
-                    &_ => { println!("The following menu is currently not implemented: {}", ev.id.0); }
+                    &_ => { log::warn!("The following menu is currently not implemented: {}", ev.id.0); }
                 }
             });
 
@@ -1372,21 +1584,65 @@ fn main() {
         .unwrap()
         .run(|app_handle, ev| {
             match ev {
-                tauri::RunEvent::WindowEvent { label, event , .. } => {
+                tauri::RunEvent::WindowEvent { label, event, .. } => {
                     match event {
-                        tauri::WindowEvent::CloseRequested { .. } => {
-                            if label.as_str() != "pdfWindow" && app_handle.windows().len() < 3 {
+                        tauri::WindowEvent::CloseRequested { api, .. } => {
+                            // Editor windows with unsaved changes stay open until the user decided about them
+                            if app_handle.state::<DocumentRegistry>().is_dirty(&label) {
+                                api.prevent_close();
+                                if let Some(window) = app_handle.get_webview_window(&label) {
+                                    confirm_close(window);
+                                }
+                                return;
+                            }
+                            if app_handle.windows().len() < 2 {
                                 app_handle.exit(0);
                             }
                         },
                         tauri::WindowEvent::Destroyed => {
-                            if label.as_str() != "pdfWindow" && app_handle.windows().len() == 1 {
+                            // The document of an editor window is gone with its window. Its autosave is only kept
+                            // if the window went away without the user deciding about the unsaved changes
+                            if let Some(document) = app_handle.state::<DocumentRegistry>().close(&label) {
+                                if !document.is_dirty() {
+                                    remove_autosave(&document.autosave_id);
+                                }
+                            }
+                            // The user decided about the last window with unsaved changes, so the app can quit now
+                            if QUIT_PENDING.load(Ordering::Relaxed) && app_handle.state::<DocumentRegistry>().dirty_labels().is_empty() {
+                                QUIT_PENDING.store(false, Ordering::Relaxed);
+                                app_handle.exit(0);
+                                return;
+                            }
+                            if app_handle.windows().is_empty() {
                                 app_handle.exit(0);
                             }
                         },
                         _ => {},
                     }
                 },
+                // Files opened with the app in Finder, both at launch and while running
+                #[cfg(any(target_os = "macos", target_os = "ios"))]
+                tauri::RunEvent::Opened { urls } => {
+                    let paths = urls.iter().filter_map(|url| url.to_file_path().ok()).collect();
+                    open_wk_files(app_handle, paths);
+                },
+                tauri::RunEvent::ExitRequested { code: None, api, .. } => {
+                    // Quitting the app closes every window with unsaved changes first, which asks for each of them.
+                    // The app quits once all of them are closed, see WindowEvent::Destroyed
+                    let dirty_labels = app_handle.state::<DocumentRegistry>().dirty_labels();
+                    if !dirty_labels.is_empty() {
+                        QUIT_PENDING.store(true, Ordering::Relaxed);
+                        api.prevent_exit();
+                        for label in dirty_labels {
+                            if let Some(window) = app_handle.get_webview_window(&label) {
+                                match window.close() {
+                                    Ok(()) => {}
+                                    Err(err) => log::error!("Could not close the Editor window: {:?}", err),
+                                }
+                            }
+                        }
+                    }
+                },
                 _ => {},
             }
         });
@@ -1399,302 +1655,113 @@ enum MenuKind {
     Editor,
     BugReport,
     LicenseWindow,
+    Settings,
+    LogViewer,
 }
 
 fn build_menus(menuKind: MenuKind, app_handle: &AppHandle) -> Menu<Wry> {
+    // The menus only differ in the File and Edit submenus, the Editor gets the document related entries
+    let menu = MenuBuilder::new(app_handle)
+        .build()
+        .unwrap();
+    menu.append(&build_app_submenu(app_handle)).unwrap();
+    menu.append(&build_file_submenu(&menuKind, app_handle)).unwrap();
+    menu.append(&build_edit_submenu(&menuKind, app_handle)).unwrap();
+    menu.append(&build_view_submenu(app_handle)).unwrap();
+    menu.append(&build_window_submenu(app_handle)).unwrap();
+    menu.append(&build_help_submenu(app_handle)).unwrap();
+    menu.set_as_app_menu().unwrap();
+    return menu;
+}
+
+fn build_app_submenu(app_handle: &AppHandle) -> Submenu<Wry> {
+    return SubmenuBuilder::new(app_handle, "DTB Kampfrichtereinsatzpläne")
+        .about(Some(AboutMetadataBuilder::new()
+            .authors(Some(vec!["Philipp Remy <philipp.remy@dtb.de>".to_string()]))
+            .license(Some("GPL-3.0-only"))
+            .copyright(Some("© Philipp Remy 2024"))
+            .comments(Some("Ein Programm zum Erstellen von Kampfrichtereinsatzplänen bei Rhönradwettkämpfen im DTB"))
+            .version(Some(APP_VERSION))
+            .website(Some("https://github.com/philippremy/dtb-kampfrichtereinsatzplaene"))
+            .website_label(Some("GitHub Repository"))
+            .build()
+        ))
+        .separator()
+        .item(&MenuItemBuilder::new("Einstellungen...").id("showSettings").accelerator("CmdOrCtrl+,").build(app_handle).unwrap())
+        .separator()
+        .services()
+        .separator()
+        .hide()
+        .hide_others()
+        .show_all()
+        .separator()
+        .quit()
+        .build()
+        .unwrap();
+}
 
+fn build_file_submenu(menuKind: &MenuKind, app_handle: &AppHandle) -> Submenu<Wry> {
+    let recent_submenu = SubmenuBuilder::with_id(app_handle, "recentFiles", "Zuletzt geöffnet")
+        .build()
+        .unwrap();
+    fill_recent_files_submenu(&recent_submenu, app_handle);
+    let file_submenu = SubmenuBuilder::with_id(app_handle, "fileMenu", "Datei")
+        .item(&MenuItemBuilder::new("Neuer Wettkampf...").id("newWettkampf").accelerator("CmdOrCtrl+N").build(app_handle).unwrap())
+        .item(&MenuItemBuilder::new("Öffnen...").id("openWettkampf").accelerator("CmdOrCtrl+O").build(app_handle).unwrap())
+        .item(&recent_submenu)
+        .separator();
+    let file_submenu = match menuKind {
+        MenuKind::Editor => file_submenu
+            .item(&MenuItemBuilder::new("Speichern").id("save").accelerator("CmdOrCtrl+S").build(app_handle).unwrap())
+            .item(&MenuItemBuilder::new("Speichern unter...").id("saveAs").accelerator("CmdOrCtrl+Shift+S").build(app_handle).unwrap())
+            .separator()
+            .item(&MenuItemBuilder::new("Als Word-Datei exportieren...").id("exportDocx").accelerator("CmdOrCtrl+E").build(app_handle).unwrap())
+            .item(&MenuItemBuilder::new("Als PDF exportieren...").id("exportPdf").accelerator("CmdOrCtrl+Shift+E").build(app_handle).unwrap())
+            .separator(),
+        _ => file_submenu,
+    };
+    return file_submenu
+        .item(&MenuItemBuilder::new("Schließen").id("closeWindow").accelerator("CmdOrCtrl+W").build(app_handle).unwrap())
+        .build()
+        .unwrap();
+}
+
+fn build_edit_submenu(menuKind: &MenuKind, app_handle: &AppHandle) -> Submenu<Wry> {
     match menuKind {
-        MenuKind::Default => {
-            let app_submenu = SubmenuBuilder::new(app_handle, "DTB Kampfrichtereinsatzpläne")
-                .about(Some(AboutMetadataBuilder::new()
-                    .authors(Some(vec!["Philipp Remy <philipp.remy@dtb.de>".to_string()]))
-                    .license(Some("GPL-3.0-only"))
-                    .copyright(Some("© Philipp Remy 2024"))
-                    .comments(Some("Ein Programm zum Erstellen von Kampfrichtereinsatzplänen bei Rhönradwettkämpfen im DTB"))
-                    .version(Some(APP_VERSION))
-                    .website(Some("https://github.com/philippremy/dtb-kampfrichtereinsatzplaene"))
-                    .website_label(Some("GitHub Repository"))
-                    .build()
-                ))
-                .separator()
-                .services()
-                .separator()
-                .hide()
-                .hide_others()
-                .show_all()
-                .separator()
-                .quit()
-                .build()
-                .unwrap();
-            let edit_submenu = SubmenuBuilder::new(app_handle, "Bearbeiten")
-                .undo()
-                .redo()
-                .separator()
-                .cut()
-                .copy()
-                .paste()
-                .select_all()
-                .build()
-                .unwrap();
-            let view_submenu = SubmenuBuilder::new(app_handle, "Darstellung")
-                .fullscreen()
-                .build()
-                .unwrap();
-            let window_submenu = SubmenuBuilder::new(app_handle, "Fenster")
-                .minimize()
-                .maximize()
-                .separator()
-                .close_window()
-                .build()
-                .unwrap();
-            let help_submenu = SubmenuBuilder::new(app_handle, "Hilfe")
-                .item(&MenuItemBuilder::new("Hilfe").id("help").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Was ist neu?").id("whatsnew").build(app_handle).unwrap())
-                .separator()
-                .item(&MenuItemBuilder::new("Support kontaktieren").id("contactSupport").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Bug melden").id("bugReport").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Feedback geben").id("feedback").build(app_handle).unwrap())
-                .separator()
-                .item(&MenuItemBuilder::new("Logs anzeigen").id("showLogs").build(app_handle).unwrap())
-                .separator()
-                .item(&MenuItemBuilder::new("Open-Source Lizenzen").id("showLicenses").build(app_handle).unwrap())
-                .build()
-                .unwrap();
-            let menu = MenuBuilder::new(app_handle)
-                .build()
-                .unwrap();
-            menu.append(&app_submenu).unwrap();
-            menu.append(&edit_submenu).unwrap();
-            menu.append(&view_submenu).unwrap();
-            menu.append(&window_submenu).unwrap();
-            menu.append(&help_submenu).unwrap();
-            menu.set_as_app_menu().unwrap();
-            return menu;
-        },
-        MenuKind::CreateWettkampf => {
-            let app_submenu = SubmenuBuilder::new(app_handle, "DTB Kampfrichtereinsatzpläne")
-                .about(Some(AboutMetadataBuilder::new()
-                    .authors(Some(vec!["Philipp Remy <philipp.remy@dtb.de>".to_string()]))
-                    .license(Some("GPL-3.0-only"))
-                    .copyright(Some("© Philipp Remy 2024"))
-                    .comments(Some("Ein Programm zum Erstellen von Kampfrichtereinsatzplänen bei Rhönradwettkämpfen im DTB"))
-                    .version(Some(APP_VERSION))
-                    .website(Some("https://github.com/philippremy/dtb-kampfrichtereinsatzplaene"))
-                    .website_label(Some("GitHub Repository"))
-                    .build()
-                ))
-                .separator()
-                .services()
-                .separator()
-                .hide()
-                .hide_others()
-                .show_all()
-                .separator()
-                .quit()
-                .build()
-                .unwrap();
-            let edit_submenu = SubmenuBuilder::new(app_handle, "Bearbeiten")
-                .undo()
-                .redo()
-                .separator()
-                .cut()
-                .copy()
-                .paste()
-                .select_all()
-                .build()
-                .unwrap();
-            let view_submenu = SubmenuBuilder::new(app_handle, "Darstellung")
-                .fullscreen()
-                .build()
-                .unwrap();
-            let window_submenu = SubmenuBuilder::new(app_handle, "Fenster")
-                .minimize()
-                .maximize()
-                .separator()
-                .close_window()
-                .build()
-                .unwrap();
-            let help_submenu = SubmenuBuilder::new(app_handle, "Hilfe")
-                .item(&MenuItemBuilder::new("Hilfe").id("help").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Was ist neu?").id("whatsnew").build(app_handle).unwrap())
-                .separator()
-                .item(&MenuItemBuilder::new("Support kontaktieren").id("contactSupport").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Bug melden").id("bugReport").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Feedback geben").id("feedback").build(app_handle).unwrap())
-                .separator()
-                .item(&MenuItemBuilder::new("Logs anzeigen").id("showLogs").build(app_handle).unwrap())
-                .separator()
-                .item(&MenuItemBuilder::new("Open-Source Lizenzen").id("showLicenses").build(app_handle).unwrap())
-                .build()
-                .unwrap();
-            let menu = MenuBuilder::new(app_handle)
-                .build()
-                .unwrap();
-            menu.append(&app_submenu).unwrap();
-            menu.append(&edit_submenu).unwrap();
-            menu.append(&view_submenu).unwrap();
-            menu.append(&window_submenu).unwrap();
-            menu.append(&help_submenu).unwrap();
-            menu.set_as_app_menu().unwrap();
-            return menu;
-        },
         MenuKind::Editor => {
-            let app_submenu = SubmenuBuilder::new(app_handle, "DTB Kampfrichtereinsatzpläne")
-                .about(Some(AboutMetadataBuilder::new()
-                    .authors(Some(vec!["Philipp Remy <philipp.remy@dtb.de>".to_string()]))
-                    .license(Some("GPL-3.0-only"))
-                    .copyright(Some("© Philipp Remy 2024"))
-                    .comments(Some("Ein Programm zum Erstellen von Kampfrichtereinsatzplänen bei Rhönradwettkämpfen im DTB"))
-                    .version(Some(APP_VERSION))
-                    .website(Some("https://github.com/philippremy/dtb-kampfrichtereinsatzplaene"))
-                    .website_label(Some("GitHub Repository"))
-                    .build()
-                ))
-                .separator()
-                .services()
-                .separator()
-                .hide()
-                .hide_others()
-                .show_all()
-                .separator()
-                .quit()
-                .build()
-                .unwrap();
-            let edit_submenu = SubmenuBuilder::new(app_handle, "Bearbeiten")
-                .undo()
-                .redo()
-                .separator()
-                .cut()
-                .copy()
-                .paste()
-                .select_all()
-                .build()
-                .unwrap();
-            let view_submenu = SubmenuBuilder::new(app_handle, "Darstellung")
-                .fullscreen()
-                .build()
-                .unwrap();
-            let window_submenu = SubmenuBuilder::new(app_handle, "Fenster")
-                .minimize()
-                .maximize()
-                .separator()
-                .close_window()
-                .build()
-                .unwrap();
-            let help_submenu = SubmenuBuilder::new(app_handle, "Hilfe")
-                .item(&MenuItemBuilder::new("Hilfe").id("help").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Was ist neu?").id("whatsnew").build(app_handle).unwrap())
-                .separator()
-                .item(&MenuItemBuilder::new("Support kontaktieren").id("contactSupport").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Bug melden").id("bugReport").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Feedback geben").id("feedback").build(app_handle).unwrap())
-                .separator()
-                .item(&MenuItemBuilder::new("Logs anzeigen").id("showLogs").build(app_handle).unwrap())
-                .separator()
-                .item(&MenuItemBuilder::new("Open-Source Lizenzen").id("showLicenses").build(app_handle).unwrap())
-                .build()
-                .unwrap();
-            let menu = MenuBuilder::new(app_handle)
-                .build()
-                .unwrap();
-            menu.append(&app_submenu).unwrap();
-            menu.append(&edit_submenu).unwrap();
-            menu.append(&view_submenu).unwrap();
-            menu.append(&window_submenu).unwrap();
-            menu.append(&help_submenu).unwrap();
-            menu.set_as_app_menu().unwrap();
-            return menu;
-        },
-        MenuKind::BugReport => {
-            let app_submenu = SubmenuBuilder::new(app_handle, "DTB Kampfrichtereinsatzpläne")
-                .about(Some(AboutMetadataBuilder::new()
-                    .authors(Some(vec!["Philipp Remy <philipp.remy@dtb.de>".to_string()]))
-                    .license(Some("GPL-3.0-only"))
-                    .copyright(Some("© Philipp Remy 2024"))
-                    .comments(Some("Ein Programm zum Erstellen von Kampfrichtereinsatzplänen bei Rhönradwettkämpfen im DTB"))
-                    .version(Some(APP_VERSION))
-                    .website(Some("https://github.com/philippremy/dtb-kampfrichtereinsatzplaene"))
-                    .website_label(Some("GitHub Repository"))
-                    .build()
-                ))
-                .separator()
-                .services()
-                .separator()
-                .hide()
-                .hide_others()
-                .show_all()
-                .separator()
-                .quit()
-                .build()
-                .unwrap();
-            let edit_submenu = SubmenuBuilder::new(app_handle, "Bearbeiten")
-                .undo()
-                .redo()
+            // The disciplines are grouped by sport, like in the add button of the Editor
+            let mut add_table_submenu = SubmenuBuilder::new(app_handle, "Kampfgericht hinzufügen");
+            let mut last_sport: Option<&str> = None;
+            for discipline in DISCIPLINES {
+                if last_sport.is_some_and(|sport| sport != discipline.sport()) {
+                    add_table_submenu = add_table_submenu.separator();
+                }
+                add_table_submenu = add_table_submenu.item(&MenuItemBuilder::new(discipline.as_str()).id(format!("addTable:{}", discipline.as_str())).build(app_handle).unwrap());
+                last_sport = Some(discipline.sport());
+            }
+            let add_table_submenu = add_table_submenu.build().unwrap();
+
+            // Undo and redo go through the history of the document, not the one of the focused text field
+            return SubmenuBuilder::new(app_handle, "Bearbeiten")
+                .item(&MenuItemBuilder::new("Rückgängig").id("undo").accelerator("CmdOrCtrl+Z").build(app_handle).unwrap())
+                .item(&MenuItemBuilder::new("Wiederholen").id("redo").accelerator(REDO_ACCELERATOR).build(app_handle).unwrap())
                 .separator()
                 .cut()
                 .copy()
                 .paste()
                 .select_all()
-                .build()
-                .unwrap();
-            let view_submenu = SubmenuBuilder::new(app_handle, "Darstellung")
-                .fullscreen()
-                .build()
-                .unwrap();
-            let window_submenu = SubmenuBuilder::new(app_handle, "Fenster")
-                .minimize()
-                .maximize()
-                .separator()
-                .close_window()
-                .build()
-                .unwrap();
-            let help_submenu = SubmenuBuilder::new(app_handle, "Hilfe")
-                .item(&MenuItemBuilder::new("Hilfe").id("help").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Was ist neu?").id("whatsnew").build(app_handle).unwrap())
-                .separator()
-                .item(&MenuItemBuilder::new("Support kontaktieren").id("contactSupport").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Bug melden").id("bugReport").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Feedback geben").id("feedback").build(app_handle).unwrap())
                 .separator()
-                .item(&MenuItemBuilder::new("Logs anzeigen").id("showLogs").build(app_handle).unwrap())
+                .item(&MenuItemBuilder::new("Wettkampfdaten bearbeiten...").id("editWkData").build(app_handle).unwrap())
+                .item(&add_table_submenu)
+                .item(&MenuItemBuilder::new("Ersatzkampfrichter hinzufügen").id("addReplacementJudges").build(app_handle).unwrap())
                 .separator()
-                .item(&MenuItemBuilder::new("Open-Source Lizenzen").id("showLicenses").build(app_handle).unwrap())
-                .build()
-                .unwrap();
-            let menu = MenuBuilder::new(app_handle)
+                .item(&MenuItemBuilder::new("Kampfrichterliste importieren...").id("importRoster").build(app_handle).unwrap())
+                .item(&MenuItemBuilder::new("Kampfrichter automatisch einteilen").id("autoAssignJudges").build(app_handle).unwrap())
                 .build()
                 .unwrap();
-            menu.append(&app_submenu).unwrap();
-            menu.append(&edit_submenu).unwrap();
-            menu.append(&view_submenu).unwrap();
-            menu.append(&window_submenu).unwrap();
-            menu.append(&help_submenu).unwrap();
-            menu.set_as_app_menu().unwrap();
-            return menu;
         },
-        MenuKind::LicenseWindow => {
-            let app_submenu = SubmenuBuilder::new(app_handle, "DTB Kampfrichtereinsatzpläne")
-                .about(Some(AboutMetadataBuilder::new()
-                    .authors(Some(vec!["Philipp Remy <philipp.remy@dtb.de>".to_string()]))
-                    .license(Some("GPL-3.0-only"))
-                    .copyright(Some("© Philipp Remy 2024"))
-                    .comments(Some("Ein Programm zum Erstellen von Kampfrichtereinsatzplänen bei Rhönradwettkämpfen im DTB"))
-                    .version(Some(APP_VERSION))
-                    .website(Some("https://github.com/philippremy/dtb-kampfrichtereinsatzplaene"))
-                    .website_label(Some("GitHub Repository"))
-                    .build()
-                ))
-                .separator()
-                .services()
-                .separator()
-                .hide()
-                .hide_others()
-                .show_all()
-                .separator()
-                .quit()
-                .build()
-                .unwrap();
-            let edit_submenu = SubmenuBuilder::new(app_handle, "Bearbeiten")
+        _ => {
+            return SubmenuBuilder::new(app_handle, "Bearbeiten")
                 .undo()
                 .redo()
                 .separator()
@@ -1704,41 +1771,39 @@ fn build_menus(menuKind: MenuKind, app_handle: &AppHandle) -> Menu<Wry> {
                 .select_all()
                 .build()
                 .unwrap();
-            let view_submenu = SubmenuBuilder::new(app_handle, "Darstellung")
-                .fullscreen()
-                .build()
-                .unwrap();
-            let window_submenu = SubmenuBuilder::new(app_handle, "Fenster")
-                .minimize()
-                .maximize()
-                .separator()
-                .close_window()
-                .build()
-                .unwrap();
-            let help_submenu = SubmenuBuilder::new(app_handle, "Hilfe")
-                .item(&MenuItemBuilder::new("Hilfe").id("help").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Was ist neu?").id("whatsnew").build(app_handle).unwrap())
-                .separator()
-                .item(&MenuItemBuilder::new("Support kontaktieren").id("contactSupport").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Bug melden").id("bugReport").build(app_handle).unwrap())
-                .item(&MenuItemBuilder::new("Feedback geben").id("feedback").build(app_handle).unwrap())
-                .separator()
-                .item(&MenuItemBuilder::new("Logs anzeigen").id("showLogs").build(app_handle).unwrap())
-                .separator()
-                .item(&MenuItemBuilder::new("Open-Source Lizenzen").id("showLicenses").build(app_handle).unwrap())
-                .build()
-                .unwrap();
-            let menu = MenuBuilder::new(app_handle)
-                .build()
-                .unwrap();
-            menu.append(&app_submenu).unwrap();
-            menu.append(&edit_submenu).unwrap();
-            menu.append(&view_submenu).unwrap();
-            menu.append(&window_submenu).unwrap();
-            menu.append(&help_submenu).unwrap();
-            menu.set_as_app_menu().unwrap();
-            return menu;
         },
-    };
+    }
+}
+
+fn build_view_submenu(app_handle: &AppHandle) -> Submenu<Wry> {
+    return SubmenuBuilder::new(app_handle, "Darstellung")
+        .fullscreen()
+        .build()
+        .unwrap();
+}
+
+fn build_window_submenu(app_handle: &AppHandle) -> Submenu<Wry> {
+    return SubmenuBuilder::new(app_handle, "Fenster")
+        .minimize()
+        .maximize()
+        .separator()
+        .item(&MenuItemBuilder::new("Startfenster anzeigen").id("showMainWindow").build(app_handle).unwrap())
+        .build()
+        .unwrap();
+}
 
+fn build_help_submenu(app_handle: &AppHandle) -> Submenu<Wry> {
+    return SubmenuBuilder::new(app_handle, "Hilfe")
+        .item(&MenuItemBuilder::new("Was ist neu?").id("whatsnew").build(app_handle).unwrap())
+        .separator()
+        .item(&MenuItemBuilder::new("Support kontaktieren").id("contactSupport").build(app_handle).unwrap())
+        .item(&MenuItemBuilder::new("Bug melden").id("bugReport").build(app_handle).unwrap())
+        .item(&MenuItemBuilder::new("Feedback geben").id("feedback").build(app_handle).unwrap())
+        .separator()
+        .item(&MenuItemBuilder::new("Logs anzeigen").id("showLogs").build(app_handle).unwrap())
+        .item(&MenuItemBuilder::new("Diagnosedaten speichern...").id("saveDiagnostics").build(app_handle).unwrap())
+        .separator()
+        .item(&MenuItemBuilder::new("Open-Source Lizenzen").id("showLicenses").build(app_handle).unwrap())
+        .build()
+        .unwrap();
 }
\ No newline at end of file
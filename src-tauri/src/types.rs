@@ -1,34 +1,42 @@
+use crate::Disciplines::{Discipline, JudgeRole};
+use crate::Roster::RosterJudge;
+use crate::Validation::mark_conflicts;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, sync::Mutex};
+use std::collections::HashMap;
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Kampfrichter {
-    pub role: String,
+    pub role: JudgeRole,
     pub name: String,
     pub doubleFound: bool,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Kampfgericht {
     pub uniqueID: String,
     pub table_name: String,
-    pub table_kind: String,
+    pub table_kind: Discipline,
     pub table_is_finale: bool,
-    pub judges: HashMap<String, Kampfrichter>,
+    /// The clubs of the gymnasts competing at this table, none of their judges may judge it.
+    pub table_clubs: Vec<String>,
+    pub judges: HashMap<JudgeRole, Kampfrichter>,
 }
 
-#[derive(Debug, Default, Serialize, Deserialize)]
-pub struct Storage {
-    pub wk_name: Mutex<String>,
-    pub wk_date: Mutex<String>,
-    pub wk_place: Mutex<String>,
-    pub wk_responsible_person: Mutex<String>,
-    pub wk_judgesmeeting_time: Mutex<String>,
-    pub wk_replacement_judges: Mutex<Vec<String>>,
-    pub wk_judgingtables: Mutex<HashMap<String, Kampfgericht>>,
+/// The data of one competition, as written to .wkdata files.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Wettkampf {
+    pub wk_name: String,
+    pub wk_date: String,
+    pub wk_place: String,
+    pub wk_responsible_person: String,
+    pub wk_judgesmeeting_time: String,
+    pub wk_replacement_judges: Vec<String>,
+    /// Imported from GymNet, only used by the backend and for suggestions in the editor.
+    pub wk_judge_roster: Vec<RosterJudge>,
+    pub wk_judgingtables: HashMap<String, Kampfgericht>,
 }
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct FrontendStorage {
     pub wk_name: String,
     pub wk_date: String,
@@ -39,54 +47,83 @@ pub struct FrontendStorage {
     pub wk_judgingtables: Option<HashMap<String, Kampfgericht>>,
 }
 
+impl Wettkampf {
+    /// Applies the data edited in the frontend.
+    /// The roster is only managed by the backend and kept. Missing tables or replacement judges count as empty.
+    /// The doubleFound flags of the frontend are not trusted, but recalculated.
+    pub fn apply_frontend(&mut self, frontend_storage: FrontendStorage) {
+        self.wk_name = frontend_storage.wk_name;
+        self.wk_date = frontend_storage.wk_date;
+        self.wk_place = frontend_storage.wk_place;
+        self.wk_responsible_person = frontend_storage.wk_responsible_person;
+        self.wk_judgesmeeting_time = frontend_storage.wk_judgesmeeting_time;
+        self.wk_replacement_judges = frontend_storage.wk_replacement_judges.unwrap_or_default();
+        self.wk_judgingtables = frontend_storage.wk_judgingtables.unwrap_or_default();
+        mark_conflicts(&mut self.wk_judgingtables);
+    }
+
+    pub fn to_frontend(&self) -> FrontendStorage {
+        return FrontendStorage {
+            wk_name: self.wk_name.clone(),
+            wk_date: self.wk_date.clone(),
+            wk_place: self.wk_place.clone(),
+            wk_responsible_person: self.wk_responsible_person.clone(),
+            wk_judgesmeeting_time: self.wk_judgesmeeting_time.clone(),
+            wk_replacement_judges: Some(self.wk_replacement_judges.clone()),
+            wk_judgingtables: Some(self.wk_judgingtables.clone()),
+        };
+    }
+}
+
+/// The kind of an error. The numbers are sent to the frontend and written to the logs, so they must stay stable:
+/// Retired codes are never reused. See Errors.rs for the details shown to the user.
 #[repr(C)]
-#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub enum ApplicationError {
     UnknownError = -1,
     NoError = 0,
     MutexPoisonedError = 1,
     JSONSerializeError = 2,
-    CStringNullError = 3,
-    MarshalJSONNullError = 4,
-    DeserializeArgumentNullError = 5,
-    DeserializeJSONError = 6,
-    DeserializeNotSupportedError = 7,
+    // 3 to 7 were the errors of passing the data to the .NET library, which is gone
     TauriWindowCreationError = 8,
     TauriWindowShowError = 9,
     RustWriteFileError = 10,
-    MarshalSavePathNullError = 11,
-    StorageNullError = 12,
-    CSharpWriteError = 13,
+    // 11 to 13 were the errors of the .NET library as well
     JSONDeserializeImporterError = 14,
-    FailedToCreateStdOutFileError = 15,
-    FailedToCreateStdErrFileError = 16,
-    LibcDup2StdOutError = 17,
-    LibcDup2StdErrError = 18,
-    CSharpPDFSavePathIsEmpty = 19,
-    ChromeDownloadError = 20,
-    ChromiumBinaryIsUnexpectedlyNone = 21,
-    BrowserCouldNotBeBuild = 22,
-    NewTabCouldNotBeCreated = 23,
-    NavigationToGeneratedHTMLFileFailed = 24,
-    WaitingForNavigationFailed = 25,
-    PDFGenerationInChromiumFailed = 26,
-    WritingPDFDataToDiskFailed = 27,
-    RemovalOfTemporaryGeneratedFilesFailed = 28,
+    // 15 to 18 were the errors of redirecting stdout and stderr into log files, see Logging.rs for what replaced it
+    PdfSavePathIsEmpty = 19,
+    // 20 to 28 were the errors of the PDF generation through Chromium, which is gone
     SMTPConnectionError = 29,
     MessageSendError = 30,
     TauriExistingWindowNotFoundError = 31,
-    WaitingForWindowsPDFResult = 32
+    // 32 was the PDF export of the .NET library still running on Windows
+    NewerFileFormatError = 33,
+    FileMigrationError = 34,
+    UnresolvedJudgeConflictsError = 35,
+    TemplateReadError = 36,
+    DocxGenerationError = 37,
+    PdfGenerationError = 38,
+    RosterImportError = 39,
+    DocumentNotFoundError = 40,
+    AutosaveReadError = 41,
+    InvalidSettingsError = 42,
+    SettingsWriteError = 43,
+    LogFileCreationError = 44,
+    LogReadError = 45,
+    DiagnosticsWriteError = 46,
+    OutboxWriteError = 47,
+    ShowItemInFolderError = 48
 }
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct UpdateAvailablePayload {
-    pub(crate) body: String,
-    pub(crate) date: String,
-    pub(crate) version: String,
+    pub body: String,
+    pub date: String,
+    pub version: String,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct UpdateProgressPayload {
-    pub(crate) chunk_len: usize,
-    pub(crate) content_len: Option<u64>,
+    pub chunk_len: usize,
+    pub content_len: Option<u64>,
 }
@@ -1,17 +1,38 @@
-import { useEffect, useId, useState } from "react";
+import { useEffect, useId, useRef, useState } from "react";
 import "./App.css";
 // @ts-ignore
 // This error is a straight-up lie
 import dtbLogo from "./assets/dtb-logo.svg";
 import dtbLogoLight from "./assets/dtb-logo-light.svg";
-import { FluentProvider, webLightTheme, webDarkTheme, Title2, Image, Button, useToastController, Toast, ToastTitle, ToastBody, Toaster, Dialog, DialogSurface, DialogBody, DialogTitle, DialogContent, DialogActions, DialogTrigger, Field, ProgressBar } from "@fluentui/react-components";
-import { FolderOpenFilled, FormNewFilled } from "@fluentui/react-icons";
-import { open } from "@tauri-apps/plugin-dialog";
+import { FluentProvider, webLightTheme, webDarkTheme, Title2, Image, Button, useToastController, Toast, ToastTitle, ToastBody, Toaster, Dialog, DialogSurface, DialogBody, DialogTitle, DialogContent, DialogActions, DialogTrigger, Field, ProgressBar, Subtitle2, Caption1 } from "@fluentui/react-components";
+import { DocumentFilled, FolderOpenFilled, FormNewFilled } from "@fluentui/react-icons";
+import { ask, open } from "@tauri-apps/plugin-dialog";
 import { listen, UnlistenFn } from "@tauri-apps/api/event";
 import { marked } from "marked";
 import { relaunch } from "@tauri-apps/plugin-process";
 import { invoke } from "@tauri-apps/api/core";
 import { getCurrentWebviewWindow } from "@tauri-apps/api/webviewWindow";
+import { loadSettings } from "./Settings";
+import { describeError, isDetailedError } from "./Errors";
+
+// Autosave of a competition that was not saved before the app was closed, as provided by the backend
+type AutosaveInfo = {
+  id: string,
+  wk_name: string,
+  source_path: string | null,
+  autosaved: string,
+}
+
+// Recently opened or saved competition file, as provided by the backend
+type RecentFile = {
+  path: string,
+  wk_name: string,
+  wk_date: string,
+  last_opened: string,
+}
+
+// The main window only has room for the most recent ones, the menu lists all of them
+const SHOWN_RECENT_FILES = 5;
 
 function App() {
   function parseMarkdown(body: string) {
@@ -130,6 +151,60 @@ function App() {
     };
   }, []);
 
+  // Offer to restore the competitions whose unsaved changes were autosaved before a crash
+  // StrictMode mounts twice during development, the ref keeps us from asking twice
+  const autosavesChecked = useRef(false);
+  useEffect(() => {
+    if (autosavesChecked.current) {
+      return;
+    }
+    autosavesChecked.current = true;
+    restoreAutosaves().then(() => {});
+  }, []);
+
+  // Recent files, so the last competitions can be opened without a file dialog
+  const [recentFiles, setRecentFiles] = useState<Array<RecentFile>>([]);
+  useEffect(() => {
+    loadRecentFiles();
+  }, []);
+
+  function loadRecentFiles() {
+    invoke("get_recent_files").then((response) => {
+      setRecentFiles((response as Array<RecentFile>).slice(0, SHOWN_RECENT_FILES));
+    });
+  }
+
+  function openRecentFile(path: string) {
+    invoke("import_wk_file_and_open_editor", { filepath: path }).then(
+      () => {
+        const thisWindow = getCurrentWebviewWindow();
+        thisWindow.close().then(() => {});
+      },
+    ).catch((error) => {
+      showBackendError(error);
+      // The file might be gone, the backend drops it from the list then
+      loadRecentFiles();
+    });
+  }
+
+  async function restoreAutosaves() {
+    const autosaves = (await invoke("get_restorable_autosaves")) as Array<AutosaveInfo>;
+    for (const autosave of autosaves) {
+      const source = autosave.source_path !== null
+        ? "Die Sicherung ist neuer als die Datei " + autosave.source_path + "."
+        : "Der Wettkampf wurde noch nie gespeichert.";
+      const restore = await ask(
+        "Vom Wettkampf \"" + autosave.wk_name + "\" gibt es eine automatische Sicherung vom " + autosave.autosaved + " mit ungespeicherten Änderungen. " + source + "\n\nSoll die Sicherung wiederhergestellt werden?",
+        { title: "Wettkampf wiederherstellen", kind: "warning", okLabel: "Wiederherstellen", cancelLabel: "Verwerfen" },
+      );
+      if (restore) {
+        await invoke("restore_autosave", { id: autosave.id }).catch(showBackendError);
+      } else {
+        await invoke("discard_autosave", { id: autosave.id });
+      }
+    }
+  }
+
   // States for the update dialog
   const [updateDialogOpen, setUpdateDialogOpen] = useState(false);
   const [updateDialogTitle, setUpdateDialogTitle] = useState("");
@@ -142,14 +217,10 @@ function App() {
 
   // Function to create a new wettkampf window
   async function createWettkampf() {
-    invoke("create_wettkampf").then((result) => {
-      if (result === "NoError") {
-        const thisWindow = getCurrentWebviewWindow();
-        thisWindow.close();
-        return;
-      }
-      showBackendError(String(result));
-    });
+    invoke("create_wettkampf").then(() => {
+      const thisWindow = getCurrentWebviewWindow();
+      thisWindow.close();
+    }).catch((error) => showBackendError(error));
   }
 
   // Dialog states
@@ -159,58 +230,50 @@ function App() {
 
   // Toaster Stuff
   const { dispatchToast, updateToast } = useToastController();
-  const showBackendError = (error: string) =>
+  // Takes the DetailedError of the backend
+  const showBackendError = (error: unknown) => {
+    let title = "Ein Fehler ist aufgetreten.";
+    if (isDetailedError(error) && error.code === "NewerFileFormatError") {
+      title = "Die Datei ist zu neu.";
+    } else if (isDetailedError(error) && error.code === "FileMigrationError") {
+      title = "Die Datei konnte nicht konvertiert werden.";
+    }
     dispatchToast(
       <Toast>
-        <ToastTitle>Ein Backend-Fehler ist aufgetreten.</ToastTitle>
-        <ToastBody>Rust gab folgenden Fehler zurück: {error}</ToastBody>
+        <ToastTitle>{title}</ToastTitle>
+        <ToastBody>{describeError(error)}</ToastBody>
       </Toast>,
       { intent: "error" },
     );
+  };
 
   // Function to open a Wettkampf
-  function openWettkampf() {
+  async function openWettkampf() {
+    const settings = await loadSettings();
     open({
       title: "Wettkampfdatei öffnen...",
+      defaultPath: settings.default_save_dir ?? undefined,
       multiple: false,
       filters: [{ name: "Wettkampfdatei", extensions: ["wkdata"] }],
     }).then((file) => {
       if (file === null) {
         return;
       } else if (Array.isArray(file)) {
-        invoke("import_wk_file_and_open_editor", { filepath: file[0].path }).then(
-          (response) => {
-            if (response === "NoError") {
-              return;
-            } else {
-              showBackendError(response as string);
-            }
-          },
-        );
+        invoke("import_wk_file_and_open_editor", { filepath: file[0].path }).catch(showBackendError);
       } else if (typeof file == "object") {
         invoke("import_wk_file_and_open_editor", { filepath: file.path }).then(
-          (response) => {
-            if (response === "NoError") {
-              const thisWindow = getCurrentWebviewWindow();
-              thisWindow.close().then(() => {});
-              return;
-            } else {
-              showBackendError(response as string);
-            }
+          () => {
+            const thisWindow = getCurrentWebviewWindow();
+            thisWindow.close().then(() => {});
           },
-        );
+        ).catch(showBackendError);
       } else {
         invoke("import_wk_file_and_open_editor", { filepath: file }).then(
-          (response) => {
-            if (response === "NoError") {
-              const thisWindow = getCurrentWebviewWindow();
-              thisWindow.close().then(() => {});
-              return;
-            } else {
-              showBackendError(response as string);
-            }
+          () => {
+            const thisWindow = getCurrentWebviewWindow();
+            thisWindow.close().then(() => {});
           },
-        );
+        ).catch(showBackendError);
       }
     });
   }
@@ -254,6 +317,29 @@ function App() {
             Wettkampf öffnen
           </Button>
         </div>
+        {recentFiles.length !== 0 && (
+          <div id="recentFilesContainer">
+            <Subtitle2>Zuletzt geöffnet</Subtitle2>
+            {recentFiles.map((recentFile) => (
+              <Button
+                key={recentFile.path}
+                appearance="subtle"
+                icon={<DocumentFilled />}
+                className="recentFileButton"
+                onClick={() => openRecentFile(recentFile.path)}
+                disabled={buttonsDisabled}
+                title={recentFile.path}
+              >
+                <div className="recentFileLabel">
+                  <span>{recentFile.wk_name !== "" ? recentFile.wk_name : recentFile.path}</span>
+                  <Caption1>
+                    {recentFile.wk_date !== "" ? recentFile.wk_date + " · " : ""}zuletzt geöffnet am {new Date(recentFile.last_opened).toLocaleString("de-DE", { dateStyle: "medium", timeStyle: "short" })}
+                  </Caption1>
+                </div>
+              </Button>
+            ))}
+          </div>
+        )}
       </div>
       <Toaster></Toaster>
       <Dialog modalType={"alert"} open={dialogOpen}>
@@ -1,11 +1,16 @@
-import { Body1Strong, Button, Caption1Strong, Checkbox, Divider, Field, FluentProvider, Input, Spinner, Textarea, webDarkTheme, webLightTheme } from "@fluentui/react-components";
+import { Body1Strong, Button, Caption1, Caption1Strong, Checkbox, Divider, Field, FluentProvider, Input, Spinner, Textarea, webDarkTheme, webLightTheme } from "@fluentui/react-components";
 import { getCurrentWebviewWindow } from "@tauri-apps/api/webviewWindow";
 import React from "react";
 import "./BugReporter.css";
-import { LaptopPersonFilled, MailboxFilled, TargetFilled, SendFilled, ErrorCircleFilled } from "@fluentui/react-icons";
+import { LaptopPersonFilled, MailboxFilled, TargetFilled, SendFilled, ErrorCircleFilled, ArrowSyncFilled, DeleteFilled } from "@fluentui/react-icons";
 import { invoke } from "@tauri-apps/api/core";
+import { listen, UnlistenFn } from "@tauri-apps/api/event";
+import { describeError } from "./Errors";
+import { loadOutboxMessages, MailDelivery, PendingMessage, retryOutboxMessages } from "./Outbox";
 
-export default class BugReporter extends React.Component<{}, {isLight: boolean, title: string | null, name: string | null , mail: string | null, subject: string, message: string, sendLogs: boolean, loadingState: "initial" | "loading" | "error", errorText: string | null, errorTextClass: "none" | "errorText" | "successText"}> {
+export default class BugReporter extends React.Component<{}, {isLight: boolean, title: string | null, name: string | null , mail: string | null, subject: string, message: string, sendLogs: boolean, loadingState: "initial" | "loading" | "error", errorText: string | null, errorTextClass: "none" | "errorText" | "successText", pendingMessages: Array<PendingMessage>, retrying: boolean}> {
+
+    unlistenOutbox: Promise<UnlistenFn> | null = null;
 
     constructor(props: {}) {
         super(props);
@@ -20,6 +25,8 @@ export default class BugReporter extends React.Component<{}, {isLight: boolean,
           loadingState: "initial",
           errorText: null,
           errorTextClass: "none",
+          pendingMessages: [],
+          retrying: false,
         }
     }
 
@@ -37,6 +44,28 @@ export default class BugReporter extends React.Component<{}, {isLight: boolean,
         const darkModePreference = window.matchMedia("(prefers-color-scheme: dark)");
         darkModePreference.matches ? this.setState({isLight: false}) : this.setState({isLight: true})
         darkModePreference.addEventListener("change", e => e.matches ? this.setState({isLight: false}) : this.setState({isLight: true}));
+        this.loadPendingMessages();
+        // The backend retries the messages in the background
+        this.unlistenOutbox = listen("outboxChanged", () => this.loadPendingMessages());
+    }
+
+    componentWillUnmount(): void {
+        this.unlistenOutbox?.then((unlisten) => unlisten());
+    }
+
+    loadPendingMessages() {
+        loadOutboxMessages().then((messages) => this.setState({pendingMessages: messages}));
+    }
+
+    retryPendingMessages() {
+        this.setState({retrying: true});
+        retryOutboxMessages().then((messages) => this.setState({pendingMessages: messages})).finally(() => this.setState({retrying: false}));
+    }
+
+    cancelPendingMessage(id: string) {
+        invoke("cancel_outbox_message", { id: id }).then(() => this.loadPendingMessages()).catch((error) => {
+          this.setState({errorText: describeError(error), errorTextClass: "errorText"});
+        });
     }
 
     async submit() {
@@ -48,13 +77,16 @@ export default class BugReporter extends React.Component<{}, {isLight: boolean,
           this.setState({mail: null});
         };
         invoke("send_mail_from_frontend", { name: this.state.name, mail: this.state.mail, subject: this.state.subject, message: this.state.message, sendlogs: this.state.sendLogs, kind: this.state.title }).then((response) => {
-          if(response === "NoError") {
+          if(response as MailDelivery === "Sent") {
             this.setState({errorText: "Mail wurde erfolgreich verschickt! Das Fenster kann geschlossen werden.", errorTextClass: "successText"});
-            this.setState({loadingState: "initial"});
           } else {
-            this.setState({errorText: "Mail konnte nicht verschickt werden. Bitte die Logs überprüfen.", errorTextClass: "errorText"});
-            this.setState({loadingState: "error"});
+            this.setState({errorText: "Keine Verbindung. Die Mail wird automatisch verschickt, sobald eine Verbindung besteht.", errorTextClass: "successText"});
           }
+          this.setState({loadingState: "initial"});
+          this.loadPendingMessages();
+        }).catch((error) => {
+          this.setState({errorText: "Mail konnte nicht verschickt werden. " + describeError(error), errorTextClass: "errorText"});
+          this.setState({loadingState: "error"});
         });
     }
 
@@ -87,6 +119,24 @@ export default class BugReporter extends React.Component<{}, {isLight: boolean,
                     <Textarea placeholder="Mir ist Folgendes aufgefallen: [...]" id="nachricht" resize="none" onInput={(ev) => this.setState({message: ev.currentTarget.value})} autoCapitalize={"off"} autoCorrect={"off"} />
                   </Field>
                   <Checkbox label={"Logs mitsenden?"} defaultChecked={this.state.sendLogs} onChange={(_ev, data) => this.setState({sendLogs: data.checked as boolean})} />
+                  {this.state.pendingMessages.length > 0 &&
+                  <div id="outbox">
+                    <div className="outboxHeader">
+                      <Caption1Strong>{"Noch nicht verschickt (" + this.state.pendingMessages.length + "):"}</Caption1Strong>
+                      <div className="spacer"></div>
+                      <Button appearance="subtle" size="small" icon={this.state.retrying ? <Spinner size="tiny" /> : <ArrowSyncFilled />} onClick={() => this.retryPendingMessages()} disabledFocusable={this.state.retrying}>Jetzt senden</Button>
+                    </div>
+                    {this.state.pendingMessages.map((message) =>
+                      <div key={message.id} className="outboxMessage">
+                        <div className="outboxMessageText">
+                          <Caption1Strong>{message.subject}</Caption1Strong>
+                          <Caption1>{"Erstellt " + message.created + ", " + message.attempts + " Versuche, " + (message.given_up ? "wird nicht mehr automatisch gesendet" : "nächster Versuch " + message.next_attempt) + (message.last_error === null ? "" : " (" + message.last_error + ")")}</Caption1>
+                        </div>
+                        <Button appearance="subtle" size="small" icon={<DeleteFilled />} onClick={() => this.cancelPendingMessage(message.id)} disabledFocusable={this.state.retrying} title="Verwerfen" />
+                      </div>
+                    )}
+                  </div>
+                  }
                   <div id="buttonDiv">
                     <Caption1Strong className={this.state.errorTextClass}>{this.state.errorText}</Caption1Strong>
                     <div className="spacer"></div>
@@ -8,6 +8,8 @@ import { CalendarFilled, PersonFilled, PinFilled, TimePickerFilled, TrophyFilled
 import { invoke } from "@tauri-apps/api/core";
 import { getCurrentWebviewWindow } from "@tauri-apps/api/webviewWindow";
 import { FrontendStorage } from "./Editor.tsx";
+import { loadSettings } from "./Settings";
+import { describeError } from "./Errors";
 
 function CreateWettkampf() {
 
@@ -19,6 +21,12 @@ function CreateWettkampf() {
   }, []);
   const [isLight, setIsLight] = useState(true);
 
+  // Prefilled from the settings, the input is remounted once they are loaded
+  useEffect(() => {
+    loadSettings().then((settings) => setDefaultResponsiblePerson(settings.default_responsible_person));
+  }, []);
+  const [defaultResponsiblePerson, setDefaultResponsiblePerson] = useState("");
+
   // States for Form Validation
   const [nameState, setNameState] = useState<"none" | "error" | "success" | "warning" | undefined>("none");
   const [placeState, setPlaceState] = useState<"none" | "error" | "success" | "warning" | undefined>("none");
@@ -133,13 +141,10 @@ function CreateWettkampf() {
       wk_responsible_person: document.getElementById(responsiblePersonInput)!.getAttribute("value")!
 
     };
-    invoke("sync_wk_data_and_open_editor", {data: wkData}).then((result) => {
-      if(result === "NoError") {
-        const thisWindow = getCurrentWebviewWindow();
-        thisWindow.close().then(() => {});
-      }
-      showBackendError(String(result));
-    });
+    invoke("sync_wk_data_and_open_editor", {data: wkData}).then(() => {
+      const thisWindow = getCurrentWebviewWindow();
+      thisWindow.close().then(() => {});
+    }).catch((error) => showBackendError(describeError(error)));
   }
 
   // Toaster Stuff
@@ -147,7 +152,7 @@ function CreateWettkampf() {
   const showBackendError = (error: string) => dispatchToast(
       <Toast>
         <ToastTitle>Ein Backend-Fehler ist aufgetreten.</ToastTitle>
-        <ToastBody>Rust gab folgenden Fehler zurück: {error}</ToastBody>
+        <ToastBody>{error}</ToastBody>
       </Toast>,
       { intent: "error" }
   );
@@ -173,7 +178,7 @@ function CreateWettkampf() {
             <Input id={timeInput} type="time" placeholder="" onInput={(data) => validateFormInput(data)} contentBefore={<TimePickerFilled></TimePickerFilled>} />
           </Field>
           <Field label={"Kampfrichterbeauftragte*r"} validationState={personState} validationMessage={personValidationMessage} required={true} className="wkField">
-            <Input id={responsiblePersonInput} onInput={(data) => validateFormInput(data)} contentBefore={<PersonFilled></PersonFilled>} autoCapitalize={"off"} autoCorrect={"off"} />
+            <Input id={responsiblePersonInput} key={defaultResponsiblePerson} defaultValue={defaultResponsiblePerson} onInput={(data) => validateFormInput(data)} contentBefore={<PersonFilled></PersonFilled>} autoCapitalize={"off"} autoCorrect={"off"} />
           </Field>
         </div>
         <div id="confirmButtonDiv">
@@ -0,0 +1,39 @@
+import { invoke } from "@tauri-apps/api/core";
+
+// Role slot of a discipline, as described by the backend
+export type RoleSchema = {
+    role: string,
+    label: string,
+    required: boolean,
+}
+
+// Discipline Interface
+export type DisciplineSchema = {
+    discipline: string,
+    sport: string,
+    roles: Array<RoleSchema>,
+}
+
+// The schemas never change while the app is running, so we only ask the backend once
+let schemaRequest: Promise<Array<DisciplineSchema>> | undefined = undefined;
+
+export function loadDisciplineSchemas(): Promise<Array<DisciplineSchema>> {
+    if(schemaRequest === undefined) {
+        schemaRequest = invoke("get_discipline_schemas").then((response) => response as Array<DisciplineSchema>);
+    }
+    return schemaRequest;
+}
+
+// Groups the disciplines by their sport, keeping the order of the backend
+export function groupBySport(schemas: Array<DisciplineSchema>): Array<[string, Array<DisciplineSchema>]> {
+    let groups: Array<[string, Array<DisciplineSchema>]> = [];
+    schemas.forEach((schema) => {
+        const group = groups.find((group) => group[0] === schema.sport);
+        if(group === undefined) {
+            groups.push([schema.sport, [schema]]);
+        } else {
+            group[1].push(schema);
+        }
+    });
+    return groups;
+}
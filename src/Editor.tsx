@@ -1,14 +1,17 @@
 import { Button, Caption2, Dialog, DialogActions, DialogBody, DialogContent, DialogSurface, DialogTitle, DialogTrigger, Field, FluentProvider, Input, Link, Menu, MenuButton, MenuButtonProps, MenuItem, MenuList, MenuPopover, MenuTrigger, Spinner, SplitButton, Subtitle2, Text, Toast, ToastBody, Toaster, ToastFooter, ToastIntent, ToastTitle, ToastTrigger, useToastController, webDarkTheme, webLightTheme, Divider } from "@fluentui/react-components";
 import { AddFilled, CalendarFilled, CheckmarkFilled, ChevronDownRegular, DocumentFilled, ErrorCircleFilled, PenFilled, PersonFilled, PinFilled, SaveFilled, TimePickerFilled, TrophyFilled } from "@fluentui/react-icons";
 import { invoke } from "@tauri-apps/api/core";
-import React, { useEffect, useId, useState } from "react";
+import { getCurrentWebviewWindow } from "@tauri-apps/api/webviewWindow";
+import React, { useEffect, useId, useRef, useState } from "react";
 import "./Editor.css";
 import { v4 as uuidv4 } from 'uuid';
 import KampfgerichteRenderer from "./KampfgerichteRenderer";
-import { ask, save } from "@tauri-apps/plugin-dialog";
-import { getCurrentWebviewWindow } from "@tauri-apps/api/webviewWindow";
+import { ask, message, open as openDialog, save } from "@tauri-apps/plugin-dialog";
 import ReplacementJudges from "./ReplacementJudges.tsx";
-import { listen } from "@tauri-apps/api/event";
+import { DisciplineSchema, groupBySport, loadDisciplineSchemas } from "./Disciplines";
+import { importJudgeRoster, loadJudgeRoster, RosterJudge, ROSTER_DATALIST_ID } from "./Roster";
+import { loadSettings } from "./Settings";
+import { describeError } from "./Errors";
 
 // Kampfrichter Interface
 export type Kampfrichter = {
@@ -23,9 +26,17 @@ export type Kampfgericht = {
     table_name: string,
     table_kind: string,
     table_is_finale: boolean,
+    table_clubs: Array<string>,
     judges: Map<string, Kampfrichter>,
 }
 
+// Double-booked judge, as detected by the backend
+export type JudgeConflict = {
+    judge_name: string,
+    is_finale: boolean,
+    assignments: Array<{table_id: string, table_name: string, role: string}>,
+}
+
 // Frontend Storage Interface
 export type FrontendStorage = {
     wk_name: string,
@@ -38,6 +49,48 @@ export type FrontendStorage = {
     changedByDoubleHook: boolean,
 }
 
+// Result of the automatic assignment of judges
+type SolverResult = {
+    wk_judgingtables: object,
+    wk_replacement_judges: Array<string>,
+    unfilled: Array<{table_id: string, table_name: string, role: string | null, required: boolean, reason: "UnknownDiscipline" | "NoLicensedJudge" | "OnlyOwnClubJudges" | "AllJudgesAssigned"}>,
+}
+
+// We have to recreate the maps, because they cannot be deserialized into a Map from JSON
+// Neither can we cast them to a Map or create them from the Object Array.
+// So the build process has to be manual.
+function judgingTablesFromBackend(tables: object | undefined): Map<string, Kampfgericht> {
+    let judgingTableMap = new Map<string, Kampfgericht>();
+    if(tables === undefined || tables === null) {
+        return judgingTableMap;
+    }
+    Object.entries(tables).forEach((pair) => {
+        // The complicated Map in the Map :(
+        const kampfrichterValues = Object.entries(pair[1]["judges"]);
+        let judgesMap = new Map<string, Kampfrichter>();
+        kampfrichterValues.forEach((secondPair) => {
+            // @ts-ignore
+            const name = secondPair[1]["name"];
+            // @ts-ignore
+            const doubleFound = secondPair[1]["doubleFound"];
+            judgesMap.set(secondPair[0], {
+                role: secondPair[0],
+                name: name,
+                doubleFound: doubleFound,
+            });
+        });
+        judgingTableMap.set(pair[0], {
+            judges: judgesMap,
+            table_is_finale: pair[1]["table_is_finale"],
+            table_kind: pair[1]["table_kind"],
+            table_name: pair[1]["table_name"],
+            table_clubs: pair[1]["table_clubs"],
+            uniqueID: pair[0],
+        });
+    });
+    return judgingTableMap;
+}
+
 function Editor() {
     
     // Prevent any unwanted manual reloads
@@ -47,6 +100,11 @@ function Editor() {
         event.preventDefault();
         console.log("Prevented an (unwanted) manual reload operation of the page.");
       }
+      // The menu redoes with Ctrl+Y on Windows, Ctrl+Shift+Z works there as on the other systems
+      if (navigator.userAgent.includes("Windows") && event.ctrlKey && event.shiftKey && event.key.toLowerCase() === 'z') {
+        event.preventDefault();
+        invoke('redo_edit').catch((error) => console.error(error));
+      }
     });
 
     document.addEventListener('contextmenu', function(event) {
@@ -82,43 +140,7 @@ function Editor() {
                 }
             }
 
-            // We have to recreate the maps, because they cannot be deserialized into a Map from JSON
-            // Neither can we cast them to a Map or create them from the Object Array.
-            // So the build process has to be manual.
-            // @ts-ignore
-            const kampfgerichtValues = Object.entries(backendStorage.wk_judgingtables);
-            let judgingTableMap = new Map<string, Kampfgericht>();
-            kampfgerichtValues.forEach((pair) => {
-                // The easy stuff.
-                // @ts-ignore
-                const tableName = pair[1]["table_name"];
-                // @ts-ignore
-                const tableKind = pair[1]["table_kind"];
-                // @ts-ignore
-                const tableIsFinale = pair[1]["table_is_finale"];
-                // The complicated Map in the Map :(
-                // @ts-ignore
-                const kampfrichterValues = Object.entries(pair[1]["judges"]);
-                let judgesMap = new Map<string, Kampfrichter>();
-                kampfrichterValues.forEach((secondPair) => {
-                    // @ts-ignore
-                    const name = secondPair[1]["name"];
-                    // @ts-ignore
-                    const doubleFound = secondPair[1]["doubleFound"];
-                    judgesMap.set(secondPair[0], {
-                        role: secondPair[0],
-                        name: name,
-                        doubleFound: doubleFound,
-                    });
-                });
-                judgingTableMap.set(pair[0], {
-                    judges: judgesMap,
-                    table_is_finale: tableIsFinale,
-                    table_kind: tableKind,
-                    table_name: tableName,
-                    uniqueID: pair[0],
-                });
-            });
+            const judgingTableMap = judgingTablesFromBackend(backendStorage.wk_judgingtables);
             if(backendStorage.wk_judgingtables !== undefined) {
                 setFrontendStorage({
                     changedByDoubleHook: true,
@@ -135,6 +157,8 @@ function Editor() {
             if(backendStorage.wk_replacement_judges !== undefined && backendStorage.wk_replacement_judges.length !== 0) {
                 setEditorExists(true);
             }
+        }).catch((error) => {
+            console.error("Could not load the Wettkampf from the backend: " + describeError(error));
         });
     }, []);
 
@@ -147,6 +171,19 @@ function Editor() {
         }
     }
 
+    // Asks the backend for double-booked judges and lets the user decide whether to create the plans anyway
+    async function getPlanApproval() {
+        const conflicts = await invoke("get_judge_conflicts", {frontendstorage: frontendStorage}) as Array<JudgeConflict>;
+        if(conflicts.length === 0) {
+            return true;
+        }
+        const description = conflicts.map((conflict) => {
+            const assignments = conflict.assignments.map((assignment) => assignment.table_name + " (" + assignment.role.toUpperCase() + ")").join(", ");
+            return "• " + conflict.judge_name + (conflict.is_finale ? " im Finale: " : " im Vorkampf: ") + assignments;
+        }).join("\n");
+        return await ask("Folgende Kampfrichter sind mehrfach eingeteilt:\n\n" + description + "\n\nSollen die Pläne trotzdem erstellt werden?", {title: "Überschneidungen gefunden", kind: "warning"});
+    }
+
     // This is the default button!
     const [lastSavePath, setLastSavePath] = useState<string | undefined>(undefined);
     async function saveWettkampf() {
@@ -161,8 +198,9 @@ function Editor() {
     }
 
     // Speichern-unter... Funktion hihi
-    function saveUnder() {
-        save({filters: [{name: "Wettkampfdatei (.wkdata)", extensions: ["wkdata"]}], title: "Wettkampf speichern unter..."}).then((filePath) => {
+    async function saveUnder() {
+        const settings = await loadSettings();
+        save({filters: [{name: "Wettkampfdatei (.wkdata)", extensions: ["wkdata"]}], title: "Wettkampf speichern unter...", defaultPath: settings.default_save_dir ?? undefined}).then((filePath) => {
             if(filePath === null) {
                 return;
             } else {
@@ -177,15 +215,11 @@ function Editor() {
 
     function syncToBackendAndSaveWettkampf(path: string) {
         displayToast("saveToast", "Bitten warten", "Wettkampf wird gespeichert...", <Spinner size="tiny" />, -1);
-        invoke("sync_to_backend_and_save", {frontendstorage: frontendStorage, filepath: path}).then((response) => {
-            if(response !== "NoError") {
-                updateToastWithID("saveToast", "error", "Fehler", "Ein Fehler ist aufgetreten: " +  response, <ErrorCircleFilled />, 3000);
-            } else {
-                updateToastWithID("saveToast", "success", "Speichern erfolgreich", "Der Wettkampf wurde gespeichert.", <CheckmarkFilled />, 3000, <Link onClick={() => {showInFolder(path)}}>Im Explorer anzeigen</Link>);
-                setLastSavePath(path);
-                let currentWindow = getCurrentWebviewWindow();
-                currentWindow.setTitle(frontendStorage.wk_name + " (gespeichert)").then(() => {});
-            }
+        invoke("sync_to_backend_and_save", {frontendstorage: frontendStorage, filepath: path}).then(() => {
+            updateToastWithID("saveToast", "success", "Speichern erfolgreich", "Der Wettkampf wurde gespeichert.", <CheckmarkFilled />, 3000, <Link onClick={() => {showInFolder(path)}}>Im Explorer anzeigen</Link>);
+            setLastSavePath(path);
+        }).catch((error) => {
+            updateToastWithID("saveToast", "error", "Fehler", describeError(error), <ErrorCircleFilled />, 3000);
         });
     }
 
@@ -204,6 +238,104 @@ function Editor() {
         return storage;
     });
 
+    // The disciplines and their roles, as defined by the backend
+    const [disciplineSchemas, setDisciplineSchemas] = useState<Array<DisciplineSchema>>([]);
+    useEffect(() => {
+        loadDisciplineSchemas().then((schemas) => setDisciplineSchemas(schemas));
+    }, []);
+
+    // The judges imported from GymNet, for autocompletion and name checks
+    const [judgeRoster, setJudgeRoster] = useState<Array<RosterJudge>>([]);
+    useEffect(() => {
+        loadJudgeRoster().then((roster) => setJudgeRoster(roster));
+    }, []);
+
+    // Replaces the roster with a GymNet export
+    function importRoster() {
+        openDialog({filters: [{name: "GymNet-Export (.csv, .xlsx)", extensions: ["csv", "xlsx"]}], title: "Kampfrichterliste importieren", multiple: false, directory: false}).then((filePath) => {
+            if(filePath === null) {
+                return;
+            }
+            displayToast("rosterToast", "Bitten warten", "Kampfrichterliste wird importiert...", <Spinner size="tiny" />, -1);
+            importJudgeRoster(filePath).then((roster) => {
+                setJudgeRoster(roster);
+                updateToastWithID("rosterToast", "success", "Import erfolgreich", roster.length + " Kampfrichter wurden importiert.", <CheckmarkFilled />, 3000);
+            }).catch((error) => {
+                updateToastWithID("rosterToast", "error", "Fehler", describeError(error), <ErrorCircleFilled />, 3000);
+            });
+        });
+    }
+
+    // The inputs only read their values when they are created, so they are recreated whenever the backend replaces the data
+    const [reloadCount, setReloadCount] = useState(0);
+
+    // Undo and redo run in the backend (Edit menu), which sends the resulting data to this window
+    useEffect(() => {
+        const unlisten = getCurrentWebviewWindow().listen<FrontendStorage>("wkDataChanged", (event) => {
+            setFrontendStorage({
+                changedByDoubleHook: false,
+                wk_date: event.payload.wk_date,
+                wk_judgesmeeting_time: event.payload.wk_judgesmeeting_time,
+                wk_judgingtables: judgingTablesFromBackend(event.payload.wk_judgingtables),
+                wk_name: event.payload.wk_name,
+                wk_place: event.payload.wk_place,
+                wk_replacement_judges: event.payload.wk_replacement_judges,
+                wk_responsible_person: event.payload.wk_responsible_person
+            });
+            if(event.payload.wk_replacement_judges !== undefined && event.payload.wk_replacement_judges.length !== 0) {
+                setEditorExists(true);
+            }
+            // The roster is part of the history as well
+            loadJudgeRoster().then((roster) => setJudgeRoster(roster));
+            setReloadCount((count) => count + 1);
+        });
+        return () => {
+            unlisten.then((unlistenFunction) => unlistenFunction());
+        };
+    }, []);
+
+    // Fills all empty judge slots from the roster, judges entered by hand are kept
+    async function autoAssignJudges() {
+        if(!await ask("Sollen alle leeren Plätze automatisch mit Kampfrichtern aus der Kampfrichterliste besetzt werden?", {title: "Kampfrichter automatisch einteilen"})) {
+            return;
+        }
+        // The seed only breaks ties, so another run may give another (equally good) assignment
+        const seed = Math.floor(Math.random() * 4294967296);
+        let result: SolverResult;
+        try {
+            result = await invoke("auto_assign_judges", {frontendstorage: frontendStorage, seed: seed}) as SolverResult;
+        } catch(error) {
+            displayToast("assignToast", "Fehler", describeError(error), <ErrorCircleFilled />, 3000);
+            return;
+        }
+        setFrontendStorage({
+            ...frontendStorage,
+            wk_judgingtables: judgingTablesFromBackend(result.wk_judgingtables),
+            wk_replacement_judges: result.wk_replacement_judges,
+            changedByDoubleHook: false,
+        });
+        if(result.wk_replacement_judges.length !== 0) {
+            setEditorExists(true);
+        }
+        setReloadCount(reloadCount + 1);
+
+        const requiredSlots = result.unfilled.filter((slot) => slot.required);
+        if(requiredSlots.length === 0) {
+            displayToast("assignToast", "Einteilung abgeschlossen", "Alle Pflichtplätze wurden besetzt.", <CheckmarkFilled />, 3000);
+            return;
+        }
+        const reasons = {
+            UnknownDiscipline: "unbekannte Disziplin",
+            NoLicensedJudge: "kein Kampfrichter mit passender Lizenz",
+            OnlyOwnClubJudges: "nur Kampfrichter aus startenden Vereinen",
+            AllJudgesAssigned: "alle passenden Kampfrichter sind bereits eingeteilt",
+        };
+        const description = requiredSlots.map((slot) => {
+            return "• " + slot.table_name + (slot.role !== null ? " (" + slot.role.toUpperCase() + ")" : "") + ": " + reasons[slot.reason];
+        }).join("\n");
+        await message("Folgende Pflichtplätze konnten nicht besetzt werden:\n\n" + description, {title: "Einteilung unvollständig", kind: "warning"});
+    }
+
     // State for the Dialog
     const [open, setOpen] = useState(false);
     const [kindToCreate, setKindToCreate] = useState("");
@@ -225,6 +357,7 @@ function Editor() {
                     table_name: name,
                     table_kind: kind,
                     table_is_finale: false,
+                    table_clubs: [],
                     judges: new Map<string, Kampfrichter>(),
                 });
             } else {
@@ -234,6 +367,7 @@ function Editor() {
                     table_name: name,
                     table_kind: kind,
                     table_is_finale: false,
+                    table_clubs: [],
                     judges: new Map<string, Kampfrichter>(),
                 });
             }
@@ -278,18 +412,18 @@ function Editor() {
         frontendStorage.wk_judgingtables.forEach((table) => {
             if(table.table_is_finale) {
                 table.judges.forEach((judge) => {
-                    if(doublesFinale.has(judge.name)) {
-                        doublesFinale.set(judge.name, (doublesFinale.get(judge.name)! + 1));
+                    if(doublesFinale.has(judge.name.trim())) {
+                        doublesFinale.set(judge.name.trim(), (doublesFinale.get(judge.name.trim())! + 1));
                     } else {
-                        doublesFinale.set(judge.name, 1);
+                        doublesFinale.set(judge.name.trim(), 1);
                     }
                 });
             } else {
                 table.judges.forEach((judge) => {
-                    if(doublesNormal.has(judge.name)) {
-                        doublesNormal.set(judge.name, (doublesNormal.get(judge.name)! + 1));
+                    if(doublesNormal.has(judge.name.trim())) {
+                        doublesNormal.set(judge.name.trim(), (doublesNormal.get(judge.name.trim())! + 1));
                     } else {
-                        doublesNormal.set(judge.name, 1);
+                        doublesNormal.set(judge.name.trim(), 1);
                     }
                 });
             }
@@ -305,7 +439,7 @@ function Editor() {
         temp_storage.wk_judgingtables.forEach((table) => {
             if(table.table_is_finale) {
                 table.judges.forEach((judge) => {
-                    let count = doublesFinale.get(judge.name)!;
+                    let count = doublesFinale.get(judge.name.trim())!;
                     if(count < 2) {
                         judge.doubleFound = false;
                     } else {
@@ -315,7 +449,7 @@ function Editor() {
                 })
             } else {
                 table.judges.forEach((judge) => {
-                    let count = doublesNormal.get(judge.name)!;
+                    let count = doublesNormal.get(judge.name.trim())!;
                     if(count < 2) {
                         judge.doubleFound = false;
                     } else {
@@ -328,10 +462,10 @@ function Editor() {
 
         temp_storage.changedByDoubleHook = true;
         setFrontendStorage(Object.assign({}, temp_storage));
-        let currentWindow = getCurrentWebviewWindow();
-        if(temp_storage.wk_name !== "") {
-            currentWindow.setTitle(temp_storage.wk_name + " (nicht gespeichert)").then(() => {});
-        }
+        // The backend tracks the unsaved changes and keeps the window title up to date
+        invoke("update_storage_data", {frontendStorage: temp_storage}).catch((error) => {
+            console.error("Could not sync the Wettkampf to the backend: " + describeError(error));
+        });
 
     }, [frontendStorage]);
 
@@ -407,55 +541,50 @@ function Editor() {
 
     // Function to create plans as docx/pdf
     async function createPlans(type: string) {
-        if(! await getUserApproval()) {
+        if(! await getPlanApproval()) {
             return;
         }
+        const settings = await loadSettings();
         if(type === "pdf") {
-            save({filters: [{name: "Adobe Acrobat Portable Document File (.pdf)", extensions: ["pdf"]}], title: "Einsatzplan speichern als PDF"}).then((filePath) => {
+            save({filters: [{name: "Adobe Acrobat Portable Document File (.pdf)", extensions: ["pdf"]}], title: "Einsatzplan speichern als PDF", defaultPath: settings.default_save_dir ?? undefined}).then((filePath) => {
                 if(filePath === null) {
                     return;
                 } else {
                     if(!filePath.endsWith(".pdf")) {
                         filePath = filePath + ".pdf";
                     }
-                    syncWithBackendAndCreate(filePath, "pdf");
+                    syncWithBackendAndCreate(filePath, "pdf", true);
                 }
             });
         } else {
-            save({filters: [{name: "Open XML Wordprocessing Document (.docx)", extensions: ["docx"]}], title: "Einsatzplan speichern als DOCX"}).then((filePath) => {
+            save({filters: [{name: "Open XML Wordprocessing Document (.docx)", extensions: ["docx"]}], title: "Einsatzplan speichern als DOCX", defaultPath: settings.default_save_dir ?? undefined}).then((filePath) => {
                 if(filePath === null) {
                     return;
                 } else {
                     if(!filePath.endsWith(".docx")) {
                         filePath = filePath + ".docx";
                     }
-                    syncWithBackendAndCreate(filePath, "docx");
+                    syncWithBackendAndCreate(filePath, "docx", true);
                 }
             });
         }
     }
 
     // Function to sync with backend and create the plans
-    function syncWithBackendAndCreate(path: string, type: string) {
-        setLastPlanSavePath(path);
+    // force: The user accepted any double-booked judges
+    function syncWithBackendAndCreate(path: string, type: string, force: boolean) {
         displayToast("createToast", "Bitten warten", "Einsatzplan wird erstellt...", <Spinner size="tiny" />, -1);
         if(type === "docx") {
-            invoke("sync_to_backend_and_create_docx", {frontendstorage: frontendStorage, filepath: path}).then((response) => {
-                if(response !== "NoError") {
-                    updateToastWithID("createToast", "error", "Fehler", "Ein Fehler ist aufgetreten: " +  response, <ErrorCircleFilled />, 3000);
-                } else {
-                    updateToastWithID("createToast", "success", "Speichern erfolgreich", "Der Einsatzplan wurde erfolgreich gespeichert.", <CheckmarkFilled />, 3000, <Link onClick={() => {showInFolder(path)}}>Im Explorer anzeigen</Link>);
-                }
+            invoke("sync_to_backend_and_create_docx", {frontendstorage: frontendStorage, filepath: path, force: force}).then(() => {
+                updateToastWithID("createToast", "success", "Speichern erfolgreich", "Der Einsatzplan wurde erfolgreich gespeichert.", <CheckmarkFilled />, 3000, <Link onClick={() => {showInFolder(path)}}>Im Explorer anzeigen</Link>);
+            }).catch((error) => {
+                updateToastWithID("createToast", "error", "Fehler", describeError(error), <ErrorCircleFilled />, 3000);
             });
         } else if(type === "pdf") {
-            invoke("sync_to_backend_and_create_pdf", {frontendstorage: frontendStorage, filepath: path}).then((response) => {
-                if(response !== "NoError" && response !== "WaitingForWindowsPDFResult") {
-                    updateToastWithID("createToast", "error", "Fehler", "Ein Fehler ist aufgetreten: " +  response, <ErrorCircleFilled />, 3000);
-                } else if(response === "WaitingForWindowsPDFResult") {
-                    return;
-                } else {
-                    updateToastWithID("createToast", "success", "Speichern erfolgreich", "Der Einsatzplan wurde erfolgreich gespeichert.", <CheckmarkFilled />, 3000, <Link onClick={() => {showInFolder(path)}}>Im Explorer anzeigen</Link>);
-                }
+            invoke("sync_to_backend_and_create_pdf", {frontendstorage: frontendStorage, filepath: path, force: force}).then(() => {
+                updateToastWithID("createToast", "success", "Speichern erfolgreich", "Der Einsatzplan wurde erfolgreich gespeichert.", <CheckmarkFilled />, 3000, <Link onClick={() => {showInFolder(path)}}>Im Explorer anzeigen</Link>);
+            }).catch((error) => {
+                updateToastWithID("createToast", "error", "Fehler", describeError(error), <ErrorCircleFilled />, 3000);
             });
         }
     }
@@ -514,15 +643,54 @@ function Editor() {
     // Everything for the replacement judges
     const [editorExists, setEditorExists] = useState(false);
 
-    // Listen for the event emitted by the Windows PDF Creation Routine
-    const [lastPlanSavePath, setLastPlanSavePath] = useState<string>("");
-    listen<{ operation_succeeded: boolean }>("pdfCreationFinishedWindows", (response) => {
-        if(!response.payload.operation_succeeded) {
-            updateToastWithID("createToast", "error", "Fehler", "Ein Fehler ist aufgetreten. Bitte Logs überprüfen.", <ErrorCircleFilled />, 3000);
-        } else {
-            updateToastWithID("createToast", "success", "Speichern erfolgreich", "Der Einsatzplan wurde erfolgreich gespeichert.", <CheckmarkFilled />, 3000, <Link onClick={() => {showInFolder(lastPlanSavePath)}}>Im Explorer anzeigen</Link>);
+    // The File and Edit menus of the window, forwarded by the backend. They do the same as the buttons.
+    // The handler is replaced on every render, so it always sees the current state.
+    const menuActionHandler = useRef<(action: string) => void>(() => {});
+    menuActionHandler.current = (action: string) => {
+        switch(action) {
+            case "save":
+                saveWettkampf();
+                break;
+            case "saveAs":
+                saveUnder();
+                break;
+            case "exportDocx":
+                createPlans("docx");
+                break;
+            case "exportPdf":
+                createPlans("pdf");
+                break;
+            case "editWkData":
+                setWkOpen(true);
+                break;
+            case "addReplacementJudges":
+                setEditorExists(true);
+                break;
+            case "importRoster":
+                importRoster();
+                break;
+            case "autoAssignJudges":
+                if(judgeRoster.length === 0) {
+                    displayToast("assignToast", "Keine Kampfrichterliste", "Bitte zuerst eine Kampfrichterliste importieren.", <ErrorCircleFilled />, 3000);
+                } else {
+                    autoAssignJudges();
+                }
+                break;
+            default:
+                if(action.startsWith("addTable:")) {
+                    setKindToCreate(action.substring("addTable:".length));
+                    setOpen(true);
+                }
         }
-    }).then(() => {});
+    };
+    useEffect(() => {
+        const unlisten = getCurrentWebviewWindow().listen<string>("menuAction", (event) => {
+            menuActionHandler.current(event.payload);
+        });
+        return () => {
+            unlisten.then((unlistenFunction) => unlistenFunction());
+        };
+    }, []);
 
     return (
         <FluentProvider theme={isLight ? webLightTheme : webDarkTheme}>
@@ -563,29 +731,33 @@ function Editor() {
                 </div>
             </div>
             <div id="mainContents">
-                <KampfgerichteRenderer storage={frontendStorage} setStorage={setFrontendStorage} />
-                <ReplacementJudges hidden={!editorExists} storage={frontendStorage} setStorage={setFrontendStorage} setHidden={setEditorExists} />
+                <KampfgerichteRenderer key={"tables" + reloadCount} storage={frontendStorage} setStorage={setFrontendStorage} schemas={disciplineSchemas} roster={judgeRoster} />
+                <ReplacementJudges key={"replacements" + reloadCount} hidden={!editorExists} storage={frontendStorage} setStorage={setFrontendStorage} setHidden={setEditorExists} />
                 <div className="filler" />
             </div>
+            <datalist id={ROSTER_DATALIST_ID}>
+                {judgeRoster.map((judge) => <option key={judge.name} value={judge.name}>{judge.club}</option>)}
+            </datalist>
             <Menu>
                 <MenuTrigger disableButtonEnhancement>
                     <MenuButton appearance="primary" icon={<AddFilled />} menuIcon={<ChevronDownRegular />} id="addButton"></MenuButton>
                 </MenuTrigger>
                 <MenuPopover>
                     <MenuList>
-                        <MenuItem disabled={true}>Rhönradturnen</MenuItem>
-                        <Divider />
-                        <MenuItem onClick={() => {setKindToCreate("Geradeturnen ohne Musik"); setOpen(true)}}>Geradeturnen ohne Musik</MenuItem>
-                        <MenuItem onClick={() => {setKindToCreate("Geradeturnen auf Musik"); setOpen(true)}}>Geradeturnen auf Musik</MenuItem>
-                        <MenuItem onClick={() => {setKindToCreate("Spiraleturnen"); setOpen(true)}}>Spiraleturnen</MenuItem>
-                        <MenuItem onClick={() => {setKindToCreate("Sprung"); setOpen(true)}}>Sprung</MenuItem>
-                        <Divider />
-                        <MenuItem disabled={true}>Cyr Wheel</MenuItem>
-                        <Divider />
-                        <MenuItem onClick={() => {setKindToCreate("Artistisches Programm"); setOpen(true)}}>Artistisches Programm</MenuItem>
-                        <MenuItem onClick={() => {setKindToCreate("Technisches Programm"); setOpen(true)}}>Technisches Programm</MenuItem>
-                        <Divider />
+                        {
+                            groupBySport(disciplineSchemas).map((group) => (
+                                <React.Fragment key={group[0]}>
+                                    <MenuItem disabled={true}>{group[0]}</MenuItem>
+                                    <Divider />
+                                    {group[1].map((schema) => <MenuItem key={schema.discipline} onClick={() => {setKindToCreate(schema.discipline); setOpen(true)}}>{schema.discipline}</MenuItem>)}
+                                    <Divider />
+                                </React.Fragment>
+                            ))
+                        }
                         <MenuItem onClick={() => {setEditorExists(true)}} disabled={editorExists}>Ersatzkampfrichter</MenuItem>
+                        <Divider />
+                        <MenuItem onClick={() => importRoster()}>Kampfrichterliste importieren...</MenuItem>
+                        <MenuItem onClick={() => autoAssignJudges()} disabled={judgeRoster.length === 0}>Kampfrichter automatisch einteilen</MenuItem>
                         { /* <MenuItem onClick={() => {setKindToCreate("Leer"); setOpen(true)}}>Leer</MenuItem> */ }
                     </MenuList>
                 </MenuPopover>
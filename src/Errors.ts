@@ -0,0 +1,23 @@
+// Error of the backend with the details of what went wrong, see Errors.rs
+export type DetailedError = {
+    // Name of the ApplicationError, e.g. "NewerFileFormatError"
+    code: string,
+    ffi_code: number,
+    // German description for the user
+    message: string,
+    path: string | null,
+    field: string | null,
+    causes: Array<string>,
+}
+
+export function isDetailedError(error: unknown): error is DetailedError {
+    return typeof error === "object" && error !== null && "code" in error && "message" in error;
+}
+
+// The message for the user, naming the file it is about
+export function describeError(error: unknown): string {
+    if(!isDetailedError(error)) {
+        return String(error);
+    }
+    return error.path === null ? error.message : error.message + " (" + error.path + ")";
+}
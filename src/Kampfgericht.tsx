@@ -2,44 +2,30 @@ import React from "react";
 import { FrontendStorage, Kampfgericht } from "./Editor";
 import "./Kampfgericht.css"
 import { Body1Stronger, Button, Caption1, Card, CardFooter, CardHeader, Checkbox, CheckboxOnChangeData, Combobox, Dialog, DialogActions, DialogBody, DialogContent, DialogSurface, DialogTitle, DialogTrigger, Field, Input, Option, Divider } from "@fluentui/react-components";
-import { CheckmarkFilled, PenFilled, WarningFilled, CopySelectFilled } from "@fluentui/react-icons";
+import { CheckmarkFilled, PenFilled, WarningFilled, CopySelectFilled, QuestionCircleFilled } from "@fluentui/react-icons";
 import { v4 as uuidv4 } from "uuid";
+import { DisciplineSchema, groupBySport, RoleSchema } from "./Disciplines";
+import { isOnRoster, RosterJudge, ROSTER_DATALIST_ID } from "./Roster";
 
 interface StateType {
-    ok: boolean | undefined
-    sk1: boolean | undefined
-    sk2: boolean | undefined
-    ak1: boolean | undefined
-    ak2: boolean | undefined
-    ak3: boolean | undefined
-    ak4: boolean | undefined
-    aik1: boolean | undefined
-    aik2: boolean | undefined
-    aik3: boolean | undefined
-    aik4: boolean | undefined
+    // Whether the judge of a role is booked twice, undefined if the role is empty
+    doubles: Record<string, boolean | undefined>,
     tableName: string | undefined,
     tableDiscipline: string | undefined,
+    // The clubs competing at this table, separated by commas
+    tableClubs: string,
     dialogOpen: boolean,
 }
 
-class KampfgerichtElement extends React.Component<{storage: FrontendStorage, setStorage: React.Dispatch<React.SetStateAction<FrontendStorage>>, uniqueID: string}, StateType> {
+class KampfgerichtElement extends React.Component<{storage: FrontendStorage, setStorage: React.Dispatch<React.SetStateAction<FrontendStorage>>, uniqueID: string, schemas: Array<DisciplineSchema>, roster: Array<RosterJudge>}, StateType> {
 
-    constructor(props: {storage: FrontendStorage, setStorage: React.Dispatch<React.SetStateAction<FrontendStorage>>, uniqueID: string}) {
+    constructor(props: {storage: FrontendStorage, setStorage: React.Dispatch<React.SetStateAction<FrontendStorage>>, uniqueID: string, schemas: Array<DisciplineSchema>, roster: Array<RosterJudge>}) {
         super(props);
         this.state = {
-            ok: undefined,
-            sk1: undefined,
-            sk2: undefined,
-            ak1: undefined,
-            ak2: undefined,
-            ak3: undefined,
-            ak4: undefined,
-            aik1: undefined,
-            aik2: undefined,
-            aik3: undefined,
-            aik4: undefined,
+            doubles: {},
             tableName: props.storage.wk_judgingtables?.get(this.props.uniqueID)?.table_name,
             tableDiscipline: props.storage.wk_judgingtables?.get(this.props.uniqueID)?.table_kind,
+            tableClubs: (props.storage.wk_judgingtables?.get(this.props.uniqueID)?.table_clubs ?? []).join(", "),
             dialogOpen: false,
         }
         // We do check here for our data, so why not perform checking if there is a double in our data here?
@@ -62,41 +48,8 @@ class KampfgerichtElement extends React.Component<{storage: FrontendStorage, set
                     let temp_storage = this.props.storage;
                     temp_storage.wk_judgingtables?.set(table[0], table[1]);
                     // After that set every empty thing back to undefined
-                    switch(data.currentTarget.id) {
-                        case "ok":
-                            if(this.state.ok !== undefined){this.setState({ok: undefined});}
-                            break;
-                        case "sk1":
-                            if(this.state.sk1 !== undefined){this.setState({sk1: undefined});}
-                            break;
-                        case "sk2":
-                            if(this.state.sk2 !== undefined){this.setState({sk2: undefined});}
-                            break;
-                        case "ak1":
-                            if(this.state.ak1 !== undefined){this.setState({ak1: undefined});}
-                            break;
-                        case "ak2":
-                            if(this.state.ak2 !== undefined){this.setState({ak2: undefined});}
-                            break;
-                        case "ak3":
-                            if(this.state.ak3 !== undefined){this.setState({ak3: undefined});}
-                            break;
-                        case "ak4":
-                            if(this.state.ak4 !== undefined){this.setState({ak4: undefined});}
-                            break;
-                        case "aik1":
-                            if(this.state.aik1 !== undefined){this.setState({aik1: undefined});}
-                            break;
-                        case "aik2":
-                            if(this.state.aik2 !== undefined){this.setState({aik2: undefined});}
-                            break;
-                        case "aik3":
-                            if(this.state.aik3 !== undefined){this.setState({aik3: undefined});}
-                            break;
-                        case "aik4":
-                            if(this.state.aik4 !== undefined){this.setState({aik4: undefined});}
-                            break;
-                    }
+                    const role = data.currentTarget.id;
+                    if(this.state.doubles[role] !== undefined){this.setState({doubles: {...this.state.doubles, [role]: undefined}});}
                     // Tell the frontendStorage that the Tables changed it
                     temp_storage.changedByDoubleHook = false;
                     // We have to trick React into thinking that this is another object!
@@ -118,145 +71,39 @@ class KampfgerichtElement extends React.Component<{storage: FrontendStorage, set
     }
 
     setIcon(type: string) {
-        switch(type) {
-            case "ok":
-                if(this.state.ok === undefined) {
-                    return undefined;
-                } else {
-                    return this.state.ok ? <WarningFilled color="#fde300" /> : <CheckmarkFilled color="#00cc6a"/>;
-                }
-            case "sk1":
-                if(this.state.sk1 === undefined) {
-                    return undefined;
-                } else {
-                    return this.state.sk1 ? <WarningFilled color="#fde300" /> : <CheckmarkFilled color="#00cc6a"/>;
-                }
-            case "sk2":
-                if(this.state.sk2 === undefined) {
-                    return undefined;
-                } else {
-                    return this.state.sk2 ? <WarningFilled color="#fde300" /> : <CheckmarkFilled color="#00cc6a"/>;
-                }
-            case "ak1":
-                if(this.state.ak1 === undefined) {
-                    return undefined;
-                } else {
-                    return this.state.ak1 ? <WarningFilled color="#fde300" /> : <CheckmarkFilled color="#00cc6a"/>;
-                }
-            case "ak2":
-                if(this.state.ak2 === undefined) {
-                    return undefined;
-                } else {
-                    return this.state.ak2 ? <WarningFilled color="#fde300" /> : <CheckmarkFilled color="#00cc6a"/>;
-                }
-            case "ak3":
-                if(this.state.ak3 === undefined) {
-                    return undefined;
-                } else {
-                    return this.state.ak3 ? <WarningFilled color="#fde300" /> : <CheckmarkFilled color="#00cc6a"/>;
-                }
-            case "ak4":
-                if(this.state.ak4 === undefined) {
-                    return undefined;
-                } else {
-                    return this.state.ak4 ? <WarningFilled color="#fde300" /> : <CheckmarkFilled color="#00cc6a"/>;
-                }
-            case "aik1":
-                if(this.state.aik1 === undefined) {
-                    return undefined;
-                } else {
-                    return this.state.aik1 ? <WarningFilled color="#fde300" /> : <CheckmarkFilled color="#00cc6a"/>;
-                }
-            case "aik2":
-                if(this.state.aik2 === undefined) {
-                    return undefined;
-                } else {
-                    return this.state.aik2 ? <WarningFilled color="#fde300" /> : <CheckmarkFilled color="#00cc6a"/>;
-                }
-            case "aik3":
-                if(this.state.aik3 === undefined) {
-                    return undefined;
-                } else {
-                    return this.state.aik3 ? <WarningFilled color="#fde300" /> : <CheckmarkFilled color="#00cc6a"/>;
-                }
-            case "aik4":
-                if(this.state.aik4 === undefined) {
-                    return undefined;
-                } else {
-                    return this.state.aik4 ? <WarningFilled color="#fde300" /> : <CheckmarkFilled color="#00cc6a"/>;
-                }
+        const double = this.state.doubles[type];
+        if(double === undefined) {
+            return undefined;
+        } else if(double) {
+            return <WarningFilled color="#fde300" />;
+        } else if(!isOnRoster(this.props.roster, this.dataSelf.judges.get(type)?.name ?? "")) {
+            // Not double-booked, but missing in the imported judge roster
+            return <QuestionCircleFilled color="#f7630c" />;
+        } else {
+            return <CheckmarkFilled color="#00cc6a"/>;
         }
     }
 
     matchTypeAndGetElements() {
 
-        switch (this.dataSelf.table_kind) {
-            case "Artistisches Programm":
-            case "Geradeturnen auf Musik":
-                return(
-                    <div className="fieldContainer">
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("ok") ? this.dataSelf.judges.get("ok")!.name : ""} contentBefore={"OK"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("ok")} className="inputType" id="ok" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("sk1") ? this.dataSelf.judges.get("sk1")!.name : ""} contentBefore={"SK1"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("sk1")} className="inputType" id="sk1" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("sk2") ? this.dataSelf.judges.get("sk2")!.name : ""} contentBefore={"SK2"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("sk2")} className="inputType" id="sk2" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("ak1") ? this.dataSelf.judges.get("ak1")!.name : ""} contentBefore={"AK1"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("ak1")} className="inputType" id="ak1" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("ak2") ? this.dataSelf.judges.get("ak2")!.name : ""} contentBefore={"AK2"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("ak2")} className="inputType" id="ak2" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("ak3") ? this.dataSelf.judges.get("ak3")!.name : ""} contentBefore={"AK3"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("ak3")} className="inputType" id="ak3" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("ak4") ? this.dataSelf.judges.get("ak4")!.name : ""} contentBefore={"AK4"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("ak4")} className="inputType" id="ak4" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("aik1") ? this.dataSelf.judges.get("aik1")!.name : ""} contentBefore={"AIK1"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("aik1")} className="inputType" id="aik1" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("aik2") ? this.dataSelf.judges.get("aik2")!.name : ""} contentBefore={"AIK2"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("aik2")} className="inputType" id="aik2" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("aik3") ? this.dataSelf.judges.get("aik3")!.name : ""} contentBefore={"AIK3"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("aik3")} className="inputType" id="aik3" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("aik4") ? this.dataSelf.judges.get("aik4")!.name : ""} contentBefore={"AIK4"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("aik4")} className="inputType" id="aik4" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                    </div>
-                );
-            default:
-                return(
-                    <div className="fieldContainer">
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("ok") ? this.dataSelf.judges.get("ok")!.name : ""} contentBefore={"OK"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("ok")} className="inputType" id="ok" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("sk1") ? this.dataSelf.judges.get("sk1")!.name : ""} contentBefore={"SK1"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("sk1")} className="inputType" id="sk1" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("sk2") ? this.dataSelf.judges.get("sk2")!.name : ""} contentBefore={"SK2"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("sk2")} className="inputType" id="sk2" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("ak1") ? this.dataSelf.judges.get("ak1")!.name : ""} contentBefore={"AK1"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("ak1")} className="inputType" id="ak1" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("ak2") ? this.dataSelf.judges.get("ak2")!.name : ""} contentBefore={"AK2"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("ak2")} className="inputType" id="ak2" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("ak3") ? this.dataSelf.judges.get("ak3")!.name : ""} contentBefore={"AK3"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("ak3")} className="inputType" id="ak3" autoCapitalize={"off"} autoCorrect={"off"} />
-                        </Field>
-                        <Field>
-                            <Input defaultValue={this.dataSelf.judges.get("ak4") ? this.dataSelf.judges.get("ak4")!.name : ""} contentBefore={"AK4"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon("ak4")} className="inputType" id="ak4" autoCapitalize={"off"} autoCorrect={"off"} />
+        // The roles of a discipline come from the backend.
+        // Unknown disciplines (e.g. from a newer version) show the roles that are already filled in.
+        const schema = this.props.schemas.find((schema) => schema.discipline === this.dataSelf.table_kind);
+        const roles: Array<RoleSchema> = schema !== undefined ? schema.roles : Array.from(this.dataSelf.judges.keys(), (role) => {
+            return {role: role, label: role.toUpperCase(), required: false};
+        });
+
+        return(
+            <div className="fieldContainer">
+                {
+                    roles.map((role) => (
+                        <Field key={role.role}>
+                            <Input defaultValue={this.dataSelf.judges.get(role.role) ? this.dataSelf.judges.get(role.role)!.name : ""} contentBefore={role.label} placeholder={role.required ? undefined : "optional"} onInput={(data) => this.updateValues(data)} contentAfter={this.setIcon(role.role)} className="inputType" id={role.role} list={ROSTER_DATALIST_ID} autoCapitalize={"off"} autoCorrect={"off"} />
                         </Field>
-                    </div>
-                );
-        }
+                    ))
+                }
+            </div>
+        );
     }
 
     updateToFinale(data: CheckboxOnChangeData) {
@@ -296,6 +143,7 @@ class KampfgerichtElement extends React.Component<{storage: FrontendStorage, set
         let table = temp_storage.wk_judgingtables!.get(this.props.uniqueID)!;
         table.table_name = this.state.tableName!;
         table.table_kind = this.state.tableDiscipline!;
+        table.table_clubs = this.state.tableClubs.split(",").map((club) => club.trim()).filter((club) => club !== "");
         temp_storage.wk_judgingtables!.set(this.props.uniqueID, table);
         temp_storage.changedByDoubleHook = false;
         this.props.setStorage(Object.assign({}, temp_storage));
@@ -317,6 +165,7 @@ class KampfgerichtElement extends React.Component<{storage: FrontendStorage, set
                     table_name: table[1].table_name,
                     table_kind: table[1].table_kind,
                     table_is_finale: table[1].table_is_finale,
+                    table_clubs: [...table[1].table_clubs],
                     judges: new Map(table[1].judges),
                 };
                 let temp_storage = Object.assign({}, this.props.storage);
@@ -337,43 +186,15 @@ class KampfgerichtElement extends React.Component<{storage: FrontendStorage, set
 
         for(const table of this.props.storage.wk_judgingtables!) {
             if(table[0] === this.props.uniqueID) {
+                let doubles = {...this.state.doubles};
+                let doublesChanged = false;
                 table[1].judges.forEach((judge, key) => {
-                    switch(key) {
-                        case "ok":
-                            if(this.state.ok !== judge.doubleFound){this.setState({ok: judge.doubleFound});}
-                            break;
-                        case "sk1":
-                            if(this.state.sk1 !== judge.doubleFound){this.setState({sk1: judge.doubleFound});}
-                            break;
-                        case "sk2":
-                            if(this.state.sk2 !== judge.doubleFound){this.setState({sk2: judge.doubleFound});}
-                            break;
-                        case "ak1":
-                            if(this.state.ak1 !== judge.doubleFound){this.setState({ak1: judge.doubleFound});}
-                            break;
-                        case "ak2":
-                            if(this.state.ak2 !== judge.doubleFound){this.setState({ak2: judge.doubleFound});}
-                            break;
-                        case "ak3":
-                            if(this.state.ak3 !== judge.doubleFound){this.setState({ak3: judge.doubleFound});}
-                            break;
-                        case "ak4":
-                            if(this.state.ak4 !== judge.doubleFound){this.setState({ak4: judge.doubleFound});}
-                            break;
-                        case "aik1":
-                            if(this.state.aik1 !== judge.doubleFound){this.setState({aik1: judge.doubleFound});}
-                            break;
-                        case "aik2":
-                            if(this.state.aik2 !== judge.doubleFound){this.setState({aik2: judge.doubleFound});}
-                            break;
-                        case "aik3":
-                            if(this.state.aik3 !== judge.doubleFound){this.setState({aik3: judge.doubleFound});}
-                            break;
-                        case "aik4":
-                            if(this.state.aik4 !== judge.doubleFound){this.setState({aik4: judge.doubleFound});}
-                            break;
+                    if(doubles[key] !== judge.doubleFound) {
+                        doubles[key] = judge.doubleFound;
+                        doublesChanged = true;
                     }
                 });
+                if(doublesChanged){this.setState({doubles: doubles});}
                 break;
             }
         }
@@ -409,19 +230,21 @@ class KampfgerichtElement extends React.Component<{storage: FrontendStorage, set
                                 </Field>
                                 <Field label={"Disziplin"} required={true}>
                                     <Combobox placeholder={this.dataSelf.table_kind} defaultValue={this.dataSelf.table_kind} onOptionSelect={(_ev, data) => this.setState({tableDiscipline: data.optionText})}>
-                                        <Option disabled={true}>Rhönradturnen</Option>
-                                        <Divider inset={true} />
-                                        <Option>Geradeturnen auf Musik</Option>
-                                        <Option>Geradeturnen ohne Musik</Option>
-                                        <Option>Spiraleturnen</Option>
-                                        <Option>Sprung</Option>
-                                        <Divider inset={true} />
-                                        <Option disabled={true}>Cyr Wheel</Option>
-                                        <Divider inset={true} />
-                                        <Option>Artistisches Programm</Option>
-                                        <Option>Technisches Programm</Option>
+                                        {
+                                            groupBySport(this.props.schemas).map((group, index) => (
+                                                <React.Fragment key={group[0]}>
+                                                    {index > 0 ? <Divider inset={true} /> : undefined}
+                                                    <Option disabled={true}>{group[0]}</Option>
+                                                    <Divider inset={true} />
+                                                    {group[1].map((schema) => <Option key={schema.discipline}>{schema.discipline}</Option>)}
+                                                </React.Fragment>
+                                            ))
+                                        }
                                     </Combobox>
                                 </Field>
+                                <Field label={"Startende Vereine"} hint={"Mehrere Vereine mit Komma trennen. Deren Kampfrichter werden bei der automatischen Einteilung nicht für dieses Kampfgericht eingeteilt."}>
+                                    <Input defaultValue={this.state.tableClubs} onInput={(data) => this.setState({tableClubs: data.currentTarget.value})} autoCapitalize={"off"} autoCorrect={"off"} />
+                                </Field>
                             </div>
                         </DialogContent>
                         <DialogActions>
@@ -443,6 +266,7 @@ class KampfgerichtElement extends React.Component<{storage: FrontendStorage, set
         table_name: "ERR",
         table_kind: "ERR",
         table_is_finale: false,
+        table_clubs: [],
         judges: new Map(),
     };
 
@@ -3,10 +3,12 @@ import React from "react";
 import { FrontendStorage, Kampfgericht } from "./Editor";
 import { Body2 } from "@fluentui/react-components";
 import { QuestionCircle32Filled } from "@fluentui/react-icons";
+import { DisciplineSchema } from "./Disciplines";
+import { RosterJudge } from "./Roster";
 
-class KampfgerichteRenderer extends React.Component<{storage: FrontendStorage, setStorage: React.Dispatch<React.SetStateAction<FrontendStorage>>}> {
+class KampfgerichteRenderer extends React.Component<{storage: FrontendStorage, setStorage: React.Dispatch<React.SetStateAction<FrontendStorage>>, schemas: Array<DisciplineSchema>, roster: Array<RosterJudge>}> {
 
-    constructor(props: {storage: FrontendStorage, setStorage: React.Dispatch<React.SetStateAction<FrontendStorage>>}) {
+    constructor(props: {storage: FrontendStorage, setStorage: React.Dispatch<React.SetStateAction<FrontendStorage>>, schemas: Array<DisciplineSchema>, roster: Array<RosterJudge>}) {
         super(props);
     }
 
@@ -53,7 +55,7 @@ class KampfgerichteRenderer extends React.Component<{storage: FrontendStorage, s
                 <>
                 {
                     this.createMap().map(table => (
-                        <KampfgerichtElement key={table.uniqueID} storage={this.props.storage} setStorage={this.props.setStorage} uniqueID={table.uniqueID} />
+                        <KampfgerichtElement key={table.uniqueID} storage={this.props.storage} setStorage={this.props.setStorage} uniqueID={table.uniqueID} schemas={this.props.schemas} roster={this.props.roster} />
                     ))
                 }
                 </>
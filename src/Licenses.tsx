@@ -11,25 +11,16 @@ export default function Licenses() {
         Tauri = "https://github.com/tauri-apps/tauri",
         Serde = "https://github.com/serde-rs/serde",
         Chrono = "https://github.com/chronotope/chrono",
-        libc = "https://github.com/rust-lang/libc",
         directories = "https://github.com/dirs-dev/directories-rs",
-        Windows = "https://github.com/microsoft/windows-rs",
-        WindowsCore = "https://github.com/microsoft/windows-rs",
         WebView2 = "https://github.com/wravery/webview2-rs",
         Glob = "https://github.com/rust-lang/glob",
-        Dotnet = "https://github.com/microsoft/dotnet",
-        OpenXMLSDK = "https://github.com/dotnet/Open-XML-SDK",
         React = "https://github.com/facebook/react",
         FluentUI = "https://github.com/microsoft/fluentui",
         FluentUIIcons = "https://github.com/microsoft/fluentui-system-icons",
         ReactIcons = "https://github.com/react-icons/react-icons",
         UUID = "https://github.com/uuidjs/uuid",
-        Anyhow = "https://github.com/dtolnay/anyhow",
         Logrs = "https://github.com/rust-lang/log",
-        Walkdir = "https://github.com/BurntSushi/walkdir",
         Zip = "https://github.com/zip-rs/zip2",
-        Ureq = "https://github.com/algesten/ureq",
-        Clippit = "https://github.com/sergey-tihon/Clippit",
         Vergen = "https://github.com/rustyhorde/vergen",
         MailSend = "https://github.com/stalwartlabs/mail-send",
         MailBuilder = "https://github.com/stalwartlabs/mail-builder",
@@ -39,7 +30,11 @@ export default function Licenses() {
         Objc2 = "https://github.com/madsmtm/objc2",
         Objc2Foundation = "https://github.com/madsmtm/objc2",
         Objc2AppKit = "https://github.com/madsmtm/objc2",
-        Objc2WebKit = "https://github.com/madsmtm/objc2"
+        Objc2WebKit = "https://github.com/madsmtm/objc2",
+        DejaVu = "https://github.com/dejavu-fonts/dejavu-fonts",
+        TtfParser = "https://github.com/harfbuzz/ttf-parser",
+        Subsetter = "https://github.com/typst/subsetter",
+        Flate2 = "https://github.com/rust-lang/flate2-rs"
     }
 
     // Theme thing :)
@@ -170,41 +165,6 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"libc"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<SettingsCogMultipleRegular/>}>
-                            <div className={"description"}><p><b>libc</b></p><p>v0.2.153</p><p><i>(MIT)</i></p></div>
-                            <div className={"filler"}/>
-                            <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.libc)}/></AccordionHeader>
-                        <AccordionPanel>
-                            <Card className={"panel"}>
-                                <div className={"licenseText"}>
-                                    <b>MIT License</b>
-                                    <br/><br/>
-                                    Copyright (c) 2014-2020 The Rust Project Developers.
-                                    <br/><br/>
-                                    Permission is hereby granted, free of charge, to any person obtaining a copy
-                                    of this software and associated documentation files (the "Software"), to deal
-                                    in the Software without restriction, including without limitation the rights
-                                    to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
-                                    copies of the Software, and to permit persons to whom the Software is
-                                    furnished to do so, subject to the following conditions:
-                                    <br/><br/>
-                                    The above copyright notice and this permission notice shall be included in all
-                                    copies or substantial portions of the Software.
-                                    <br/><br/>
-                                    THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
-                                    IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
-                                    FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
-                                    AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
-                                    LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
-                                    OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
-                                    SOFTWARE.
-                                </div>
-                            </Card>
-                        </AccordionPanel>
-                        <Divider inset={true}/>
-                    </AccordionItem>
                     <AccordionItem value={"directories"}>
                         <AccordionHeader button={<Button appearance={"outline"}/>} icon={<FolderListRegular/>}>
                             <div className={"description"}><p><b>directories</b></p><p>v5.0.1</p><p><i>(MIT)</i></p>
@@ -241,80 +201,6 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"windows"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<StoreMicrosoftRegular/>}>
-                            <div className={"description"}><p><b>windows (Syscall Bindings)</b></p><p>v0.56.0</p><p>
-                                <i>(MIT)</i>
-                            </p></div>
-                            <div className={"filler"}/>
-                            <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Windows)}/></AccordionHeader>
-                        <AccordionPanel>
-                            <Card className={"panel"}>
-                                <div className={"licenseText"}>
-                                    <b>MIT License</b>
-                                    <br/><br/>
-                                    Copyright (c) Microsoft Corporation.
-                                    <br/><br/>
-                                    Permission is hereby granted, free of charge, to any person obtaining a copy
-                                    of this software and associated documentation files (the "Software"), to deal
-                                    in the Software without restriction, including without limitation the rights
-                                    to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
-                                    copies of the Software, and to permit persons to whom the Software is
-                                    furnished to do so, subject to the following conditions:
-                                    <br/><br/>
-                                    The above copyright notice and this permission notice shall be included in all
-                                    copies or substantial portions of the Software.
-                                    <br/><br/>
-                                    THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
-                                    IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
-                                    FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
-                                    AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
-                                    LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
-                                    OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
-                                    SOFTWARE.
-                                </div>
-                            </Card>
-                        </AccordionPanel>
-                        <Divider inset={true}/>
-                    </AccordionItem>
-                    <AccordionItem value={"windows-core"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<StoreMicrosoftRegular/>}>
-                            <div className={"description"}><p><b>windows-core (Syscall Bindings)</b></p><p>v0.58.0</p><p>
-                                <i>(MIT)</i>
-                            </p></div>
-                            <div className={"filler"}/>
-                            <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.WindowsCore)}/></AccordionHeader>
-                        <AccordionPanel>
-                            <Card className={"panel"}>
-                                <div className={"licenseText"}>
-                                    <b>MIT License</b>
-                                    <br/><br/>
-                                    Copyright (c) Microsoft Corporation.
-                                    <br/><br/>
-                                    Permission is hereby granted, free of charge, to any person obtaining a copy
-                                    of this software and associated documentation files (the "Software"), to deal
-                                    in the Software without restriction, including without limitation the rights
-                                    to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
-                                    copies of the Software, and to permit persons to whom the Software is
-                                    furnished to do so, subject to the following conditions:
-                                    <br/><br/>
-                                    The above copyright notice and this permission notice shall be included in all
-                                    copies or substantial portions of the Software.
-                                    <br/><br/>
-                                    THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
-                                    IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
-                                    FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
-                                    AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
-                                    LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
-                                    OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
-                                    SOFTWARE.
-                                </div>
-                            </Card>
-                        </AccordionPanel>
-                        <Divider inset={true}/>
-                    </AccordionItem>
                     <AccordionItem value={"webview2"}>
                         <AccordionHeader button={<Button appearance={"outline"}/>} icon={<GlobeRegular />}>
                             <div className={"description"}><p><b>webview2-com</b></p><p>v0.33.0</p><p>
@@ -387,78 +273,6 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"dotnet"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<CodeFilled/>}>
-                            <div className={"description"}><p><b>Microsoft .NET SDK</b></p><p>v8.0 (oder höher)</p><p>
-                                <i>(MIT)</i></p></div>
-                            <div className={"filler"}/>
-                            <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Dotnet)}/></AccordionHeader>
-                        <AccordionPanel>
-                            <Card className={"panel"}>
-                                <div className={"licenseText"}>
-                                    <b>MIT License</b>
-                                    <br/><br/>
-                                    Copyright (c) 2019 Microsoft.
-                                    <br/><br/>
-                                    Permission is hereby granted, free of charge, to any person obtaining a copy
-                                    of this software and associated documentation files (the "Software"), to deal
-                                    in the Software without restriction, including without limitation the rights
-                                    to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
-                                    copies of the Software, and to permit persons to whom the Software is
-                                    furnished to do so, subject to the following conditions:
-                                    <br/><br/>
-                                    The above copyright notice and this permission notice shall be included in all
-                                    copies or substantial portions of the Software.
-                                    <br/><br/>
-                                    THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
-                                    IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
-                                    FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
-                                    AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
-                                    LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
-                                    OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
-                                    SOFTWARE.
-                                </div>
-                            </Card>
-                        </AccordionPanel>
-                        <Divider inset={true}/>
-                    </AccordionItem>
-                    <AccordionItem value={"openxml-sdk"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<TextWholeWordRegular/>}>
-                            <div className={"description"}><p><b>.NET Open XML SDK</b></p><p>v3.0.2</p><p><i>(MIT)</i>
-                            </p></div>
-                            <div className={"filler"}/>
-                            <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.OpenXMLSDK)}/></AccordionHeader>
-                        <AccordionPanel>
-                            <Card className={"panel"}>
-                                <div className={"licenseText"}>
-                                    <b>MIT License</b>
-                                    <br/><br/>
-                                    Copyright (c) .NET Foundation and Contributors.
-                                    <br/><br/>
-                                    Permission is hereby granted, free of charge, to any person obtaining a copy
-                                    of this software and associated documentation files (the "Software"), to deal
-                                    in the Software without restriction, including without limitation the rights
-                                    to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
-                                    copies of the Software, and to permit persons to whom the Software is
-                                    furnished to do so, subject to the following conditions:
-                                    <br/><br/>
-                                    The above copyright notice and this permission notice shall be included in all
-                                    copies or substantial portions of the Software.
-                                    <br/><br/>
-                                    THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
-                                    IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
-                                    FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
-                                    AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
-                                    LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
-                                    OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
-                                    SOFTWARE.
-                                </div>
-                            </Card>
-                        </AccordionPanel>
-                        <Divider inset={true}/>
-                    </AccordionItem>
                     <AccordionItem value={"react"}>
                         <AccordionHeader button={<Button appearance={"outline"}/>} icon={<WindowMultipleFilled/>}>
                             <div className={"description"}><p><b>React</b></p><p>v18.3.1</p><p><i>(MIT)</i></p></div>
@@ -1041,18 +855,18 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"anyhow"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<CommentErrorRegular/>}>
-                            <div className={"description"}><p><b>Anyhow ¯\_(°ペ)_/¯</b></p><p>v1.0.82</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"logrs"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<WindowConsoleRegular/>}>
+                            <div className={"description"}><p><b>log</b></p><p>v0.4.21</p><p><i>(MIT)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Anyhow)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.Logrs)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
                                     <b>MIT License</b>
                                     <br/><br/>
-                                    Copyright © anyhow contributors.
+                                    Copyright (c) 2014 The Rust Project Developers.
                                     <br/><br/>
                                     Permission is hereby granted, free of charge, to any person obtaining a copy
                                     of this software and associated documentation files (the "Software"), to deal
@@ -1076,18 +890,18 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"logrs"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<WindowConsoleRegular/>}>
-                            <div className={"description"}><p><b>log</b></p><p>v0.4.21</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"zip"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<ArchiveRegular/>}>
+                            <div className={"description"}><p><b>zip</b></p><p>v0.6.6</p><p><i>(MIT)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Logrs)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.Zip)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
                                     <b>MIT License</b>
                                     <br/><br/>
-                                    Copyright (c) 2014 The Rust Project Developers.
+                                    Copyright (c) 2014 Mathijs van de Nes.
                                     <br/><br/>
                                     Permission is hereby granted, free of charge, to any person obtaining a copy
                                     of this software and associated documentation files (the "Software"), to deal
@@ -1111,18 +925,18 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"walkdir"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<FolderListRegular/>}>
-                            <div className={"description"}><p><b>walkdir</b></p><p>v2.5.0</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"vergen"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<NumberRowRegular/>}>
+                            <div className={"description"}><p><b>vergen</b></p><p>v8.3.1</p><p><i>(MIT)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Walkdir)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.Vergen)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
                                     <b>MIT License</b>
                                     <br/><br/>
-                                    Copyright (c) 2015 Andrew Gallant.
+                                    Copyright (c) 2016 The Rust Project Developers.
                                     <br/><br/>
                                     Permission is hereby granted, free of charge, to any person obtaining a copy
                                     of this software and associated documentation files (the "Software"), to deal
@@ -1146,18 +960,18 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"zip"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<ArchiveRegular/>}>
-                            <div className={"description"}><p><b>zip</b></p><p>v0.6.6</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"mail-send"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<PersonFeedbackRegular/>}>
+                            <div className={"description"}><p><b>mail-send</b></p><p>v0.4.7</p><p><i>(MIT)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Zip)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.MailSend)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
                                     <b>MIT License</b>
                                     <br/><br/>
-                                    Copyright (c) 2014 Mathijs van de Nes.
+                                    Copyright (c) mail-send Contributors.
                                     <br/><br/>
                                     Permission is hereby granted, free of charge, to any person obtaining a copy
                                     of this software and associated documentation files (the "Software"), to deal
@@ -1181,18 +995,18 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"ureq"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<PlugConnectedCheckmarkRegular/>}>
-                            <div className={"description"}><p><b>ureq</b></p><p>v2.9.7</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"mail-builder"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<MailEditRegular/>}>
+                            <div className={"description"}><p><b>mail-builder</b></p><p>v0.3.1</p><p><i>(MIT)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Ureq)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.MailBuilder)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
                                     <b>MIT License</b>
                                     <br/><br/>
-                                    Copyright (c) 2019 Martin Algesten.
+                                    Copyright (c) mail-builder Contributors.
                                     <br/><br/>
                                     Permission is hereby granted, free of charge, to any person obtaining a copy
                                     of this software and associated documentation files (the "Software"), to deal
@@ -1216,18 +1030,18 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"clippit"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<DocumentPdfRegular/>}>
-                            <div className={"description"}><p><b>Clippit (Modified)</b></p><p>v2.1.1 (commit 8e09e70)</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"tokio"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<PersonRunningRegular/>}>
+                            <div className={"description"}><p><b>Tokio</b></p><p>v1.37.0</p><p><i>(MIT)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Clippit)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.Tokio)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
                                     <b>MIT License</b>
                                     <br/><br/>
-                                    Copyright (c) Microsoft Corporation.
+                                    Copyright (c) 2023 Tokio Contributors.
                                     <br/><br/>
                                     Permission is hereby granted, free of charge, to any person obtaining a copy
                                     of this software and associated documentation files (the "Software"), to deal
@@ -1251,18 +1065,18 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"vergen"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<NumberRowRegular/>}>
-                            <div className={"description"}><p><b>vergen</b></p><p>v8.3.1</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"marked"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<ReadingListFilled/>}>
+                            <div className={"description"}><p><b>marked</b></p><p>v12.0.2</p><p><i>(MIT)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Vergen)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.Marked)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
                                     <b>MIT License</b>
                                     <br/><br/>
-                                    Copyright (c) 2016 The Rust Project Developers.
+                                    Copyright (c) 2018+, MarkedJS (<a href={"https://github.com/markedjs/"}>https://github.com/markedjs/</a>) Copyright (c) 2011-2018, Christopher Jeffrey (<a href={"https://github.com/chjj/"}>https://github.com/chjj/</a>).
                                     <br/><br/>
                                     Permission is hereby granted, free of charge, to any person obtaining a copy
                                     of this software and associated documentation files (the "Software"), to deal
@@ -1286,53 +1100,64 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"mail-send"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<PersonFeedbackRegular/>}>
-                            <div className={"description"}><p><b>mail-send</b></p><p>v0.4.7</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"markdown"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<MarkdownFilled/>}>
+                            <div className={"description"}><p><b>Markdown</b></p><p>v1.0.1</p><p><i>(BSD-3-Clause)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.MailSend)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.Markdown)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
-                                    <b>MIT License</b>
+                                    <b>BSD-3-Clause License</b>
                                     <br/><br/>
-                                    Copyright (c) mail-send Contributors.
+                                    Copyright © 2004, John Gruber.
                                     <br/><br/>
-                                    Permission is hereby granted, free of charge, to any person obtaining a copy
-                                    of this software and associated documentation files (the "Software"), to deal
-                                    in the Software without restriction, including without limitation the rights
-                                    to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
-                                    copies of the Software, and to permit persons to whom the Software is
-                                    furnished to do so, subject to the following conditions:
+                                    <a href={"http://daringfireball.net/"}>http://daringfireball.net/</a>
                                     <br/><br/>
-                                    The above copyright notice and this permission notice shall be included in all
-                                    copies or substantial portions of the Software.
+                                    All rights reserved.
                                     <br/><br/>
-                                    THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
-                                    IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
-                                    FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
-                                    AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
-                                    LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
-                                    OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
-                                    SOFTWARE.
+                                    Redistribution and use in source and binary forms, with or without modification, are
+                                    permitted provided that the following conditions are met:
+                                    <br/><br/>
+                                    - Redistributions of source code must retain the above copyright notice, this list
+                                    of conditions and the following disclaimer.
+                                    <br/><br/>
+                                    - Redistributions in binary form must reproduce the above copyright notice, this
+                                    list of conditions and the following disclaimer in the documentation and/or other
+                                    materials provided with the distribution.
+                                    <br/><br/>
+                                    - Neither the name “Markdown” nor the names of its contributors may be used to
+                                    endorse or promote products derived from this software without specific prior
+                                    written permission.
+                                    <br/><br/>
+                                    This software is provided by the copyright holders and contributors “as is” and any
+                                    express or implied warranties, including, but not limited to, the implied warranties
+                                    of merchantability and fitness for a particular purpose are disclaimed. In no event
+                                    shall the copyright owner or contributors be liable for any direct, indirect,
+                                    incidental, special, exemplary, or consequential damages (including, but not limited
+                                    to, procurement of substitute goods or services; loss of use, data, or profits; or
+                                    business interruption) however caused and on any theory of liability, whether in
+                                    contract, strict liability, or tort (including negligence or otherwise) arising in
+                                    any way out of the use of this software, even if advised of the possibility of such
+                                    damage.
                                 </div>
                             </Card>
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"mail-builder"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<MailEditRegular/>}>
-                            <div className={"description"}><p><b>mail-builder</b></p><p>v0.3.1</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"objc2"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<FaApple />}>
+                            <div className={"description"}><p><b>objc2</b></p><p>v0.5.2</p><p><i>(MIT)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.MailBuilder)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.Objc2)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
                                     <b>MIT License</b>
                                     <br/><br/>
-                                    Copyright (c) mail-builder Contributors.
+                                    Copyright (c) Steven Sheldon
                                     <br/><br/>
                                     Permission is hereby granted, free of charge, to any person obtaining a copy
                                     of this software and associated documentation files (the "Software"), to deal
@@ -1356,18 +1181,18 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"tokio"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<PersonRunningRegular/>}>
-                            <div className={"description"}><p><b>Tokio</b></p><p>v1.37.0</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"objc2-foundation"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<FaApple />}>
+                            <div className={"description"}><p><b>objc2-foundation</b></p><p>v0.2.2</p><p><i>(MIT)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Tokio)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.Objc2Foundation)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
                                     <b>MIT License</b>
                                     <br/><br/>
-                                    Copyright (c) 2023 Tokio Contributors.
+                                    Copyright (c) Steven Sheldon
                                     <br/><br/>
                                     Permission is hereby granted, free of charge, to any person obtaining a copy
                                     of this software and associated documentation files (the "Software"), to deal
@@ -1391,18 +1216,18 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"marked"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<ReadingListFilled/>}>
-                            <div className={"description"}><p><b>marked</b></p><p>v12.0.2</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"objc2-app-kit"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<FaApple />}>
+                            <div className={"description"}><p><b>objc2-app-kit</b></p><p>v0.2.2</p><p><i>(MIT)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Marked)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.Objc2AppKit)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
                                     <b>MIT License</b>
                                     <br/><br/>
-                                    Copyright (c) 2018+, MarkedJS (<a href={"https://github.com/markedjs/"}>https://github.com/markedjs/</a>) Copyright (c) 2011-2018, Christopher Jeffrey (<a href={"https://github.com/chjj/"}>https://github.com/chjj/</a>).
+                                    Copyright (c) Steven Sheldon
                                     <br/><br/>
                                     Permission is hereby granted, free of charge, to any person obtaining a copy
                                     of this software and associated documentation files (the "Software"), to deal
@@ -1426,58 +1251,12 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"markdown"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<MarkdownFilled/>}>
-                            <div className={"description"}><p><b>Markdown</b></p><p>v1.0.1</p><p><i>(BSD-3-Clause)</i></p></div>
-                            <div className={"filler"}/>
-                            <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Markdown)}/></AccordionHeader>
-                        <AccordionPanel>
-                            <Card className={"panel"}>
-                                <div className={"licenseText"}>
-                                    <b>BSD-3-Clause License</b>
-                                    <br/><br/>
-                                    Copyright © 2004, John Gruber.
-                                    <br/><br/>
-                                    <a href={"http://daringfireball.net/"}>http://daringfireball.net/</a>
-                                    <br/><br/>
-                                    All rights reserved.
-                                    <br/><br/>
-                                    Redistribution and use in source and binary forms, with or without modification, are
-                                    permitted provided that the following conditions are met:
-                                    <br/><br/>
-                                    - Redistributions of source code must retain the above copyright notice, this list
-                                    of conditions and the following disclaimer.
-                                    <br/><br/>
-                                    - Redistributions in binary form must reproduce the above copyright notice, this
-                                    list of conditions and the following disclaimer in the documentation and/or other
-                                    materials provided with the distribution.
-                                    <br/><br/>
-                                    - Neither the name “Markdown” nor the names of its contributors may be used to
-                                    endorse or promote products derived from this software without specific prior
-                                    written permission.
-                                    <br/><br/>
-                                    This software is provided by the copyright holders and contributors “as is” and any
-                                    express or implied warranties, including, but not limited to, the implied warranties
-                                    of merchantability and fitness for a particular purpose are disclaimed. In no event
-                                    shall the copyright owner or contributors be liable for any direct, indirect,
-                                    incidental, special, exemplary, or consequential damages (including, but not limited
-                                    to, procurement of substitute goods or services; loss of use, data, or profits; or
-                                    business interruption) however caused and on any theory of liability, whether in
-                                    contract, strict liability, or tort (including negligence or otherwise) arising in
-                                    any way out of the use of this software, even if advised of the possibility of such
-                                    damage.
-                                </div>
-                            </Card>
-                        </AccordionPanel>
-                        <Divider inset={true}/>
-                    </AccordionItem>
-                    <AccordionItem value={"objc2"}>
+                    <AccordionItem value={"objc2-web-kit"}>
                         <AccordionHeader button={<Button appearance={"outline"}/>} icon={<FaApple />}>
-                            <div className={"description"}><p><b>objc2</b></p><p>v0.5.2</p><p><i>(MIT)</i></p></div>
+                            <div className={"description"}><p><b>objc2-web-kit</b></p><p>v0.2.2</p><p><i>(MIT)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Objc2)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.Objc2WebKit)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
@@ -1507,18 +1286,77 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"objc2-foundation"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<FaApple />}>
-                            <div className={"description"}><p><b>objc2-foundation</b></p><p>v0.2.2</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"dejavu-fonts"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<TextWholeWordRegular/>}>
+                            <div className={"description"}><p><b>DejaVu Sans</b></p><p>v2.37</p><p><i>(Bitstream Vera License)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Objc2Foundation)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.DejaVu)}/></AccordionHeader>
+                        <AccordionPanel>
+                            <Card className={"panel"}>
+                                <div className={"licenseText"}>
+                                    <b>Bitstream Vera Fonts Copyright</b>
+                                    <br/><br/>
+                                    Copyright (c) 2003 by Bitstream, Inc. All Rights Reserved. Bitstream Vera is
+                                    a trademark of Bitstream, Inc. DejaVu changes are in public domain.
+                                    <br/><br/>
+                                    Permission is hereby granted, free of charge, to any person obtaining a copy
+                                    of the fonts accompanying this license ("Fonts") and associated
+                                    documentation files (the "Font Software"), to reproduce and distribute the
+                                    Font Software, including without limitation the rights to use, copy, merge,
+                                    publish, distribute, and/or sell copies of the Font Software, and to permit
+                                    persons to whom the Font Software is furnished to do so, subject to the
+                                    following conditions:
+                                    <br/><br/>
+                                    The above copyright and trademark notices and this permission notice shall
+                                    be included in all copies of one or more of the Font Software typefaces.
+                                    <br/><br/>
+                                    The Font Software may be modified, altered, or added to, and in particular
+                                    the designs of glyphs or characters in the Fonts may be modified and
+                                    additional glyphs or characters may be added to the Fonts, only if the fonts
+                                    are renamed to names not containing either the words "Bitstream" or the word
+                                    "Vera".
+                                    <br/><br/>
+                                    This License becomes null and void to the extent applicable to Fonts or Font
+                                    Software that has been modified and is distributed under the "Bitstream
+                                    Vera" names.
+                                    <br/><br/>
+                                    The Font Software may be sold as part of a larger software package but no
+                                    copy of one or more of the Font Software typefaces may be sold by itself.
+                                    <br/><br/>
+                                    THE FONT SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS
+                                    OR IMPLIED, INCLUDING BUT NOT LIMITED TO ANY WARRANTIES OF MERCHANTABILITY,
+                                    FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT OF COPYRIGHT, PATENT,
+                                    TRADEMARK, OR OTHER RIGHT. IN NO EVENT SHALL BITSTREAM OR THE GNOME
+                                    FOUNDATION BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, INCLUDING
+                                    ANY GENERAL, SPECIAL, INDIRECT, INCIDENTAL, OR CONSEQUENTIAL DAMAGES,
+                                    WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF
+                                    THE USE OR INABILITY TO USE THE FONT SOFTWARE OR FROM OTHER DEALINGS IN THE
+                                    FONT SOFTWARE.
+                                    <br/><br/>
+                                    Except as contained in this notice, the names of Gnome, the Gnome
+                                    Foundation, and Bitstream Inc., shall not be used in advertising or
+                                    otherwise to promote the sale, use or other dealings in this Font Software
+                                    without prior written authorization from the Gnome Foundation or Bitstream
+                                    Inc., respectively. For further information, contact: fonts at gnome dot
+                                    org.
+                                </div>
+                            </Card>
+                        </AccordionPanel>
+                        <Divider inset={true}/>
+                    </AccordionItem>
+                    <AccordionItem value={"ttf-parser"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<TextAlignJustifyRegular/>}>
+                            <div className={"description"}><p><b>ttf-parser</b></p><p>v0.20.0</p><p><i>(MIT)</i></p></div>
+                            <div className={"filler"}/>
+                            <Button appearance={"transparent"} icon={<FaGithub/>}
+                                    onClick={() => redirect(Repositories.TtfParser)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
                                     <b>MIT License</b>
                                     <br/><br/>
-                                    Copyright (c) Steven Sheldon
+                                    Copyright (c) 2018 Yevhenii Reizner
                                     <br/><br/>
                                     Permission is hereby granted, free of charge, to any person obtaining a copy
                                     of this software and associated documentation files (the "Software"), to deal
@@ -1542,18 +1380,18 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"objc2-app-kit"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<FaApple />}>
-                            <div className={"description"}><p><b>objc2-app-kit</b></p><p>v0.2.2</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"subsetter"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<TextAlignJustifyRegular/>}>
+                            <div className={"description"}><p><b>subsetter</b></p><p>v0.1.1</p><p><i>(MIT)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Objc2AppKit)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.Subsetter)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
                                     <b>MIT License</b>
                                     <br/><br/>
-                                    Copyright (c) Steven Sheldon
+                                    Copyright (c) Laurenz Mädje
                                     <br/><br/>
                                     Permission is hereby granted, free of charge, to any person obtaining a copy
                                     of this software and associated documentation files (the "Software"), to deal
@@ -1577,18 +1415,18 @@ export default function Licenses() {
                         </AccordionPanel>
                         <Divider inset={true}/>
                     </AccordionItem>
-                    <AccordionItem value={"objc2-web-kit"}>
-                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<FaApple />}>
-                            <div className={"description"}><p><b>objc2-web-kit</b></p><p>v0.2.2</p><p><i>(MIT)</i></p></div>
+                    <AccordionItem value={"flate2"}>
+                        <AccordionHeader button={<Button appearance={"outline"}/>} icon={<ArchiveRegular/>}>
+                            <div className={"description"}><p><b>flate2</b></p><p>v1.1.10</p><p><i>(MIT)</i></p></div>
                             <div className={"filler"}/>
                             <Button appearance={"transparent"} icon={<FaGithub/>}
-                                    onClick={() => redirect(Repositories.Objc2WebKit)}/></AccordionHeader>
+                                    onClick={() => redirect(Repositories.Flate2)}/></AccordionHeader>
                         <AccordionPanel>
                             <Card className={"panel"}>
                                 <div className={"licenseText"}>
                                     <b>MIT License</b>
                                     <br/><br/>
-                                    Copyright (c) Steven Sheldon
+                                    Copyright (c) 2014-2025 Alex Crichton
                                     <br/><br/>
                                     Permission is hereby granted, free of charge, to any person obtaining a copy
                                     of this software and associated documentation files (the "Software"), to deal
@@ -0,0 +1,271 @@
+import { Body1Strong, Button, Caption1Strong, Checkbox, Divider, Field, FluentProvider, Input, Select, Spinner, webDarkTheme, webLightTheme } from "@fluentui/react-components";
+import { getCurrentWebviewWindow } from "@tauri-apps/api/webviewWindow";
+import { invoke } from "@tauri-apps/api/core";
+import { save } from "@tauri-apps/plugin-dialog";
+import React from "react";
+import "./LogViewer.css";
+import { ArrowDownloadFilled, CopyFilled, FolderOpenFilled, SelectAllOnFilled } from "@fluentui/react-icons";
+import { describeError } from "./Errors";
+import { formatLogRecord, loadLogSessions, LOG_LEVELS, LogLevel, LogRecord, LogSession, readLogRecords } from "./Logs";
+import { loadSettings } from "./Settings";
+
+// How often the log of the running app is checked for new records
+const POLL_INTERVAL_MS = 2000;
+
+type LogViewerState = {
+    isLight: boolean,
+    sessions: Array<LogSession>,
+    session: LogSession | null,
+    records: Array<LogRecord>,
+    offset: number,
+    rotations: number,
+    loading: boolean,
+    levels: Array<LogLevel>,
+    // As given by the datetime-local inputs, e.g. "2026-10-18T10:29", empty for no limit
+    from: string,
+    to: string,
+    // Indices into records
+    selected: Set<number>,
+    lastClicked: number | null,
+    statusText: string | null,
+    statusTextClass: "none" | "errorText" | "successText",
+}
+
+export default class LogViewer extends React.Component<{}, LogViewerState> {
+
+    pollTimer: number | null = null;
+    polling: boolean = false;
+    tableRef = React.createRef<HTMLDivElement>();
+
+    constructor(props: {}) {
+        super(props);
+        this.state = {
+          isLight: true,
+          sessions: [],
+          session: null,
+          records: [],
+          offset: 0,
+          rotations: 0,
+          loading: true,
+          levels: ["ERROR", "WARN", "INFO"],
+          from: "",
+          to: "",
+          selected: new Set(),
+          lastClicked: null,
+          statusText: null,
+          statusTextClass: "none",
+        }
+    }
+
+    componentDidMount(): void {
+        const darkModePreference = window.matchMedia("(prefers-color-scheme: dark)");
+        darkModePreference.matches ? this.setState({isLight: false}) : this.setState({isLight: true})
+        darkModePreference.addEventListener("change", e => e.matches ? this.setState({isLight: false}) : this.setState({isLight: true}));
+        loadLogSessions().then((sessions) => {
+          this.setState({sessions: sessions});
+          if(sessions.length > 0) {
+            this.selectSession(sessions[0]);
+          } else {
+            this.setState({loading: false});
+          }
+        });
+        this.pollTimer = window.setInterval(() => this.poll(), POLL_INTERVAL_MS);
+    }
+
+    componentWillUnmount(): void {
+        if(this.pollTimer !== null) {
+          window.clearInterval(this.pollTimer);
+        }
+    }
+
+    selectSession(session: LogSession) {
+        this.setState({session: session, records: [], offset: 0, rotations: 0, loading: true, selected: new Set(), lastClicked: null, statusText: null, statusTextClass: "none"});
+        readLogRecords(session.id, 0, 0).then((chunk) => {
+          this.setState({records: chunk.records, offset: chunk.offset, rotations: chunk.rotations}, () => this.scrollToEnd());
+        }).catch((error) => {
+          this.setState({statusText: describeError(error), statusTextClass: "errorText"});
+        }).finally(() => this.setState({loading: false}));
+    }
+
+    // Appends the records written since the last read, only the log of the running app grows
+    poll() {
+        const session = this.state.session;
+        if(session === null || !session.is_current || this.state.loading || this.polling) {
+          return;
+        }
+        this.polling = true;
+        const table = this.tableRef.current;
+        const wasAtEnd = table === null || table.scrollHeight - table.scrollTop - table.clientHeight < 20;
+        readLogRecords(session.id, this.state.offset, this.state.rotations).then((chunk) => {
+          if(this.state.session?.id !== session.id || (chunk.records.length === 0 && !chunk.reset)) {
+            return;
+          }
+          if(chunk.reset) {
+            this.setState({records: chunk.records, offset: chunk.offset, rotations: chunk.rotations, selected: new Set(), lastClicked: null});
+          } else {
+            this.setState({records: this.state.records.concat(chunk.records), offset: chunk.offset}, () => wasAtEnd && this.scrollToEnd());
+          }
+        }).catch((error) => {
+          this.setState({statusText: describeError(error), statusTextClass: "errorText"});
+        }).finally(() => this.polling = false);
+    }
+
+    scrollToEnd() {
+        const table = this.tableRef.current;
+        if(table !== null) {
+          table.scrollTop = table.scrollHeight;
+        }
+    }
+
+    // Lines that are no records have no level or timestamp, they are only hidden by the filters that need one
+    isVisible(record: LogRecord): boolean {
+        if(record.level !== null && !this.state.levels.includes(record.level)) {
+          return false;
+        }
+        if(this.state.from === "" && this.state.to === "") {
+          return true;
+        }
+        if(record.timestamp === null) {
+          return false;
+        }
+        // Both are in ISO order, so comparing the strings compares the times
+        const timestamp = record.timestamp.replace(" ", "T");
+        if(this.state.from !== "" && timestamp < this.state.from) {
+          return false;
+        }
+        // The end is inclusive, so the records of its minute are still shown
+        if(this.state.to !== "" && timestamp.substring(0, this.state.to.length) > this.state.to) {
+          return false;
+        }
+        return true;
+    }
+
+    toggleLevel(level: LogLevel, checked: boolean) {
+        const levels = checked ? this.state.levels.concat([level]) : this.state.levels.filter((other) => other !== level);
+        this.setState({levels: levels});
+    }
+
+    // Like in a file list: Click selects a record, Cmd/Ctrl-click adds or removes it, Shift-click selects a range
+    clickRecord(index: number, visibleIndices: Array<number>, event: React.MouseEvent) {
+        let selected = new Set(this.state.selected);
+        if(event.shiftKey && this.state.lastClicked !== null) {
+          const start = visibleIndices.indexOf(this.state.lastClicked);
+          const end = visibleIndices.indexOf(index);
+          if(start !== -1 && end !== -1) {
+            visibleIndices.slice(Math.min(start, end), Math.max(start, end) + 1).forEach((other) => selected.add(other));
+          }
+        } else if(event.metaKey || event.ctrlKey) {
+          selected.has(index) ? selected.delete(index) : selected.add(index);
+        } else {
+          selected = new Set([index]);
+        }
+        this.setState({selected: selected, lastClicked: index});
+    }
+
+    // The selected records, or all that match the filters if none are selected
+    recordsToCopy(visibleIndices: Array<number>): Array<LogRecord> {
+        const selected = visibleIndices.filter((index) => this.state.selected.has(index));
+        return (selected.length > 0 ? selected : visibleIndices).map((index) => this.state.records[index]);
+    }
+
+    copyRecords(records: Array<LogRecord>) {
+        navigator.clipboard.writeText(records.map(formatLogRecord).join("\n")).then(() => {
+          this.setState({statusText: records.length + " Einträge kopiert.", statusTextClass: "successText"});
+        }).catch(() => {
+          this.setState({statusText: "Die Einträge konnten nicht kopiert werden.", statusTextClass: "errorText"});
+        });
+    }
+
+    async exportRecords(records: Array<LogRecord>) {
+        const settings = await loadSettings();
+        save({filters: [{name: "Textdatei (.log)", extensions: ["log", "txt"]}], title: "Logs exportieren", defaultPath: settings.default_save_dir ?? undefined}).then((filePath) => {
+          if(filePath === null) {
+            return;
+          }
+          // Linux might not add the proper file extension
+          if(!filePath.endsWith(".log") && !filePath.endsWith(".txt")) {
+            filePath = filePath + ".log";
+          }
+          invoke("export_log_records", { filepath: filePath, contents: records.map(formatLogRecord).join("\n") + "\n" }).then(() => {
+            this.setState({statusText: records.length + " Einträge exportiert.", statusTextClass: "successText"});
+          }).catch((error) => {
+            this.setState({statusText: describeError(error), statusTextClass: "errorText"});
+          });
+        });
+    }
+
+    showInFolder() {
+        if(this.state.session === null) {
+          return;
+        }
+        invoke("show_item_in_folder", { path: this.state.session.path }).catch((error) => {
+          this.setState({statusText: describeError(error), statusTextClass: "errorText"});
+        });
+    }
+
+    render() {
+        const visibleIndices = this.state.records.flatMap((record, index) => this.isVisible(record) ? [index] : []);
+        const recordsToCopy = this.recordsToCopy(visibleIndices);
+        const selectedCount = visibleIndices.filter((index) => this.state.selected.has(index)).length;
+        const actionSuffix = selectedCount > 0 ? " (" + selectedCount + ")" : " (alle)";
+
+        return(
+            <>
+              <FluentProvider theme={this.state.isLight ? webLightTheme : webDarkTheme}>
+                <div id="mainContents">
+                  <Body1Strong>Logs</Body1Strong>
+                  <Divider inset={true} id="divider" />
+                  <div id="filterRow">
+                    <Field label={"Sitzung:"}>
+                      <Select value={this.state.session?.id ?? ""} onChange={(_ev, data) => {
+                        const session = this.state.sessions.find((session) => session.id === data.value);
+                        if(session !== undefined) {
+                          this.selectSession(session);
+                        }
+                      }}>
+                        {this.state.sessions.map((session) =>
+                          <option key={session.id} value={session.id}>{session.last_modified + (session.is_current ? " (aktuell)" : "")}</option>
+                        )}
+                      </Select>
+                    </Field>
+                    <Field label={"Von:"}>
+                      <Input type="datetime-local" value={this.state.from} onChange={(_ev, data) => this.setState({from: data.value})} />
+                    </Field>
+                    <Field label={"Bis:"}>
+                      <Input type="datetime-local" value={this.state.to} onChange={(_ev, data) => this.setState({to: data.value})} />
+                    </Field>
+                  </div>
+                  <div id="levelRow">
+                    {LOG_LEVELS.map((level) =>
+                      <Checkbox key={level} label={level} checked={this.state.levels.includes(level)} onChange={(_ev, data) => this.toggleLevel(level, data.checked as boolean)} />
+                    )}
+                  </div>
+                  <div id="recordTable" ref={this.tableRef}>
+                    {this.state.loading ? <Spinner /> :
+                    visibleIndices.map((index) => {
+                      const record = this.state.records[index];
+                      return(
+                        <div key={index} className={"recordRow level" + (record.level ?? "NONE") + (this.state.selected.has(index) ? " selectedRecord" : "")} onClick={(event) => this.clickRecord(index, visibleIndices, event)}>
+                          <span className="recordTimestamp">{record.timestamp ?? ""}</span>
+                          <span className="recordLevel">{record.level ?? ""}</span>
+                          <span className="recordTarget">{record.target}</span>
+                          <span className="recordMessage">{record.message}</span>
+                        </div>
+                      );
+                    })}
+                  </div>
+                  <div id="buttonDiv">
+                    <Button appearance="subtle" icon={<FolderOpenFilled />} onClick={() => this.showInFolder()} disabledFocusable={this.state.session === null}>Im Ordner anzeigen</Button>
+                    <Button appearance="subtle" icon={<SelectAllOnFilled />} onClick={() => this.setState({selected: new Set(visibleIndices)})} disabledFocusable={visibleIndices.length === 0}>Alle auswählen</Button>
+                    <Caption1Strong className={this.state.statusTextClass}>{this.state.statusText}</Caption1Strong>
+                    <div className="spacer"></div>
+                    <Button appearance="secondary" icon={<CopyFilled />} onClick={() => this.copyRecords(recordsToCopy)} disabledFocusable={recordsToCopy.length === 0}>{"Kopieren" + actionSuffix}</Button>
+                    <Button appearance="primary" icon={<ArrowDownloadFilled />} onClick={() => this.exportRecords(recordsToCopy)} disabledFocusable={recordsToCopy.length === 0}>{"Exportieren..." + actionSuffix}</Button>
+                    <Button appearance="secondary" onClick={() => getCurrentWebviewWindow().close()}>Schließen</Button>
+                  </div>
+                </div>
+              </FluentProvider>
+            </>
+        );
+    }
+}
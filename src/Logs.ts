@@ -0,0 +1,49 @@
+import { invoke } from "@tauri-apps/api/core";
+
+// A launch of the app with its log, as provided by the backend (see Logging.rs)
+export type LogSession = {
+    id: string,
+    path: string,
+    // e.g. "18.10.2026 10:29"
+    last_modified: string,
+    size: number,
+    // The log of the running app, which still grows
+    is_current: boolean,
+}
+
+export type LogRecord = {
+    // e.g. "2026-10-18 10:29:26.123", null for lines that are no records
+    timestamp: string | null,
+    level: LogLevel | null,
+    target: string,
+    message: string,
+}
+
+export type LogChunk = {
+    records: Array<LogRecord>,
+    // Where to continue reading on the next call
+    offset: number,
+    // How often the log was rotated, passed to the next call together with the offset
+    rotations: number,
+    // The log was rotated, the records replace all earlier ones
+    reset: boolean,
+}
+
+export type LogLevel = "ERROR" | "WARN" | "INFO" | "DEBUG" | "TRACE";
+export const LOG_LEVELS: Array<LogLevel> = ["ERROR", "WARN", "INFO", "DEBUG", "TRACE"];
+
+export function loadLogSessions(): Promise<Array<LogSession>> {
+    return invoke("get_log_sessions").then((response) => response as Array<LogSession>);
+}
+
+export function readLogRecords(session: string, offset: number, rotations: number): Promise<LogChunk> {
+    return invoke("read_log_records", { session: session, offset: offset, rotations: rotations }).then((response) => response as LogChunk);
+}
+
+// Same layout as the log file, so exported records read like the original
+export function formatLogRecord(record: LogRecord): string {
+    if(record.timestamp === null || record.level === null) {
+        return record.message;
+    }
+    return record.timestamp + " " + record.level.padEnd(5) + " [" + record.target + "] " + record.message.split("\n").join("\n    ");
+}
@@ -0,0 +1,28 @@
+import { invoke } from "@tauri-apps/api/core";
+
+// A message to the developers that could not be sent yet, as provided by the backend (see Outbox.rs)
+export type PendingMessage = {
+    id: string,
+    // e.g. "Bug" or "Feedback"
+    kind: string,
+    subject: string,
+    // e.g. "18.10.2026 14:05"
+    created: string,
+    attempts: number,
+    next_attempt: string,
+    // Why the last attempt failed, null before the first attempt
+    last_error: string | null,
+    // Whether the server refused it too often, so it is only sent again with "Jetzt senden"
+    given_up: boolean,
+}
+
+// What happened to a message handed to send_mail_from_frontend
+export type MailDelivery = "Sent" | "Queued";
+
+export function loadOutboxMessages(): Promise<Array<PendingMessage>> {
+    return invoke("get_outbox_messages").then((response) => response as Array<PendingMessage>);
+}
+
+export function retryOutboxMessages(): Promise<Array<PendingMessage>> {
+    return invoke("retry_outbox_messages").then((response) => response as Array<PendingMessage>);
+}
@@ -3,6 +3,7 @@ import { FrontendStorage } from "./Editor.tsx";
 import { Body1Stronger, Button, Caption1, Card, CardFooter, CardHeader, Field, Input } from "@fluentui/react-components";
 import { AddFilled, DeleteFilled, DeleteRegular } from "@fluentui/react-icons";
 import "./ReplacementJudges.css";
+import { ROSTER_DATALIST_ID } from "./Roster";
 
 export default class ReplacementJudges extends React.Component<{storage: FrontendStorage, setStorage: React.Dispatch<React.SetStateAction<FrontendStorage>>, hidden: boolean, setHidden: React.Dispatch<React.SetStateAction<boolean>>}> {
 
@@ -104,7 +105,7 @@ export default class ReplacementJudges extends React.Component<{storage: Fronten
                                         noOfReplacementJudges++;
                                         return(
                                             <Field>
-                                                <Input aria-label={name} contentBefore={noOfReplacementJudges.toString() + "."} defaultValue={name} className="inputTypeReplacement" onInput={(element) => {this.reactToInputChange(element)}} contentAfter={
+                                                <Input aria-label={name} contentBefore={noOfReplacementJudges.toString() + "."} defaultValue={name} className="inputTypeReplacement" list={ROSTER_DATALIST_ID} onInput={(element) => {this.reactToInputChange(element)}} contentAfter={
                                                     <Button aria-label={name} icon={<DeleteRegular />} appearance={"transparent"} size={"small"} onClick={(element) => {this.removeCurrent(element)}} autoCapitalize={"off"} autoCorrect={"off"} />
                                                 }></Input>
                                             </Field>
@@ -0,0 +1,31 @@
+import { invoke } from "@tauri-apps/api/core";
+
+// Judge of the roster imported from GymNet, as provided by the backend
+export type RosterJudge = {
+    name: string,
+    club: string,
+    license: "D" | "C" | "B" | "A" | "International" | null,
+    disciplines: Array<string>,
+}
+
+// ID of the <datalist> the judge inputs use for autocompletion
+export const ROSTER_DATALIST_ID = "judgeRoster";
+
+export function loadJudgeRoster(): Promise<Array<RosterJudge>> {
+    return invoke("get_judge_roster").then((response) => response as Array<RosterJudge>);
+}
+
+// Throws the DetailedError of the backend if the file could not be read, see Errors.ts
+export function importJudgeRoster(filepath: string): Promise<Array<RosterJudge>> {
+    return invoke("import_judge_roster", {filepath: filepath}).then((response) => response as Array<RosterJudge>);
+}
+
+// Same comparison as the backend: ignores case and surrounding spaces
+// Without a roster every name counts as known
+export function isOnRoster(roster: Array<RosterJudge>, name: string): boolean {
+    if(roster.length === 0) {
+        return true;
+    }
+    const normalized = name.trim().toLowerCase();
+    return roster.some((judge) => judge.name.trim().toLowerCase() === normalized);
+}
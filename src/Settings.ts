@@ -0,0 +1,36 @@
+import { invoke } from "@tauri-apps/api/core";
+
+// Preferences of the user, as provided by the backend (see Settings.rs)
+export type Settings = {
+    // "File" writes the mails into mail_dir instead of sending them
+    mail_transport: "Smtp" | "File",
+    smtp_host: string,
+    smtp_port: number,
+    smtp_tls: "StartTls" | "Implicit" | "None",
+    // "BuiltIn" uses the account the app was built with, "Login" smtp_username and smtp_password
+    smtp_auth: "BuiltIn" | "Login" | "None",
+    smtp_username: string,
+    smtp_password: string,
+    mail_from: string,
+    mail_to: string,
+    mail_dir: string | null,
+    default_save_dir: string | null,
+    template_dir: string | null,
+    check_for_updates: boolean,
+    default_responsible_person: string,
+    backup_count: number,
+    autosave_interval_minutes: number,
+}
+
+// Same limits as the validation of the backend
+export const MAX_BACKUP_COUNT = 20;
+export const MIN_AUTOSAVE_INTERVAL_MINUTES = 1;
+export const MAX_AUTOSAVE_INTERVAL_MINUTES = 60;
+
+export function loadSettings(): Promise<Settings> {
+    return invoke("get_settings").then((response) => response as Settings);
+}
+
+export function loadDefaultSettings(): Promise<Settings> {
+    return invoke("get_default_settings").then((response) => response as Settings);
+}
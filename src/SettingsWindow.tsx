@@ -0,0 +1,163 @@
+import { Body1Strong, Button, Caption1Strong, Checkbox, Divider, Field, FluentProvider, Input, Select, Spinner, webDarkTheme, webLightTheme } from "@fluentui/react-components";
+import { getCurrentWebviewWindow } from "@tauri-apps/api/webviewWindow";
+import { invoke } from "@tauri-apps/api/core";
+import { open } from "@tauri-apps/plugin-dialog";
+import React from "react";
+import "./SettingsWindow.css";
+import { ArrowResetFilled, DismissFilled, FolderOpenFilled, KeyFilled, MailFilled, PersonFilled, SaveFilled, ServerFilled } from "@fluentui/react-icons";
+import { describeError } from "./Errors";
+import { loadDefaultSettings, loadSettings, MAX_AUTOSAVE_INTERVAL_MINUTES, MAX_BACKUP_COUNT, MIN_AUTOSAVE_INTERVAL_MINUTES, Settings } from "./Settings";
+
+export default class SettingsWindow extends React.Component<{}, {isLight: boolean, settings: Settings | null, saving: boolean, errorText: string | null, errorTextClass: "none" | "errorText" | "successText"}> {
+
+    constructor(props: {}) {
+        super(props);
+        this.state = {
+          isLight: true,
+          settings: null,
+          saving: false,
+          errorText: null,
+          errorTextClass: "none",
+        }
+    }
+
+    componentDidMount(): void {
+        const darkModePreference = window.matchMedia("(prefers-color-scheme: dark)");
+        darkModePreference.matches ? this.setState({isLight: false}) : this.setState({isLight: true})
+        darkModePreference.addEventListener("change", e => e.matches ? this.setState({isLight: false}) : this.setState({isLight: true}));
+        loadSettings().then((settings) => this.setState({settings: settings}));
+    }
+
+    updateSettings(change: Partial<Settings>) {
+        if(this.state.settings === null) {
+          return;
+        }
+        this.setState({settings: {...this.state.settings, ...change}, errorText: null, errorTextClass: "none"});
+    }
+
+    async pickFolder(current: string | null): Promise<string | null> {
+        const folder = await open({directory: true, multiple: false, defaultPath: current ?? undefined});
+        return typeof folder === "string" ? folder : current;
+    }
+
+    async resetToDefaults() {
+        this.setState({settings: await loadDefaultSettings(), errorText: null, errorTextClass: "none"});
+    }
+
+    async submit() {
+        if(this.state.settings === null) {
+          return;
+        }
+        this.setState({saving: true, errorText: null, errorTextClass: "none"});
+        invoke("update_settings", { settings: this.state.settings }).then(() => {
+          this.setState({errorText: "Einstellungen wurden gespeichert.", errorTextClass: "successText"});
+        }).catch((error) => {
+          this.setState({errorText: describeError(error), errorTextClass: "errorText"});
+        }).finally(() => this.setState({saving: false}));
+    }
+
+    folderField(label: string, hint: string, value: string | null, onChange: (folder: string | null) => void) {
+        return(
+          <Field label={label} hint={hint}>
+            <div className="folderRow">
+              <Input className="folderInput" type="text" readOnly={true} value={value ?? ""} placeholder="Standard" contentBefore={<FolderOpenFilled />} />
+              <Button appearance="secondary" onClick={async () => onChange(await this.pickFolder(value))}>Auswählen...</Button>
+              <Button appearance="subtle" icon={<DismissFilled />} onClick={() => onChange(null)} disabled={value === null} title="Zurücksetzen" />
+            </div>
+          </Field>
+        );
+    }
+
+    render() {
+        const settings = this.state.settings;
+
+        return(
+            <>
+              <FluentProvider theme={this.state.isLight ? webLightTheme : webDarkTheme}>
+                <div id="mainContents">
+                  <Body1Strong>Einstellungen</Body1Strong>
+                  <Divider inset={true} id="divider" />
+                  {settings === null ? <Spinner /> :
+                  <div id="settingsForm">
+                    <Field label={"Standard-Verantwortliche/r:"} hint={"Wird bei neuen Wettkämpfen vorausgefüllt."}>
+                      <Input type="text" placeholder="Vorname Nachname" value={settings.default_responsible_person} onChange={(_ev, data) => this.updateSettings({default_responsible_person: data.value})} contentBefore={<PersonFilled />} autoCapitalize={"off"} autoCorrect={"off"} />
+                    </Field>
+                    {this.folderField("Speicherort:", "Ordner, in dem die Speichern- und Export-Dialoge starten.", settings.default_save_dir, (folder) => this.updateSettings({default_save_dir: folder}))}
+                    {this.folderField("Vorlagen:", "Ordner mit eigenen Vorlagen. Fehlende Vorlagen werden durch die mitgelieferten ersetzt.", settings.template_dir, (folder) => this.updateSettings({template_dir: folder}))}
+                    <div className="numberRow">
+                      <Field label={"Anzahl Sicherungskopien:"} validationMessage={settings.backup_count > MAX_BACKUP_COUNT ? "Höchstens " + MAX_BACKUP_COUNT : undefined}>
+                        <Input type="number" min={0} max={MAX_BACKUP_COUNT} value={settings.backup_count.toString()} onChange={(_ev, data) => this.updateSettings({backup_count: Math.max(0, parseInt(data.value) || 0)})} />
+                      </Field>
+                      <Field label={"Autospeichern alle (Minuten):"} validationMessage={settings.autosave_interval_minutes < MIN_AUTOSAVE_INTERVAL_MINUTES || settings.autosave_interval_minutes > MAX_AUTOSAVE_INTERVAL_MINUTES ? "Zwischen " + MIN_AUTOSAVE_INTERVAL_MINUTES + " und " + MAX_AUTOSAVE_INTERVAL_MINUTES : undefined}>
+                        <Input type="number" min={MIN_AUTOSAVE_INTERVAL_MINUTES} max={MAX_AUTOSAVE_INTERVAL_MINUTES} value={settings.autosave_interval_minutes.toString()} onChange={(_ev, data) => this.updateSettings({autosave_interval_minutes: parseInt(data.value) || 0})} />
+                      </Field>
+                    </div>
+                    <Field label={"Versand von Fehlerberichten und Feedback:"}>
+                      <Select value={settings.mail_transport} onChange={(_ev, data) => this.updateSettings({mail_transport: data.value as Settings["mail_transport"]})}>
+                        <option value="Smtp">Über einen SMTP-Server</option>
+                        <option value="File">Als Datei in einen Ordner (zum Testen)</option>
+                      </Select>
+                    </Field>
+                    {settings.mail_transport === "Smtp" ?
+                    <>
+                      <div className="numberRow">
+                        <Field label={"SMTP-Server:"}>
+                          <Input type="text" value={settings.smtp_host} onChange={(_ev, data) => this.updateSettings({smtp_host: data.value})} contentBefore={<ServerFilled />} autoCapitalize={"off"} autoCorrect={"off"} />
+                        </Field>
+                        <Field label={"Port:"}>
+                          <Input type="number" min={1} max={65535} value={settings.smtp_port.toString()} onChange={(_ev, data) => this.updateSettings({smtp_port: parseInt(data.value) || 0})} />
+                        </Field>
+                      </div>
+                      <div className="numberRow">
+                        <Field label={"Verschlüsselung:"}>
+                          <Select value={settings.smtp_tls} onChange={(_ev, data) => this.updateSettings({smtp_tls: data.value as Settings["smtp_tls"]})}>
+                            <option value="StartTls">STARTTLS (meist Port 587)</option>
+                            <option value="Implicit">TLS (meist Port 465)</option>
+                            <option value="None">Keine</option>
+                          </Select>
+                        </Field>
+                        <Field label={"Anmeldung:"}>
+                          <Select value={settings.smtp_auth} onChange={(_ev, data) => this.updateSettings({smtp_auth: data.value as Settings["smtp_auth"]})}>
+                            <option value="BuiltIn">Mitgeliefertes Konto</option>
+                            <option value="Login">Benutzername und Passwort</option>
+                            <option value="None">Keine</option>
+                          </Select>
+                        </Field>
+                      </div>
+                      {settings.smtp_auth === "Login" &&
+                      <div className="numberRow">
+                        <Field label={"Benutzername:"}>
+                          <Input type="text" value={settings.smtp_username} onChange={(_ev, data) => this.updateSettings({smtp_username: data.value})} contentBefore={<PersonFilled />} autoCapitalize={"off"} autoCorrect={"off"} />
+                        </Field>
+                        <Field label={"Passwort:"} hint={"Wird unverschlüsselt in den Einstellungen gespeichert."}>
+                          <Input type="password" value={settings.smtp_password} onChange={(_ev, data) => this.updateSettings({smtp_password: data.value})} contentBefore={<KeyFilled />} />
+                        </Field>
+                      </div>
+                      }
+                    </> :
+                    this.folderField("Ordner für Mails:", "Die Mails werden als .eml-Dateien gespeichert statt verschickt.", settings.mail_dir, (folder) => this.updateSettings({mail_dir: folder}))
+                    }
+                    <div className="numberRow">
+                      <Field label={"Absender:"}>
+                        <Input type="email" value={settings.mail_from} onChange={(_ev, data) => this.updateSettings({mail_from: data.value})} contentBefore={<MailFilled />} autoCapitalize={"off"} autoCorrect={"off"} />
+                      </Field>
+                      <Field label={"Empfänger:"}>
+                        <Input type="email" value={settings.mail_to} onChange={(_ev, data) => this.updateSettings({mail_to: data.value})} contentBefore={<MailFilled />} autoCapitalize={"off"} autoCorrect={"off"} />
+                      </Field>
+                    </div>
+                    <Checkbox label={"Beim Start nach Updates suchen"} checked={settings.check_for_updates} onChange={(_ev, data) => this.updateSettings({check_for_updates: data.checked as boolean})} />
+                  </div>
+                  }
+                  <div id="buttonDiv">
+                    <Button appearance="subtle" icon={<ArrowResetFilled />} onClick={() => this.resetToDefaults()} disabledFocusable={settings === null || this.state.saving}>Standardwerte</Button>
+                    <Caption1Strong className={this.state.errorTextClass}>{this.state.errorText}</Caption1Strong>
+                    <div className="spacer"></div>
+                    <Button appearance="primary" onClick={() => this.submit()} icon={this.state.saving ? <Spinner size="tiny" /> : <SaveFilled />} disabledFocusable={settings === null || this.state.saving}>Speichern</Button>
+                    <Button appearance="secondary" onClick={() => getCurrentWebviewWindow().close()}>Schließen</Button>
+                  </div>
+                </div>
+              </FluentProvider>
+            </>
+        );
+    }
+}
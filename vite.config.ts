@@ -31,7 +31,8 @@ export default defineConfig(async () => ({
         editor: resolve(__dirname, 'editor.html'),
         licenses: resolve(__dirname, 'licenses.html'),
         bugreporter: resolve(__dirname, 'bugreporter.html'),
-        blank: resolve(__dirname, 'blank.html')
+        settings: resolve(__dirname, 'settings.html'),
+        logs: resolve(__dirname, 'logs.html')
       },
     },
   },